@@ -0,0 +1,49 @@
+//! Terrain generation benchmarks for wide (high-DPI) windows.
+//!
+//! Run with `cargo bench --bench terrain_generation`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use lunar_lander::surface::{
+    generate_terrain_heights, generate_terrain_with_multiple_landing_zones, LandingZone, LandingZoneDifficulty,
+    DEFAULT_ZONE_COUNT,
+};
+
+const NUM_POINTS: usize = 10_000;
+const LANDER_WIDTH_POINTS: usize = 36;
+
+fn bench_terrain_generation(c: &mut Criterion) {
+    c.bench_function("terrain_with_zones_10k", |b| {
+        b.iter(|| {
+            generate_terrain_with_multiple_landing_zones(
+                black_box(NUM_POINTS),
+                0.0,
+                100.0,
+                0.01,
+                6,
+                0.5,
+                LANDER_WIDTH_POINTS,
+                DEFAULT_ZONE_COUNT,
+                black_box(42),
+            )
+        })
+    });
+
+    // Heights only, with fixed zones so every iteration does the same work
+    let zones: Vec<LandingZone> = [1000, 4000, 8000]
+        .iter()
+        .map(|&start| LandingZone {
+            start,
+            end: start + LANDER_WIDTH_POINTS - 1,
+            difficulty: LandingZoneDifficulty::Hard,
+            width_points: LANDER_WIDTH_POINTS,
+            motion: None,
+        })
+        .collect();
+    c.bench_function("terrain_heights_10k", |b| {
+        b.iter(|| generate_terrain_heights(black_box(NUM_POINTS), 0.0, 100.0, 0.01, 6, 0.5, &zones, 0))
+    });
+}
+
+criterion_group!(benches, bench_terrain_generation);
+criterion_main!(benches);
@@ -1,5 +1,6 @@
 use macroquad_text::Fonts;
-use macroquad::texture::Texture2D;
+use macroquad::miniquad::conf::Icon;
+use macroquad::texture::{Image, ImageFormat, Texture2D};
 
 const GLASS_TTY_VT220: &[u8] = include_bytes!("../assets/fonts/Glass_TTY_VT220.ttf");
 const LANDER_UPRIGHT: &[u8] = include_bytes!("../assets/images/lander-upright.png");
@@ -33,3 +34,68 @@ pub fn load_lander_textures() -> (Texture2D, Texture2D) {
 
     (lander_texture, thrust_texture)
 }
+
+/// Builds the window icon from the embedded lander artwork.
+///
+/// Runs before the window exists, so the PNG is decoded on the CPU and
+/// scaled to the three icon sizes with nearest-neighbor sampling.
+///
+/// # Returns
+///
+/// The icon, or `None` if the embedded image cannot be decoded
+pub fn window_icon() -> Option<Icon> {
+    let image = Image::from_file_with_format(LANDER_UPRIGHT, Some(ImageFormat::Png)).ok()?;
+    let (width, height) = (image.width as usize, image.height as usize);
+    Some(Icon {
+        small: resample_rgba(&image.bytes, width, height, 16).try_into().ok()?,
+        medium: resample_rgba(&image.bytes, width, height, 32).try_into().ok()?,
+        big: resample_rgba(&image.bytes, width, height, 64).try_into().ok()?,
+    })
+}
+
+/// Scales an RGBA image to a square of `size` pixels (nearest neighbor).
+///
+/// # Arguments
+///
+/// * `rgba` - Source pixels, 4 bytes per pixel, row-major
+/// * `width` - Source width in pixels
+/// * `height` - Source height in pixels
+/// * `size` - Width and height of the result
+///
+/// # Returns
+///
+/// `size * size * 4` bytes of RGBA pixels
+pub fn resample_rgba(rgba: &[u8], width: usize, height: usize, size: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(size * size * 4);
+    for y in 0..size {
+        let src_y = y * height / size;
+        for x in 0..size {
+            let src_x = x * width / size;
+            let offset = (src_y * width + src_x) * 4;
+            out.extend_from_slice(&rgba[offset..offset + 4]);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resample_rgba() {
+        // 2x2 image with one distinct color per quadrant
+        let rgba = [
+            1, 1, 1, 255, 2, 2, 2, 255, //
+            3, 3, 3, 255, 4, 4, 4, 255,
+        ];
+        let scaled = resample_rgba(&rgba, 2, 2, 4);
+        assert_eq!(scaled.len(), 4 * 4 * 4);
+        assert_eq!(&scaled[0..4], &[1, 1, 1, 255]);
+        assert_eq!(&scaled[3 * 4..4 * 4], &[2, 2, 2, 255], "Top-right pixel comes from the top-right quadrant");
+        assert_eq!(&scaled[15 * 4..16 * 4], &[4, 4, 4, 255]);
+
+        // Downscaling keeps the requested size
+        assert_eq!(resample_rgba(&[9; 64 * 64 * 4], 64, 64, 16).len(), 16 * 16 * 4);
+    }
+}
@@ -1,4 +1,46 @@
+//! Engine and ambient sound playback, plus the persisted mute setting.
+//!
+//! `rusty_audio` always plays at full level and has no volume API, so muting
+//! is the only level control; there is no volume setting to persist.
+
+use std::fs;
+use std::io;
+
+use macroquad::logging::warn;
 use rusty_audio::Audio;
+use serde::{Deserialize, Serialize};
+
+/// File the audio settings are kept in (JSON, next to `scores.json`)
+pub const AUDIO_SETTINGS_FILE: &str = "audio_settings.json";
+
+/// Audio preferences remembered between runs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct AudioSettings {
+    pub muted: bool, // All game audio off (M), independent of the ambient toggle (S)
+}
+
+impl AudioSettings {
+    /// Reads the settings file, using the defaults if it is missing or corrupt.
+    pub fn load(path: &str) -> Self {
+        match fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).unwrap_or_else(|err| {
+                warn!("Ignoring corrupt audio settings {}: {}", path, err);
+                Self::default()
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(err) => {
+                warn!("Could not read {}: {}", path, err);
+                Self::default()
+            }
+        }
+    }
+
+    /// Writes the settings file.
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json)
+    }
+}
 
 pub fn load_audio() -> Audio {
     let mut audio = Audio::new();
@@ -7,9 +49,22 @@ pub fn load_audio() -> Audio {
         "acceleration",
         "assets/sounds/218837-jet_turbine_main_blast.wav",
     );
+    audio.add("crash", "assets/sounds/crash.wav");
+    audio.add("landing_chime", "assets/sounds/landing_chime.wav");
+    audio.add("low_fuel_beep", "assets/sounds/low_fuel_beep.wav");
+    audio.add("fuel_pickup", "assets/sounds/fuel_pickup.wav");
     audio
 }
 
+/// Plays a sound effect once from the start, cutting off whatever is playing.
+///
+/// Nothing restarts it, unlike the ambient loop in `update_audio`, so it
+/// runs to its end and stops.
+pub fn play_oneshot(audio: &mut Audio, name: &str) {
+    audio.stop();
+    audio.play(name);
+}
+
 pub fn update_audio(audio: &mut Audio) {
     if !audio.is_playing() {
         audio.play("ambient"); // Execution continues while playback occurs in another thread.
@@ -18,4 +73,22 @@ pub fn update_audio(audio: &mut Audio) {
 
 pub fn shutdown_audio(audio: &mut Audio) {
     audio.stop();
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audio_settings_round_trip() {
+        let path = std::env::temp_dir().join(format!("lunar_lander_audio_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        assert_eq!(AudioSettings::load(path), AudioSettings::default(), "Missing file starts unmuted");
+
+        AudioSettings { muted: true }.save(path).unwrap();
+        assert!(AudioSettings::load(path).muted);
+
+        fs::write(path, "muted").unwrap();
+        assert_eq!(AudioSettings::load(path), AudioSettings::default());
+        fs::remove_file(path).unwrap();
+    }
+}
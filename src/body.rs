@@ -0,0 +1,92 @@
+//! Celestial bodies the lander can be flown on.
+//!
+//! Each body only changes the surface gravity; the lander, terrain and
+//! landing limits stay the same. The LM's engine is sized for the Moon: on
+//! Mars it can barely hover at full load, and on Earth it cannot hover at all
+//! until it has burned off a good part of its fuel.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ACCEL_GRAV_Y;
+
+/// Where the lander is landing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CelestialBody {
+    #[default]
+    Moon,   // 1.625 m/s², the classic game
+    Mars,   // 3.721 m/s²
+    Earth,  // 9.807 m/s²
+    Europa, // 1.315 m/s²
+}
+
+impl CelestialBody {
+    /// Every body, in the order the in-game key cycles through them
+    pub const ALL: [CelestialBody; 4] = [
+        CelestialBody::Moon,
+        CelestialBody::Mars,
+        CelestialBody::Earth,
+        CelestialBody::Europa,
+    ];
+
+    /// Surface gravity in m/s²
+    pub fn gravity(&self) -> f32 {
+        match self {
+            CelestialBody::Moon => ACCEL_GRAV_Y,
+            CelestialBody::Mars => 3.721,
+            CelestialBody::Earth => 9.807,
+            CelestialBody::Europa => 1.315,
+        }
+    }
+
+    /// Returns the human-readable name of this body.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CelestialBody::Moon => "Moon",
+            CelestialBody::Mars => "Mars",
+            CelestialBody::Earth => "Earth",
+            CelestialBody::Europa => "Europa",
+        }
+    }
+
+    /// Looks up a body by name (case-insensitive).
+    ///
+    /// # Returns
+    ///
+    /// The matching body, or `None` for an unknown name
+    pub fn from_name(name: &str) -> Option<CelestialBody> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|body| body.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the next body in the cycle Moon -> Mars -> Earth -> Europa -> Moon.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|body| *body == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_celestial_bodies() {
+        assert_eq!(CelestialBody::default(), CelestialBody::Moon);
+        assert_eq!(CelestialBody::Moon.gravity(), ACCEL_GRAV_Y, "Moon keeps the classic balance");
+        assert!(CelestialBody::Europa.gravity() < CelestialBody::Moon.gravity());
+        assert!(CelestialBody::Earth.gravity() > CelestialBody::Mars.gravity());
+
+        assert_eq!(CelestialBody::from_name("mars"), Some(CelestialBody::Mars));
+        assert_eq!(CelestialBody::from_name("Pluto"), None);
+
+        // Cycling visits every body once and comes back around
+        let mut body = CelestialBody::Moon;
+        for expected in CelestialBody::ALL.iter().skip(1) {
+            body = body.next();
+            assert_eq!(body, *expected);
+        }
+        assert_eq!(body.next(), CelestialBody::Moon);
+    }
+}
@@ -6,24 +6,192 @@
 //! - Velocity-based landing success/failure determination
 //! - Distance measurements from landing zone edges
 //! - Legacy flat spot compatibility
+//!
+//! The limits below are the defaults; detection reads the values actually in
+//! force from a `LandingTuning` (see `tuning.rs`).
 
 use macroquad::prelude::*;
 
-use crate::entity::Entity;
-use crate::surface::{LandingZone, LandingZoneDifficulty};
+use crate::entity::{Entity, Transform};
+use crate::session::{AttemptResult, LandingGrade};
+use crate::surface::{peak_height_under, terrain_height_at, LandingZone, LandingZoneDifficulty};
+use crate::tuning::LandingTuning;
+use crate::utils::{pixel_to_terrain_x, terrain_x_to_pixel};
 
-const COLLISION_MARGIN: f32 = 3.0;
+pub const COLLISION_MARGIN: f32 = 3.0; // Distance above the terrain that already counts as contact
 const LEG_HEIGHT_RATIO: f32 = 0.25; // Bottom 25% is legs
 const LEG_WIDTH_RATIO: f32 = 0.3; // Each leg takes 30% of width (20% gap in middle)
-const MAX_LANDING_VELOCITY: f32 = 10.0; // Maximum safe landing speed
-const MAX_LANDING_ANGLE_DEGREES: f32 = 15.0; // Maximum angle from vertical for safe landing
+pub const MAX_LANDING_VELOCITY: f32 = 10.0; // Maximum safe landing speed
+pub const MAX_LANDING_ANGLE_DEGREES: f32 = 15.0; // Maximum angle from vertical for safe landing
+pub const MAX_SLOPE_MISMATCH_DEGREES: f32 = 5.0; // Off a zone, maximum tilt difference from the local slope
+const WRECKAGE_HEIGHT_RATIO: f32 = 0.4; // Crumpled wreckage stands 40% of the lander height
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CollisionType {
     None,
     LegCollision,
     BodyCollision,
     LandingSuccess,
+    UnstableLanding, // Gentle touchdown on a zone, but on one leg: the lander tips over
+}
+
+/// Lander collision geometry expressed as fractions of the sprite size.
+///
+/// This is the single source of truth for where the legs and body are, shared
+/// by collision detection and the debug visualization. Different lander sprites
+/// can tune the ratios so the legs line up with the artwork.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CollisionShape {
+    pub leg_height_ratio: f32, // Bottom fraction of the lander treated as legs
+    pub leg_width_ratio: f32,  // Width fraction taken by each leg
+}
+
+impl Default for CollisionShape {
+    fn default() -> Self {
+        Self {
+            leg_height_ratio: LEG_HEIGHT_RATIO,
+            leg_width_ratio: LEG_WIDTH_RATIO,
+        }
+    }
+}
+
+/// Leg and body collision rectangles in camera coordinates (`y` is the bottom edge)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CollisionZones {
+    pub left_leg: Rect,
+    pub right_leg: Rect,
+    pub body: Rect,
+}
+
+impl CollisionShape {
+    /// Lays the shape out over a lander transform.
+    ///
+    /// - Legs: bottom `leg_height_ratio` of the lander, at the left and right edges
+    /// - Body: center section between the legs, from the top of the legs upward
+    ///
+    /// # Arguments
+    ///
+    /// * `transform` - Lander position (bottom-left, camera coordinates) and size
+    ///
+    /// # Returns
+    ///
+    /// The leg and body rectangles in camera coordinates
+    pub fn zones(&self, transform: &Transform) -> CollisionZones {
+        let x = transform.position.x;
+        let y = transform.position.y;
+        let width = transform.size.x;
+        let height = transform.size.y;
+
+        let leg_height = height * self.leg_height_ratio;
+        let leg_width = width * self.leg_width_ratio;
+
+        CollisionZones {
+            left_leg: Rect::new(x, y, leg_width, leg_height),
+            right_leg: Rect::new(x + width - leg_width, y, leg_width, leg_height),
+            body: Rect::new(x + leg_width, y + leg_height, width - 2.0 * leg_width, height - leg_height),
+        }
+    }
+}
+
+/// Tilt of the terrain surface under the lander's legs, in degrees.
+///
+/// The surface is taken as the line between the terrain heights under the
+/// centers of the two legs; the result is the angle of its normal from
+/// straight up, positive counterclockwise like the lander rotation. Ground
+/// rising to the right therefore gives a positive angle, and a flat zone `0.0`.
+///
+/// # Arguments
+///
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `zones` - Leg and body rectangles of the lander (see `CollisionShape::zones`)
+/// * `world_width` - Width in pixels the terrain spans
+///
+/// # Returns
+///
+/// Surface normal angle in degrees, or `0.0` if a leg is off the terrain
+pub fn surface_normal_angle(terrain: &[f64], zones: &CollisionZones, world_width: f32) -> f32 {
+    let left_x = zones.left_leg.x + zones.left_leg.w / 2.0;
+    let right_x = zones.right_leg.x + zones.right_leg.w / 2.0;
+    let height_at = |x: f32| terrain_height_at(terrain, pixel_to_terrain_x(x, terrain.len(), world_width));
+    match (height_at(left_x), height_at(right_x)) {
+        (Some(left_y), Some(right_y)) if right_x > left_x => (right_y - left_y).atan2(right_x - left_x).to_degrees(),
+        _ => 0.0,
+    }
+}
+
+/// Tilt of the terrain across the lander's footprint, in degrees.
+///
+/// Unlike `surface_normal_angle`, which only samples under the two leg
+/// centers, this fits a least-squares line through every terrain point under
+/// the lander plus one neighbor on either side, so a single spike or notch
+/// doesn't swing the result. The sign follows the lander rotation: ground
+/// rising to the right gives a positive angle.
+///
+/// # Arguments
+///
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `transform` - Lander position (bottom-left, camera coordinates) and size
+/// * `world_width` - Width in pixels the terrain spans
+///
+/// # Returns
+///
+/// Slope angle in degrees, or `0.0` with fewer than two terrain points under the lander
+pub fn local_slope_angle(terrain: &[f64], transform: &Transform, world_width: f32) -> f32 {
+    if terrain.len() < 2 {
+        return 0.0;
+    }
+    let left = pixel_to_terrain_x(transform.position.x, terrain.len(), world_width);
+    let right = pixel_to_terrain_x(transform.position.x + transform.size.x, terrain.len(), world_width);
+    let first = (left.floor() as i64 - 1).max(0) as usize;
+    let last = (right.ceil() as i64 + 1).min(terrain.len() as i64 - 1);
+    if last < first as i64 + 1 {
+        return 0.0;
+    }
+    let points = first..=last as usize;
+
+    // Fit in pixels, so the angle matches what is drawn on screen
+    let pixel_x = |i: usize| terrain_x_to_pixel(i as f32, terrain.len(), world_width) as f64;
+    let count = points.clone().count() as f64;
+    let mean_x = points.clone().map(pixel_x).sum::<f64>() / count;
+    let mean_y = points.clone().map(|i| terrain[i]).sum::<f64>() / count;
+    let (covariance, variance) = points.fold((0.0, 0.0), |(cov, var), i| {
+        let dx = pixel_x(i) - mean_x;
+        (cov + dx * (terrain[i] - mean_y), var + dx * dx)
+    });
+    (covariance / variance).atan().to_degrees() as f32
+}
+
+/// Judges a soft touchdown away from any landing zone.
+///
+/// Rough ground has no flat pad to settle on, so besides arriving within the
+/// landing speed the lander has to come down tilted to match the local slope
+/// (within `LandingTuning::max_slope_mismatch_degrees`), legs square to the
+/// ground. `detect_collision` still treats every off-zone touchdown as a
+/// crash; a free-landing mode decides success with this instead.
+///
+/// # Arguments
+///
+/// * `transform` - Lander position (bottom-left, camera coordinates), size and rotation
+/// * `tuning` - Landing speed limit and slope tolerance
+/// * `velocity` - Lander velocity at touchdown
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `world_width` - Width in pixels the terrain spans
+///
+/// # Returns
+///
+/// Whether the touchdown holds, and the local slope angle (see `local_slope_angle`)
+pub fn assess_free_touchdown(
+    transform: &Transform,
+    tuning: &LandingTuning,
+    velocity: Vec2,
+    terrain: &[f64],
+    world_width: f32,
+) -> (bool, f32) {
+    let slope = local_slope_angle(terrain, transform, world_width);
+    let (max_velocity, _) = tuning.zone_limits(None);
+    let mismatch = angle_from_vertical(transform.rotation - slope);
+    let landed = velocity.length() <= max_velocity && mismatch <= tuning.max_slope_mismatch_degrees;
+    (landed, slope)
 }
 
 /// Determines if the lander is positioned within any landing zone and calculates positioning accuracy.
@@ -77,6 +245,39 @@ pub fn get_landing_zone_info(terrain_indices: &[usize], landing_zones: &[Landing
     None
 }
 
+/// Height difference across the surface of a rough landing zone.
+///
+/// Hard and Medium zones are roughened (see `roughen_landing_zones`), so the
+/// ground under the two legs can differ by up to the zone's full relief even
+/// when the lander comes down level. Flat zones report `0.0`, as does a span
+/// that isn't inside any zone.
+///
+/// # Arguments
+///
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `landing_zones` - Landing zones laid out on the terrain
+/// * `difficulty` - Difficulty of the zone the lander touched
+/// * `terrain_indices` - Terrain points the lander is touching
+fn zone_relief(terrain: &[f64], landing_zones: &[LandingZone], difficulty: LandingZoneDifficulty, terrain_indices: &[usize]) -> f32 {
+    if difficulty.roughness() <= 0.0 {
+        return 0.0;
+    }
+    let (Some(&min_idx), Some(&max_idx)) = (terrain_indices.iter().min(), terrain_indices.iter().max()) else {
+        return 0.0;
+    };
+
+    landing_zones
+        .iter()
+        .find(|zone| min_idx >= zone.start && max_idx <= zone.end)
+        .and_then(|zone| terrain.get(zone.start..=zone.end.min(terrain.len() - 1)))
+        .map(|surface| {
+            let highest = surface.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let lowest = surface.iter().cloned().fold(f64::INFINITY, f64::min);
+            (highest - lowest) as f32
+        })
+        .unwrap_or(0.0)
+}
+
 /// Legacy function for backward compatibility.
 ///
 /// Returns only the difficulty level of the landing zone, without distance measurements.
@@ -135,7 +336,8 @@ pub fn is_on_flat_spot(terrain_indices: &[usize], flat_spot_range: (usize, usize
 /// - Terrain collision detection using leg and body zones
 /// - Landing zone positioning validation
 /// - Velocity and angle requirements for successful landing
-/// - Distance measurements for positioning feedback
+///
+/// The rules live in `detect_collision`; this reads its inputs from the entity.
 ///
 /// # Collision Zones
 ///
@@ -158,178 +360,11 @@ pub fn is_on_flat_spot(terrain_indices: &[usize], flat_spot_range: (usize, usize
 /// * `CollisionType::LandingSuccess` - Successful landing meeting all requirements
 /// * `CollisionType::LegCollision` - Hard landing (failed velocity/angle requirements)
 /// * `CollisionType::BodyCollision` - Body collision (mission failure)
+/// * `CollisionType::UnstableLanding` - Gentle touchdown on one leg; the lander tips over
 /// * `CollisionType::None` - No collision detected
 pub fn check_collision(entity: &Entity) -> CollisionType {
-    // CAMERA COORDINATE COLLISION DETECTION
-    // Both lander and terrain are already in camera coordinates:
-    // - Lander position: stored in camera coordinates (entity.transform.position)
-    // - Terrain Y values: stored directly as camera Y coordinates
-    // - Terrain X mapping: array indices 0-1000 map to camera X range
-
-    let _screen_width = macroquad::window::screen_width();
-
-    // Lander position in camera coordinates (already correct)
-    let lander_x = entity.transform.position.x;
-    let lander_y = entity.transform.position.y;
-    let lander_width = entity.transform.size.x;
-    let lander_height = entity.transform.size.y;
-
-    // Calculate lander bottom in camera coordinates
-    // In camera coordinates: Y increases UPWARD (due to -2.0/screen_height zoom), so bottom = Y position
-    let lander_bottom_y = lander_y;
-
-    // Convert lander camera X position to terrain array indices
-    // Simple 1:1 mapping: camera_x = i, so i = camera_x
-    let lander_left_x = lander_x;
-    let lander_right_x = lander_x + lander_width;
-
-    // Convert to terrain array indices (simple 1:1 mapping)
-    let terrain_start_idx = (lander_left_x as i32).max(0) as usize;
-    let terrain_end_idx = (lander_right_x as i32).min((entity.terrain.len() - 1) as i32) as usize;
-
-    // Safety bounds check
-    if terrain_start_idx >= entity.terrain.len() || terrain_end_idx >= entity.terrain.len() {
-        return CollisionType::None;
-    }
-
-    // Collision zones - divide lander into legs and body
-    // Legs: bottom 25% of lander, only at the edges (left and right 30% of width)
-    // Body: upper 75% of lander, or center 40% of width at bottom
-
-    // Define collision zones - corrected for camera coordinates (Y increases upward)
-    let leg_zone_bottom = lander_bottom_y; // Bottom of lander (lower Y value)
-    let leg_zone_top = lander_bottom_y + (lander_height * LEG_HEIGHT_RATIO); // 25% up from bottom
-    let body_zone_bottom = leg_zone_top; // Body starts where legs end
-
-    // Leg collision areas (left and right edges)
-    let leg_width = lander_width * LEG_WIDTH_RATIO;
-    let left_leg_start = lander_left_x;
-    let left_leg_end = lander_left_x + leg_width;
-    let right_leg_start = lander_right_x - leg_width;
-    let right_leg_end = lander_right_x;
-
-    // Body collision area (center section)
-    let body_left = left_leg_end;
-    let body_right = right_leg_start;
-
-    // Check for collisions in different zones and collect terrain indices under lander
-    let mut leg_collision = false;
-    let mut body_collision = false;
-    let mut collision_terrain_indices = Vec::new();
-
-    for i in terrain_start_idx..=terrain_end_idx {
-        let terrain_y = entity.terrain[i] as f32;
-        let terrain_x = i as f32; // Simple 1:1 mapping
-
-        // Check leg collisions (only at lander bottom, in leg zones)
-        if leg_zone_bottom <= terrain_y + COLLISION_MARGIN {
-            if (terrain_x >= left_leg_start && terrain_x <= left_leg_end)
-                || (terrain_x >= right_leg_start && terrain_x <= right_leg_end)
-            {
-                leg_collision = true;
-                collision_terrain_indices.push(i);
-                info!(
-                    "LEG COLLISION: terrain_idx={}, leg_bottom={:.1}, terrain_y={:.1}",
-                    i, leg_zone_bottom, terrain_y
-                );
-            }
-        }
-
-        // Check body collision (center section or higher up)
-        if body_zone_bottom <= terrain_y + COLLISION_MARGIN {
-            if terrain_x >= body_left && terrain_x <= body_right {
-                body_collision = true;
-                collision_terrain_indices.push(i);
-                info!(
-                    "BODY COLLISION: terrain_idx={}, body_bottom={:.1}, terrain_y={:.1}",
-                    i, body_zone_bottom, terrain_y
-                );
-            }
-        }
-    }
-
-    // Determine collision type based on landing zones, velocity, and collision zones
-    // CRITICAL: Only landing zones are safe landing spots!
-    if leg_collision {
-        // Check if landing on any landing zone (mandatory for success)
-        let screen_width = macroquad::window::screen_width();
-        let terrain_points_per_pixel = 1000.0 / (screen_width * 2.0);
-        let lander_width_terrain_points = (entity.transform.size.x * terrain_points_per_pixel) as usize;
-        
-        let landing_zone_info = get_landing_zone_info(&collision_terrain_indices, &entity.landing_zones, lander_width_terrain_points);
-
-        if let Some((difficulty, dist_left, dist_right)) = landing_zone_info {
-            info!("LANDING ON {} ZONE: {} difficulty, distances: {:.1} from left edge, {:.1} from right edge", 
-                  difficulty.name().to_uppercase(), difficulty.name(), dist_left, dist_right);
-        } else {
-            info!("ROUGH TERRAIN LANDING: Not on any landing zone - Mission Failed!");
-            return CollisionType::LegCollision;
-        }
-
-        // On landing zone - now check velocity and angle for success vs crash
-        if let Some(physics) = &entity.physics {
-            let landing_velocity = physics.velocity.length();
-            
-            // Check lander angle relative to vertical (0 degrees is straight up)
-            // Convert rotation from degrees to a normalized angle from vertical
-            let lander_angle = entity.transform.rotation;
-            // Normalize to 0-360 range
-            let normalized_angle = lander_angle.rem_euclid(360.0);
-            // Calculate deviation from vertical (0 degrees)
-            let angle_from_vertical = normalized_angle.min(360.0 - normalized_angle);
-            // angle_from_vertical already handles wraparound correctly
-            let angle_deviation = angle_from_vertical;
-            
-            // Check both velocity and angle requirements
-            let velocity_ok = landing_velocity <= MAX_LANDING_VELOCITY;
-            let angle_ok = angle_deviation <= MAX_LANDING_ANGLE_DEGREES;
-            
-            if velocity_ok && angle_ok {
-                if let Some((difficulty, dist_left, dist_right)) = landing_zone_info {
-                    info!(
-                        "SUCCESSFUL LANDING: velocity={:.1}, angle={:.1}° from vertical on {} zone (edges: {:.1}L, {:.1}R)",
-                        landing_velocity, angle_deviation, difficulty.name().to_lowercase(), dist_left, dist_right
-                    );
-                } else {
-                    info!(
-                        "SUCCESSFUL LANDING: velocity={:.1}, angle={:.1}° from vertical",
-                        landing_velocity, angle_deviation
-                    );
-                }
-                CollisionType::LandingSuccess
-            } else {
-                let (zone_name, edge_info) = if let Some((difficulty, dist_left, dist_right)) = landing_zone_info {
-                    (difficulty.name().to_lowercase(), format!(" (edges: {:.1}L, {:.1}R)", dist_left, dist_right))
-                } else {
-                    ("landing".to_string(), String::new())
-                };
-                
-                if !velocity_ok && !angle_ok {
-                    info!(
-                        "HARD LANDING: velocity={:.1} > {:.1} AND angle={:.1}° > {:.1}° on {} zone{}",
-                        landing_velocity, MAX_LANDING_VELOCITY, angle_deviation, MAX_LANDING_ANGLE_DEGREES, zone_name, edge_info
-                    );
-                } else if !velocity_ok {
-                    info!(
-                        "HARD LANDING: velocity={:.1} > {:.1} on {} zone (angle ok: {:.1}°){}",
-                        landing_velocity, MAX_LANDING_VELOCITY, zone_name, angle_deviation, edge_info
-                    );
-                } else {
-                    info!(
-                        "TILTED LANDING: angle={:.1}° > {:.1}° on {} zone (velocity ok: {:.1}){}",
-                        angle_deviation, MAX_LANDING_ANGLE_DEGREES, zone_name, landing_velocity, edge_info
-                    );
-                }
-                CollisionType::LegCollision
-            }
-        } else {
-            CollisionType::LegCollision
-        }
-    } else if body_collision {
-        CollisionType::BodyCollision
-    } else {
-        CollisionType::None
-    }
+    // Same rules as the game loop and headless sim; see `detect_collision`
+    check_collision_with_zone_info(entity).0
 }
 
 /// Enhanced collision check that returns both collision type and landing zone information
@@ -341,54 +376,104 @@ pub fn check_collision(entity: &Entity) -> CollisionType {
 /// * `CollisionType` - The type of collision detected
 /// * `Option<LandingZoneDifficulty>` - Landing zone difficulty if successful landing
 pub fn check_collision_with_zone_info(entity: &Entity) -> (CollisionType, Option<LandingZoneDifficulty>) {
-    // We need to extract zone info during collision detection to avoid duplication
-    // This is a modified version of check_collision that also returns zone info
-    
-    let lander_x = entity.transform.position.x;
-    let lander_y = entity.transform.position.y;
-    let lander_width = entity.transform.size.x;
-    let lander_height = entity.transform.size.y;
+    detect_collision(
+        &entity.transform,
+        &entity.tuning,
+        entity.physics.as_ref().map(|physics| physics.velocity),
+        &entity.terrain,
+        entity.world_size.x,
+        &entity.landing_zones,
+    )
+}
+
+/// Collision detection core shared by the game loop and the headless simulation.
+///
+/// Works purely on the lander geometry and terrain data, without touching any
+/// `macroquad` window state, so it can run outside the game loop.
+///
+/// # Arguments
+///
+/// * `transform` - Lander position (bottom-left, camera coordinates), size and rotation
+/// * `tuning` - Leg and body layout, contact margin and landing limits
+/// * `velocity` - Lander velocity, or `None` if the lander has no physics component
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `world_width` - Width in pixels the terrain spans
+/// * `landing_zones` - Landing zones laid out on the terrain
+///
+/// # Returns
+///
+/// A tuple containing:
+/// * `CollisionType` - The type of collision detected
+/// * `Option<LandingZoneDifficulty>` - Landing zone difficulty if the legs touched a zone
+pub fn detect_collision(
+    transform: &Transform,
+    tuning: &LandingTuning,
+    velocity: Option<Vec2>,
+    terrain: &[f64],
+    world_width: f32,
+    landing_zones: &[LandingZone],
+) -> (CollisionType, Option<LandingZoneDifficulty>) {
+    if terrain.is_empty() {
+        return (CollisionType::None, None);
+    }
+
+    let lander_x = transform.position.x;
+    let lander_y = transform.position.y;
+    let lander_width = transform.size.x;
     let lander_bottom_y = lander_y;
     
     // Convert to terrain array indices
-    let terrain_start_idx = (lander_x as i32).max(0) as usize;
-    let terrain_end_idx = ((lander_x + lander_width) as i32).min((entity.terrain.len() - 1) as i32) as usize;
+    let terrain_start_idx = (pixel_to_terrain_x(lander_x, terrain.len(), world_width) as i32).max(0) as usize;
+    let terrain_end_idx = (pixel_to_terrain_x(lander_x + lander_width, terrain.len(), world_width) as i32)
+        .min((terrain.len() - 1) as i32) as usize;
     
-    if terrain_start_idx >= entity.terrain.len() || terrain_end_idx >= entity.terrain.len() {
+    if terrain_start_idx >= terrain.len() || terrain_end_idx >= terrain.len() {
         return (CollisionType::None, None);
     }
     
     // Check for collisions and collect terrain indices
-    let mut leg_collision = false;
+    let mut left_leg = false;
+    let mut right_leg = false;
     let mut body_collision = false;
     let mut collision_terrain_indices = Vec::new();
+    // Highest ground under each leg, for settling a leg that hasn't touched yet
+    let mut left_ground = f32::NEG_INFINITY;
+    let mut right_ground = f32::NEG_INFINITY;
     
     // Define collision zones
-    let leg_zone_top = lander_bottom_y + (lander_height * LEG_HEIGHT_RATIO);
-    let leg_width = lander_width * LEG_WIDTH_RATIO;
-    let left_leg_start = lander_x;
-    let left_leg_end = lander_x + leg_width;
-    let right_leg_start = lander_x + lander_width - leg_width;
-    let right_leg_end = lander_x + lander_width;
-    let body_left = left_leg_end;
-    let body_right = right_leg_start;
+    let zones = tuning.shape.zones(transform);
+    let leg_zone_top = zones.body.y;
+    let left_leg_start = zones.left_leg.x;
+    let left_leg_end = zones.left_leg.x + zones.left_leg.w;
+    let right_leg_start = zones.right_leg.x;
+    let right_leg_end = zones.right_leg.x + zones.right_leg.w;
+    let body_left = zones.body.x;
+    let body_right = zones.body.x + zones.body.w;
     
     for i in terrain_start_idx..=terrain_end_idx {
-        let terrain_y = entity.terrain[i] as f32;
-        let terrain_x = i as f32;
+        let terrain_y = terrain[i] as f32;
+        let terrain_x = terrain_x_to_pixel(i as f32, terrain.len(), world_width);
         
-        // Check leg collisions
-        if lander_bottom_y <= terrain_y + COLLISION_MARGIN {
-            if (terrain_x >= left_leg_start && terrain_x <= left_leg_end)
-                || (terrain_x >= right_leg_start && terrain_x <= right_leg_end)
-            {
-                leg_collision = true;
+        if terrain_x >= left_leg_start && terrain_x <= left_leg_end {
+            left_ground = left_ground.max(terrain_y);
+        }
+        if terrain_x >= right_leg_start && terrain_x <= right_leg_end {
+            right_ground = right_ground.max(terrain_y);
+        }
+
+        // Check leg collisions, each leg on its own
+        if lander_bottom_y <= terrain_y + tuning.collision_margin {
+            let on_left = terrain_x >= left_leg_start && terrain_x <= left_leg_end;
+            let on_right = terrain_x >= right_leg_start && terrain_x <= right_leg_end;
+            left_leg |= on_left;
+            right_leg |= on_right;
+            if on_left || on_right {
                 collision_terrain_indices.push(i);
             }
         }
         
         // Check body collision
-        if leg_zone_top <= terrain_y + COLLISION_MARGIN {
+        if leg_zone_top <= terrain_y + tuning.collision_margin {
             if terrain_x >= body_left && terrain_x <= body_right {
                 body_collision = true;
                 collision_terrain_indices.push(i);
@@ -397,26 +482,35 @@ pub fn check_collision_with_zone_info(entity: &Entity) -> (CollisionType, Option
     }
     
     // Determine collision type and zone difficulty
-    if leg_collision {
+    if left_leg || right_leg {
         // Check if landing on any landing zone
-        let screen_width = macroquad::window::screen_width();
-        let terrain_points_per_pixel = 1000.0 / (screen_width * 2.0);
-        let lander_width_terrain_points = (entity.transform.size.x * terrain_points_per_pixel) as usize;
+        let lander_width_terrain_points = pixel_to_terrain_x(lander_width, terrain.len(), world_width) as usize;
         
-        let landing_zone_info = get_landing_zone_info(&collision_terrain_indices, &entity.landing_zones, lander_width_terrain_points);
+        let landing_zone_info = get_landing_zone_info(&collision_terrain_indices, landing_zones, lander_width_terrain_points);
         
         if let Some((difficulty, _, _)) = landing_zone_info {
             // On landing zone - check velocity and angle for success vs crash
-            if let Some(physics) = &entity.physics {
-                let landing_velocity = physics.velocity.length();
-                let lander_angle = entity.transform.rotation;
-                let normalized_angle = lander_angle.rem_euclid(360.0);
-                let angle_deviation = normalized_angle.min(360.0 - normalized_angle);
+            if let Some(velocity) = velocity {
+                let landing_velocity = velocity.length();
+                // Attitude is judged against the surface normal under the legs; both are
+                // measured in the terrain's frame, so a gravity tilt doesn't enter into it
+                let surface_angle = surface_normal_angle(terrain, &zones, world_width);
+                let angle_deviation = angle_from_vertical(transform.rotation - surface_angle);
                 
-                let velocity_ok = landing_velocity <= MAX_LANDING_VELOCITY;
-                let angle_ok = angle_deviation <= MAX_LANDING_ANGLE_DEGREES;
+                let (max_velocity, max_angle) = tuning.zone_limits(Some(difficulty));
+                let velocity_ok = landing_velocity <= max_velocity;
+                let angle_ok = angle_deviation <= max_angle;
                 
-                if velocity_ok && angle_ok {
+                // On a rough zone the pad tilts under the legs, so a level lander
+                // puts one leg down a little before the other, which then settles
+                let hanging_ground = if left_leg { right_ground } else { left_ground };
+                let settle_margin = tuning.collision_margin + zone_relief(terrain, landing_zones, difficulty, &collision_terrain_indices);
+                let settles = lander_bottom_y - hanging_ground <= settle_margin;
+
+                if velocity_ok && angle_ok && !(left_leg && right_leg) && !settles {
+                    // Slow and level enough, but the other leg is still in the air
+                    (CollisionType::UnstableLanding, Some(difficulty))
+                } else if velocity_ok && angle_ok {
                     (CollisionType::LandingSuccess, Some(difficulty))
                 } else {
                     (CollisionType::LegCollision, Some(difficulty))
@@ -434,11 +528,493 @@ pub fn check_collision_with_zone_info(entity: &Entity) -> (CollisionType, Option
     }
 }
 
+/// Grades a successful landing by how far inside the limits it stayed.
+///
+/// Centering uses the edge distances of the lander footprint from
+/// `zone_edge_distances`: equal distances either side score 1.0, touching an
+/// edge scores 0.0. Speed and angle margins are the unused fraction of the
+/// zone's landing speed and angle limits (see `LandingTuning::zone_limits`),
+/// the angle taken against the surface normal under the legs.
+///
+/// # Arguments
+///
+/// * `transform` - Lander position (bottom-left, camera coordinates), size and rotation
+/// * `tuning` - Leg and body layout and landing limits
+/// * `velocity` - Lander velocity at touchdown
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `world_width` - Width in pixels the terrain spans
+/// * `landing_zones` - Landing zones laid out on the terrain
+///
+/// # Returns
+///
+/// The letter grade for the landing
+pub fn landing_grade(
+    transform: &Transform,
+    tuning: &LandingTuning,
+    velocity: Vec2,
+    terrain: &[f64],
+    world_width: f32,
+    landing_zones: &[LandingZone],
+) -> LandingGrade {
+    let zone_info = zone_edge_distances(transform, landing_zones, terrain.len(), world_width);
+    let centering = match zone_info {
+        Some((_, left, right)) if left + right > 0.0 => left.min(right) / ((left + right) / 2.0),
+        Some(_) => 1.0,
+        None => 0.0, // Legs in the zone but the body overhangs an edge
+    };
+    let (max_velocity, max_angle) = tuning.zone_limits(zone_info.map(|(difficulty, _, _)| difficulty));
+    let speed = 1.0 - velocity.length() / max_velocity;
+    let surface_angle = surface_normal_angle(terrain, &tuning.shape.zones(transform), world_width);
+    let angle = 1.0 - angle_from_vertical(transform.rotation - surface_angle) / max_angle;
+    LandingGrade::from_margins(centering, speed, angle)
+}
+
+/// Landing zone under the whole lander footprint and the slack either side.
+///
+/// Like `get_landing_zone_info`, but spans the full lander width rather than
+/// the terrain points the legs happen to touch, so it also reads the final
+/// position after a successful landing.
+///
+/// # Arguments
+///
+/// * `transform` - Lander position (bottom-left, camera coordinates) and size
+/// * `landing_zones` - Landing zones laid out on the terrain
+/// * `terrain_points` - Number of points in the terrain
+/// * `world_width` - Width in pixels the terrain spans
+///
+/// # Returns
+///
+/// `Some((difficulty, distance_from_left, distance_from_right))` in terrain points,
+/// or `None` if the lander overhangs every zone
+pub fn zone_edge_distances(
+    transform: &Transform,
+    landing_zones: &[LandingZone],
+    terrain_points: usize,
+    world_width: f32,
+) -> Option<(LandingZoneDifficulty, f32, f32)> {
+    let to_terrain = |x: f32| pixel_to_terrain_x(x, terrain_points, world_width).max(0.0) as usize;
+    let footprint = [
+        to_terrain(transform.position.x),
+        to_terrain(transform.position.x + transform.size.x),
+    ];
+    get_landing_zone_info(&footprint, landing_zones, footprint[1] - footprint[0])
+}
+
+/// Deviation of a lander rotation from upright, in degrees.
+///
+/// # Arguments
+///
+/// * `rotation` - Lander rotation in degrees (any range, 0° = upright)
+///
+/// # Returns
+///
+/// Angle from vertical in `[0, 180]`, handling wraparound
+pub fn angle_from_vertical(rotation: f32) -> f32 {
+    let normalized_angle = rotation.rem_euclid(360.0);
+    normalized_angle.min(360.0 - normalized_angle)
+}
+
+/// Lays out solid wreckage from failed attempts on the current terrain.
+///
+/// Each crash site becomes a lander-wide box resting on the highest terrain
+/// point beneath it. Terrain is regenerated between attempts, so only the
+/// horizontal crash position carries over. Wreckage that would sit in the
+/// spawn column is pushed just clear of it, so a reset never drops the lander
+/// straight onto debris.
+///
+/// # Arguments
+///
+/// * `attempt_sites` - Recorded attempt sites; only failures leave wreckage
+/// * `terrain` - Terrain heights in camera coordinates, one per terrain point
+/// * `world_width` - Width in pixels the terrain spans
+/// * `lander_size` - Lander width and height in pixels
+/// * `spawn_center_x` - Camera X of the lander's start position
+///
+/// # Returns
+///
+/// Wreckage rectangles in camera coordinates (`y` is the bottom edge)
+pub fn wreckage_boxes(
+    attempt_sites: &[(Vec2, AttemptResult)],
+    terrain: &[f64],
+    world_width: f32,
+    lander_size: Vec2,
+    spawn_center_x: f32,
+) -> Vec<Rect> {
+    if terrain.is_empty() {
+        return Vec::new();
+    }
+
+    let width = lander_size.x;
+    let height = lander_size.y * WRECKAGE_HEIGHT_RATIO;
+    let max_x = (world_width - width).max(0.0);
+
+    attempt_sites
+        .iter()
+        .filter(|(_, result)| *result == AttemptResult::Failure)
+        .map(|(site, _)| {
+            // Keep the wreckage center at least a lander width from the spawn point
+            let mut center_x = site.x;
+            let offset = center_x - spawn_center_x;
+            if offset.abs() < width {
+                center_x = if offset < 0.0 { spawn_center_x - width } else { spawn_center_x + width };
+            }
+
+            let x = (center_x - width / 2.0).clamp(0.0, max_x);
+            let ground = peak_height_under(
+                terrain,
+                pixel_to_terrain_x(x, terrain.len(), world_width),
+                pixel_to_terrain_x(width, terrain.len(), world_width),
+            );
+
+            Rect::new(x, ground, width, height)
+        })
+        .collect()
+}
+
+/// Checks the lander against solid wreckage boxes.
+///
+/// Any contact with wreckage is a failure: touching it with the body is a body
+/// collision, touching it with a leg is a hard landing.
+///
+/// # Arguments
+///
+/// * `transform` - Lander position (bottom-left, camera coordinates) and size
+/// * `shape` - Lander collision geometry
+/// * `wreckage` - Wreckage rectangles from `wreckage_boxes`
+pub fn check_wreckage_collision(transform: &Transform, shape: &CollisionShape, wreckage: &[Rect]) -> CollisionType {
+    let zones = shape.zones(transform);
+
+    if wreckage.iter().any(|debris| debris.overlaps(&zones.body)) {
+        CollisionType::BodyCollision
+    } else if wreckage
+        .iter()
+        .any(|debris| debris.overlaps(&zones.left_leg) || debris.overlaps(&zones.right_leg))
+    {
+        CollisionType::LegCollision
+    } else {
+        CollisionType::None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::entity::{Entity, Transform, Collision};
     use crate::physics::Physics;
+    use crate::surface::roughen_landing_zones;
+
+    #[test]
+    fn test_collision_shape_zones() {
+        let transform = Transform {
+            size: Vec2::new(32.0, 32.0),
+            position: Vec2::new(100.0, 50.0),
+            rotation: 0.0,
+        };
+
+        // Default shape keeps the documented 25% leg height / 30% leg width
+        let shape = CollisionShape::default();
+        assert_eq!(shape.leg_height_ratio, LEG_HEIGHT_RATIO);
+        assert_eq!(shape.leg_width_ratio, LEG_WIDTH_RATIO);
+
+        let zones = shape.zones(&transform);
+        assert_eq!((zones.left_leg.x, zones.left_leg.y, zones.left_leg.h), (100.0, 50.0, 8.0));
+        assert!((zones.left_leg.w - 9.6).abs() < 0.001);
+        assert!((zones.right_leg.x - 122.4).abs() < 0.001);
+        assert_eq!(zones.right_leg.y, 50.0);
+        assert!((zones.body.x - 109.6).abs() < 0.001);
+        assert_eq!(zones.body.y, 58.0, "Body starts where the legs end");
+        assert!((zones.body.w - 12.8).abs() < 0.001);
+        assert_eq!(zones.body.h, 24.0);
+    }
+
+    #[test]
+    fn test_landing_angle_follows_surface_normal() {
+        // Zone on ground rising at 10° to the right
+        let slope = 10.0_f32.to_radians().tan() as f64;
+        let terrain: Vec<f64> = (0..400).map(|i| 100.0 + i as f64 * slope).collect();
+        let zones = vec![LandingZone {
+            start: 0,
+            end: 399,
+            difficulty: LandingZoneDifficulty::Easy,
+            width_points: 400,
+            motion: None,
+        }];
+        let mut transform = Transform {
+            size: Vec2::new(32.0, 32.0),
+            position: Vec2::new(100.0, terrain[100] as f32 + 1.0),
+            rotation: 10.0,
+        };
+        let tuning = LandingTuning::default();
+        let shape = tuning.shape;
+        assert!((surface_normal_angle(&terrain, &shape.zones(&transform), 400.0) - 10.0).abs() < 0.01);
+
+        // Flat to the slope lands; upright is only 10° off the normal and still lands
+        let slow = Some(Vec2::new(0.0, -2.0));
+        assert_eq!(detect_collision(&transform, &tuning, slow, &terrain, 400.0, &zones).0, CollisionType::LandingSuccess);
+        transform.rotation = 0.0;
+        assert_eq!(detect_collision(&transform, &tuning, slow, &terrain, 400.0, &zones).0, CollisionType::LandingSuccess);
+
+        // Leaning 10° away from the slope is 20° off the normal
+        transform.rotation = -10.0;
+        assert_eq!(detect_collision(&transform, &tuning, slow, &terrain, 400.0, &zones).0, CollisionType::LegCollision);
+
+        // Flat ground reduces to the plain vertical check
+        let flat = vec![0.0; 400];
+        assert_eq!(surface_normal_angle(&flat, &shape.zones(&transform), 400.0), 0.0);
+    }
+
+    #[test]
+    fn test_free_touchdown_matches_local_slope() {
+        // Rough ground rising at 20° to the right, with a notch under the lander
+        let slope = 20.0_f32.to_radians().tan() as f64;
+        let mut terrain: Vec<f64> = (0..400).map(|i| 100.0 + i as f64 * slope).collect();
+        terrain[116] -= 3.0;
+        let mut transform = Transform {
+            size: Vec2::new(32.0, 32.0),
+            position: Vec2::new(100.0, terrain[100] as f32),
+            rotation: 20.0,
+        };
+        assert!((local_slope_angle(&terrain, &transform, 400.0) - 20.0).abs() < 1.0, "The notch barely moves the fit");
+        assert_eq!(local_slope_angle(&[50.0; 400], &transform, 400.0), 0.0);
+        assert_eq!(local_slope_angle(&[50.0], &transform, 1.0), 0.0);
+
+        let tuning = LandingTuning::default();
+        let slow = Vec2::new(0.0, -2.0);
+        let (landed, angle) = assess_free_touchdown(&transform, &tuning, slow, &terrain, 400.0);
+        assert!(landed);
+        assert_eq!(angle, local_slope_angle(&terrain, &transform, 400.0), "The slope is reported back");
+
+        // Fast, or upright on the slope, doesn't hold
+        assert!(!assess_free_touchdown(&transform, &tuning, Vec2::new(0.0, -12.0), &terrain, 400.0).0);
+        transform.rotation = 0.0;
+        assert!(!assess_free_touchdown(&transform, &tuning, slow, &terrain, 400.0).0);
+    }
+
+    #[test]
+    fn test_tuning_sets_landing_limits() {
+        // Easy zones accept the tuned limits in full
+        let terrain = vec![50.0; 400];
+        let zones = vec![LandingZone {
+            start: 0,
+            end: 399,
+            difficulty: LandingZoneDifficulty::Easy,
+            width_points: 400,
+            motion: None,
+        }];
+        let transform = Transform {
+            size: Vec2::new(32.0, 32.0),
+            position: Vec2::new(100.0, 54.0),
+            rotation: 20.0,
+        };
+        let fast = Some(Vec2::new(0.0, -12.0));
+
+        // 12 m/s at 20° is a crash by default, and 4 units up is not yet contact
+        let default = LandingTuning::default();
+        assert_eq!(detect_collision(&transform, &default, fast, &terrain, 400.0, &zones).0, CollisionType::None);
+        let on_ground = Transform { position: Vec2::new(100.0, 52.0), ..transform };
+        assert_eq!(detect_collision(&on_ground, &default, fast, &terrain, 400.0, &zones).0, CollisionType::LegCollision);
+
+        let forgiving = LandingTuning {
+            collision_margin: 5.0,
+            max_landing_velocity: 14.0,
+            max_landing_angle_degrees: 25.0,
+            ..LandingTuning::default()
+        };
+        assert_eq!(detect_collision(&transform, &forgiving, fast, &terrain, 400.0, &zones).0, CollisionType::LandingSuccess);
+    }
+
+    #[test]
+    fn test_harder_zones_demand_gentler_landings() {
+        let terrain = vec![50.0; 400];
+        let tuning = LandingTuning::default();
+        let land = |difficulty, rotation: f32, speed: f32| {
+            let zones = vec![LandingZone { start: 0, end: 399, difficulty, width_points: 400, motion: None }];
+            let transform = Transform {
+                size: Vec2::new(32.0, 32.0),
+                position: Vec2::new(100.0, 51.0),
+                rotation,
+            };
+            detect_collision(&transform, &tuning, Some(Vec2::new(0.0, -speed)), &terrain, 400.0, &zones).0
+        };
+
+        assert_eq!(tuning.zone_limits(Some(LandingZoneDifficulty::Hard)), (6.0, 9.0));
+        assert_eq!(tuning.zone_limits(Some(LandingZoneDifficulty::Medium)), (8.0, 12.0));
+        assert_eq!(tuning.zone_limits(Some(LandingZoneDifficulty::Easy)), (10.0, 15.0));
+        assert_eq!(tuning.zone_limits(None), (MAX_LANDING_VELOCITY, MAX_LANDING_ANGLE_DEGREES));
+
+        // 7 m/s upright is gentle enough for Medium and Easy, but not Hard
+        assert_eq!(land(LandingZoneDifficulty::Easy, 0.0, 7.0), CollisionType::LandingSuccess);
+        assert_eq!(land(LandingZoneDifficulty::Medium, 0.0, 7.0), CollisionType::LandingSuccess);
+        assert_eq!(land(LandingZoneDifficulty::Hard, 0.0, 7.0), CollisionType::LegCollision);
+
+        // A slow touchdown leaning 10°: too much tilt only for Hard
+        assert_eq!(land(LandingZoneDifficulty::Medium, 10.0, 2.0), CollisionType::LandingSuccess);
+        assert_eq!(land(LandingZoneDifficulty::Hard, 10.0, 2.0), CollisionType::LegCollision);
+        assert_eq!(land(LandingZoneDifficulty::Hard, 8.0, 5.5), CollisionType::LandingSuccess);
+    }
+
+    #[test]
+    fn test_one_legged_touchdown_tips_over() {
+        let zones = vec![LandingZone { start: 0, end: 399, difficulty: LandingZoneDifficulty::Easy, width_points: 400, motion: None }];
+        let tuning = LandingTuning::default();
+        let transform = Transform {
+            size: Vec2::new(32.0, 32.0),
+            position: Vec2::new(100.0, 51.0),
+            rotation: -10.0,
+        };
+        let slow = Some(Vec2::new(0.0, -2.0));
+
+        // Level ground: both legs touch together
+        let flat = vec![50.0; 400];
+        let upright = Transform { rotation: 0.0, ..transform };
+        assert_eq!(detect_collision(&upright, &tuning, slow, &flat, 400.0, &zones).0, CollisionType::LandingSuccess);
+
+        // Ground stepping down 5 units between the legs: the right leg is still in the air
+        let mut step = vec![50.0; 400];
+        step[116..].fill(45.0);
+        assert_eq!(
+            detect_collision(&transform, &tuning, slow, &step, 400.0, &zones),
+            (CollisionType::UnstableLanding, Some(LandingZoneDifficulty::Easy))
+        );
+
+        // Mirrored, the left leg hangs instead
+        let mut step = vec![45.0; 400];
+        step[116..].fill(50.0);
+        let mirrored = Transform { rotation: 10.0, ..transform };
+        assert_eq!(detect_collision(&mirrored, &tuning, slow, &step, 400.0, &zones).0, CollisionType::UnstableLanding);
+
+        // Coming in too fast is still a crash rather than a tip-over
+        let fast = Some(Vec2::new(0.0, -12.0));
+        assert_eq!(detect_collision(&mirrored, &tuning, fast, &step, 400.0, &zones).0, CollisionType::LegCollision);
+    }
+
+    #[test]
+    fn test_level_touchdown_settles_on_rough_zone() {
+        // A Hard zone roughened as in the game, then scaled to camera units
+        let zones = vec![LandingZone { start: 100, end: 159, difficulty: LandingZoneDifficulty::Hard, width_points: 60, motion: None }];
+        let mut terrain = vec![50.0; 400];
+        roughen_landing_zones(&mut terrain, &zones, 100.0);
+        let terrain: Vec<f64> = terrain.iter().map(|h| h * 0.4 + 60.0).collect();
+        let tuning = LandingTuning::default();
+
+        // Ground under each leg, one terrain point per unit
+        let ground = |leg: Rect| {
+            (leg.x.ceil() as usize..=(leg.x + leg.w).floor() as usize)
+                .map(|i| terrain[i] as f32)
+                .fold(f32::MIN, f32::max)
+        };
+        let lander_at = |x: f32, y: f32| Transform { size: Vec2::new(32.0, 32.0), position: Vec2::new(x, y), rotation: 0.0 };
+
+        // Come down level where the pad tilts most across the legs
+        let (x, low, high) = (100..=127)
+            .map(|x| {
+                let legs = tuning.shape.zones(&lander_at(x as f32, 0.0));
+                let (left, right) = (ground(legs.left_leg), ground(legs.right_leg));
+                (x as f32, left.min(right), left.max(right))
+            })
+            .max_by(|a, b| (a.2 - a.1).total_cmp(&(b.2 - b.1)))
+            .unwrap();
+        assert!(high - low > 1.0, "Roughness should tilt the pad under the legs");
+
+        // The first leg has just reached the contact margin; the other is further up
+        let touchdown = lander_at(x, high + tuning.collision_margin);
+        assert!(touchdown.position.y - low > tuning.collision_margin);
+        assert_eq!(
+            detect_collision(&touchdown, &tuning, Some(Vec2::new(0.0, -1.0)), &terrain, 400.0, &zones),
+            (CollisionType::LandingSuccess, Some(LandingZoneDifficulty::Hard))
+        );
+    }
+
+    #[test]
+    fn test_collision_shape_drives_detection() {
+        // A rock under the middle of the lander only, nothing under the legs
+        let mut terrain = vec![0.0; 400];
+        for height in &mut terrain[110..=120] {
+            *height = 60.0;
+        }
+        let transform = Transform {
+            size: Vec2::new(32.0, 32.0),
+            position: Vec2::new(100.0, 55.0),
+            rotation: 0.0,
+        };
+
+        // Default legs are 8 tall, so the body bottom (63) touches the rock
+        let (collision, _) = detect_collision(&transform, &LandingTuning::default(), Some(Vec2::ZERO), &terrain, 400.0, &[]);
+        assert_eq!(collision, CollisionType::BodyCollision);
+
+        // Longer legs lift the body clear of the rock
+        let long_legs = LandingTuning {
+            shape: CollisionShape { leg_height_ratio: 0.5, ..CollisionShape::default() },
+            ..LandingTuning::default()
+        };
+        let (collision, _) = detect_collision(&transform, &long_legs, Some(Vec2::ZERO), &terrain, 400.0, &[]);
+        assert_eq!(collision, CollisionType::None);
+    }
+
+    #[test]
+    fn test_detection_scales_terrain_to_world_width() {
+        // 1000 points over an 800 pixel world: the zone (points 100-200) spans pixels 80-160
+        let terrain = vec![50.0; 1000];
+        let zones = vec![LandingZone { start: 100, end: 200, difficulty: LandingZoneDifficulty::Easy, width_points: 101, motion: None }];
+        let tuning = LandingTuning::default();
+        let slow = Some(Vec2::new(0.0, -2.0));
+        let at = |x: f32| Transform { size: Vec2::new(32.0, 32.0), position: Vec2::new(x, 51.0), rotation: 0.0 };
+
+        // Pixels 100-132 are points 125-165, inside the zone
+        assert_eq!(
+            detect_collision(&at(100.0), &tuning, slow, &terrain, 800.0, &zones),
+            (CollisionType::LandingSuccess, Some(LandingZoneDifficulty::Easy))
+        );
+        assert_eq!(zone_edge_distances(&at(100.0), &zones, terrain.len(), 800.0), Some((LandingZoneDifficulty::Easy, 25.0, 35.0)));
+
+        // Pixels 150-182 would fit a 1:1 terrain, but reach point 227, past the zone end
+        assert_eq!(detect_collision(&at(150.0), &tuning, slow, &terrain, 800.0, &zones).0, CollisionType::LegCollision);
+        assert_eq!(zone_edge_distances(&at(150.0), &zones, terrain.len(), 800.0), None);
+    }
+
+    #[test]
+    fn test_wreckage_rests_on_terrain_clear_of_spawn() {
+        let mut terrain = vec![50.0; 800];
+        terrain[205] = 70.0;
+        let sites = vec![
+            (Vec2::new(210.0, 0.0), AttemptResult::Failure),
+            (Vec2::new(600.0, 0.0), AttemptResult::Success),  // No wreckage for landings
+            (Vec2::new(395.0, 0.0), AttemptResult::Failure),  // Under the spawn point
+        ];
+
+        let boxes = wreckage_boxes(&sites, &terrain, 800.0, Vec2::new(32.0, 32.0), 400.0);
+        assert_eq!(boxes.len(), 2);
+
+        // Rests on the highest point beneath it
+        assert_eq!(boxes[0].x, 194.0);
+        assert_eq!(boxes[0].y, 70.0);
+        assert!((boxes[0].h - 12.8).abs() < 0.001);
+
+        // Pushed a lander width out of the spawn column
+        assert_eq!(boxes[1].x + boxes[1].w / 2.0, 368.0);
+
+        assert!(wreckage_boxes(&sites, &[], 800.0, Vec2::new(32.0, 32.0), 400.0).is_empty());
+    }
+
+    #[test]
+    fn test_wreckage_collision() {
+        let wreckage = vec![Rect::new(200.0, 50.0, 32.0, 12.0)];
+        let shape = CollisionShape::default();
+        let mut transform = Transform {
+            size: Vec2::new(32.0, 32.0),
+            position: Vec2::new(100.0, 55.0),
+            rotation: 0.0,
+        };
+        assert_eq!(check_wreckage_collision(&transform, &shape, &wreckage), CollisionType::None);
+
+        // Legs (bottom 8 units) dip into the top of the wreckage
+        transform.position = Vec2::new(200.0, 58.0);
+        assert_eq!(check_wreckage_collision(&transform, &shape, &wreckage), CollisionType::LegCollision);
+
+        // Sliding down onto it hits the body
+        transform.position = Vec2::new(200.0, 50.0);
+        assert_eq!(check_wreckage_collision(&transform, &shape, &wreckage), CollisionType::BodyCollision);
+    }
 
     #[test]
     fn test_is_on_flat_spot() {
@@ -532,18 +1108,21 @@ mod tests {
                 end: 120,
                 difficulty: LandingZoneDifficulty::Hard,
                 width_points: 20,
+                motion: None,
             },
             LandingZone {
                 start: 200,
                 end: 225,
                 difficulty: LandingZoneDifficulty::Medium,
                 width_points: 25,
+                motion: None,
             },
             LandingZone {
                 start: 300,
                 end: 330,
                 difficulty: LandingZoneDifficulty::Easy,
                 width_points: 30,
+                motion: None,
             },
         ];
         
@@ -0,0 +1,592 @@
+//! Runtime configuration for the lunar lander game.
+//!
+//! Options are read from command-line flags so they can be changed without
+//! recompiling, e.g. `cargo run -- --no-aa` on lower-end machines. Display
+//! preferences changed in game are remembered in `DisplaySettings`.
+
+use std::fs;
+use std::io;
+use std::ops::RangeInclusive;
+
+use macroquad::logging::warn;
+use macroquad::math::{vec2, Vec2};
+use serde::{Deserialize, Serialize};
+
+use crate::body::CelestialBody;
+use crate::physics::Integrator;
+use crate::surface::DEFAULT_ZONE_COUNT;
+use crate::theme::TerrainTheme;
+use crate::units::UnitSystem;
+use crate::utils::DEFAULT_VIRTUAL_RESOLUTION;
+
+/// File the display settings are kept in (JSON, next to `scores.json`)
+pub const DISPLAY_SETTINGS_FILE: &str = "display_settings.json";
+/// Number of MSAA samples requested when anti-aliasing is enabled
+const MSAA_SAMPLES: i32 = 4;
+/// Largest gravity tilt accepted, in degrees either side of straight down
+const MAX_GRAVITY_ANGLE: f32 = 45.0;
+/// Most landing zones that can be requested with `--zones`
+const MAX_ZONES: usize = 9;
+/// Smallest window accepted by `--window`, in pixels
+const MIN_WINDOW_SIZE: i32 = 320;
+/// Base window title; the selected modes are appended
+const WINDOW_TITLE: &str = "Lunar Lander";
+
+/// Game-wide options selected at startup
+#[derive(Debug, Clone)]
+pub struct GameConfig {
+    pub antialiasing: bool,     // Multisampled window + feathered terrain lines
+    pub single_use_zones: bool, // Each zone difficulty scores only once per session
+    pub assisted: bool,         // Start with the approach corridor guidance shown
+    pub theme: TerrainTheme,    // Terrain and landing zone colors
+    pub two_stage: bool,        // Lander carries a jettisonable descent stage
+    pub solid_wreckage: bool,   // Wreckage from failed attempts is an obstacle ("ironman")
+    pub exhaust_velocity: Option<f64>, // Engine exhaust velocity override (m/s)
+    pub vertical_wrap: bool,    // Flying off the top reappears at the bottom
+    pub gravity_angle: f32,     // Gravity direction in degrees from straight down
+    pub gravity_falloff: bool,  // Gravity grows toward the surface (inverse square)
+    pub body: CelestialBody,    // World to land on, which sets the surface gravity
+    pub wind: f32,              // Steady sideways wind force (N), positive to the right
+    pub gustiness: f32,         // Gust strength as a fraction of the wind (0.0-1.0)
+    pub drag_coefficient: f32,  // Atmospheric drag k (kg/m); 0 for no atmosphere
+    pub random_spawn: bool,     // Randomize start position and drift each attempt
+    pub telemetry: bool,        // Write a CSV flight log at the end of each attempt
+    pub same_terrain: bool,     // All attempts in a session share one terrain layout
+    pub integrator: Integrator, // Numerical integration scheme for the lander
+    pub confirm_restart: bool,  // Ask before R abandons a live attempt
+    pub units: Option<UnitSystem>, // HUD display units (default: the saved choice)
+    pub zone_count: RangeInclusive<usize>, // Number of landing zones per terrain
+    pub tuning_file: Option<String>, // Landing limits / leg geometry file (see tuning.rs)
+    pub window_size: Option<(i32, i32)>, // Initial window width and height (default: macroquad's)
+    pub practice: bool,         // Single Easy zone under the start, unscored attempts
+    pub flame_tint: bool,       // Tint the thrust flame by descent rate (off: classic white)
+    pub terrain_seed: Option<u64>, // Fixed terrain seed (default: a new time-based seed per terrain)
+    pub follow_camera: bool,    // Camera tracks the lander and zooms in near the surface
+    pub minimap: bool,          // Show the whole-terrain minimap (always on with the follow camera)
+    pub colorblind: bool,       // Start with shape markers on the landing zones
+    pub moving_pad: bool,       // One landing zone slides back and forth (challenge mode)
+    pub fuel_pickups: bool,     // Scatter collectable fuel canisters over the terrain
+    pub players: usize,         // Players taking turns at the controls (2 for hotseat)
+    pub daily: bool,            // Today's shared layout with the flying settings locked
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            antialiasing: true,
+            single_use_zones: false,
+            assisted: false,
+            theme: TerrainTheme::default(),
+            two_stage: false,
+            solid_wreckage: false,
+            exhaust_velocity: None,
+            vertical_wrap: false,
+            gravity_angle: 0.0,
+            gravity_falloff: false,
+            body: CelestialBody::default(),
+            wind: 0.0,
+            gustiness: 0.0,
+            drag_coefficient: 0.0,
+            random_spawn: false,
+            telemetry: false,
+            same_terrain: false,
+            integrator: Integrator::default(),
+            confirm_restart: true,
+            units: None,
+            zone_count: DEFAULT_ZONE_COUNT,
+            tuning_file: None,
+            window_size: None,
+            practice: false,
+            flame_tint: true,
+            terrain_seed: None,
+            follow_camera: false,
+            minimap: false,
+            colorblind: false,
+            moving_pad: false,
+            fuel_pickups: false,
+            players: 1,
+            daily: false,
+        }
+    }
+}
+
+impl GameConfig {
+    /// Builds the configuration from the process command-line arguments.
+    pub fn from_args() -> Self {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    /// Builds the configuration from a list of flags.
+    ///
+    /// Unknown flags are ignored so the game always starts.
+    ///
+    /// # Flags
+    ///
+    /// * `--aa` / `--no-aa` - Enable or disable anti-aliasing
+    /// * `--single-use-zones` - Landing zones are consumed after a successful landing
+    /// * `--assisted` - Show the approach corridor from the start (toggle in game with G)
+    /// * `--theme <name>` - Terrain color theme: `classic`, `lunar` or `mars`
+    /// * `--two-stage` - Fly a two-stage lander; jettison the descent stage with J
+    /// * `--ironman` - Wreckage from failed attempts stays on the surface as a solid obstacle
+    /// * `--exhaust-velocity <m/s>` - Engine exhaust velocity; must be positive
+    /// * `--vertical-wrap` - Wrap off the top of the screen (default: altitude is capped)
+    /// * `--gravity-angle <degrees>` - Tilt gravity from straight down, clamped to ±45°
+    /// * `--gravity-falloff` - Gravity weakens with height above the terrain (default: constant)
+    /// * `--body <name>` - Land on `moon` (default), `mars`, `earth` or `europa` (cycle in game with N)
+    /// * `--wind <newtons>` - Sideways wind force, negative blowing left (default: calm)
+    /// * `--gusts <fraction>` - Let the wind gust by up to this fraction of its strength, clamped to 0-1
+    /// * `--drag <k>` - Atmospheric drag coefficient in kg/m, F = -k·v·|v| (default 0: no atmosphere)
+    /// * `--random-spawn` - Randomize the horizontal start position and drift
+    /// * `--telemetry` - Record each attempt and write it to a CSV file when it ends
+    /// * `--same-terrain` - Fly all attempts of a session over the same terrain
+    /// * `--instant-restart` - R restarts a live attempt without asking for confirmation
+    /// * `--zones <n>` / `--zones <min>-<max>` - Landing zones per terrain (default 1-3, at most 9)
+    /// * `--units <name>` - HUD units: `metric` or `imperial` (default: the last choice made with U)
+    /// * `--integrator <name>` - Physics integrator: `euler`, `semi-implicit` (default) or `verlet`
+    /// * `--tuning <path>` - Load landing limits and leg geometry from a tuning file
+    /// * `--window <width>x<height>` - Initial window size, at least 320x320
+    /// * `--practice` - One Easy zone straight below the start; attempts are not scored
+    /// * `--classic-flame` - Keep the thrust flame white instead of tinting it by descent rate
+    /// * `--seed <n>` - Generate every terrain from this seed, for replaying a layout
+    /// * `--follow-cam` - Follow the lander and zoom in as it nears the surface (shows the minimap)
+    /// * `--minimap` - Show a minimap of the whole terrain in the bottom-left corner
+    /// * `--colorblind` - Mark landing zone difficulty with shapes as well as colors (toggle with K)
+    /// * `--moving-pad` - One landing zone slides back and forth along the surface
+    /// * `--fuel-pickups` - Scatter fuel canisters between the landing zones; touching one refuels
+    /// * `--hotseat` - Two players alternate attempts, each with their own lander and session
+    /// * `--daily` - Daily challenge: terrain seeded from today's date, every setting that
+    ///   changes the flying or scoring back at its default (see `into_daily`)
+    pub fn parse<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut config = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_ref() {
+                "--aa" => config.antialiasing = true,
+                "--no-aa" => config.antialiasing = false,
+                "--single-use-zones" => config.single_use_zones = true,
+                "--assisted" => config.assisted = true,
+                "--two-stage" => config.two_stage = true,
+                "--ironman" => config.solid_wreckage = true,
+                "--vertical-wrap" => config.vertical_wrap = true,
+                "--gravity-falloff" => config.gravity_falloff = true,
+                "--random-spawn" => config.random_spawn = true,
+                "--telemetry" => config.telemetry = true,
+                "--same-terrain" => config.same_terrain = true,
+                "--instant-restart" => config.confirm_restart = false,
+                "--practice" => config.practice = true,
+                "--classic-flame" => config.flame_tint = false,
+                "--gravity-angle" => {
+                    let value = args.next().and_then(|value| value.as_ref().parse::<f32>().ok());
+                    if let Some(angle) = value.filter(|angle| angle.is_finite()) {
+                        config.gravity_angle = angle.clamp(-MAX_GRAVITY_ANGLE, MAX_GRAVITY_ANGLE);
+                    }
+                }
+                "--wind" => {
+                    let value = args.next().and_then(|value| value.as_ref().parse::<f32>().ok());
+                    if let Some(force) = value.filter(|force| force.is_finite()) {
+                        config.wind = force;
+                    }
+                }
+                "--gusts" => {
+                    let value = args.next().and_then(|value| value.as_ref().parse::<f32>().ok());
+                    if let Some(fraction) = value.filter(|fraction| fraction.is_finite()) {
+                        config.gustiness = fraction.clamp(0.0, 1.0);
+                    }
+                }
+                "--drag" => {
+                    let value = args.next().and_then(|value| value.as_ref().parse::<f32>().ok());
+                    if let Some(k) = value.filter(|k| k.is_finite() && *k >= 0.0) {
+                        config.drag_coefficient = k;
+                    }
+                }
+                "--exhaust-velocity" => {
+                    let value = args.next().and_then(|value| value.as_ref().parse::<f64>().ok());
+                    if let Some(value) = value.filter(|value| value.is_finite() && *value > 0.0) {
+                        config.exhaust_velocity = Some(value);
+                    }
+                }
+                "--zones" => {
+                    if let Some(range) = args.next().and_then(|value| parse_zone_count(value.as_ref())) {
+                        config.zone_count = range;
+                    }
+                }
+                "--units" => {
+                    if let Some(units) = args.next().and_then(|name| UnitSystem::from_name(name.as_ref())) {
+                        config.units = Some(units);
+                    }
+                }
+                "--body" => {
+                    if let Some(body) = args.next().and_then(|name| CelestialBody::from_name(name.as_ref())) {
+                        config.body = body;
+                    }
+                }
+                "--integrator" => {
+                    if let Some(integrator) = args.next().and_then(|name| Integrator::from_name(name.as_ref())) {
+                        config.integrator = integrator;
+                    }
+                }
+                "--window" => {
+                    if let Some(size) = args.next().and_then(|value| parse_window_size(value.as_ref())) {
+                        config.window_size = Some(size);
+                    }
+                }
+                "--follow-cam" => config.follow_camera = true,
+                "--minimap" => config.minimap = true,
+                "--colorblind" => config.colorblind = true,
+                "--moving-pad" => config.moving_pad = true,
+                "--fuel-pickups" => config.fuel_pickups = true,
+                "--hotseat" => config.players = 2,
+                "--daily" => config.daily = true,
+                "--seed" => {
+                    if let Some(seed) = args.next().and_then(|value| value.as_ref().parse::<u64>().ok()) {
+                        config.terrain_seed = Some(seed);
+                    }
+                }
+                "--tuning" => {
+                    if let Some(path) = args.next() {
+                        config.tuning_file = Some(path.as_ref().to_string());
+                    }
+                }
+                "--theme" => {
+                    if let Some(theme) = args.next().and_then(|name| TerrainTheme::from_name(name.as_ref())) {
+                        config.theme = theme;
+                    }
+                }
+                _ => {}
+            }
+        }
+        if config.daily {
+            config = config.into_daily();
+        }
+        config
+    }
+
+    /// Locks the configuration for the daily challenge so everyone's scores compare.
+    ///
+    /// Display and comfort options carry over; body, engine, physics, terrain
+    /// and scoring options return to their defaults. The terrain seed is left
+    /// unset for the caller to derive from the date.
+    fn into_daily(self) -> Self {
+        Self {
+            antialiasing: self.antialiasing,
+            assisted: self.assisted,
+            theme: self.theme,
+            telemetry: self.telemetry,
+            confirm_restart: self.confirm_restart,
+            units: self.units,
+            window_size: self.window_size,
+            flame_tint: self.flame_tint,
+            follow_camera: self.follow_camera,
+            minimap: self.minimap,
+            colorblind: self.colorblind,
+            players: self.players,
+            daily: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the MSAA sample count to request for the window.
+    ///
+    /// # Returns
+    ///
+    /// `4` when anti-aliasing is enabled, `1` (no multisampling) otherwise
+    pub fn msaa_samples(&self) -> i32 {
+        if self.antialiasing {
+            MSAA_SAMPLES
+        } else {
+            1
+        }
+    }
+
+    /// Returns the virtual resolution gameplay runs in.
+    ///
+    /// This is the initial window size and stays fixed for the whole run;
+    /// resizing the window later only letterboxes the same world.
+    ///
+    /// # Returns
+    ///
+    /// The `--window` size, or 800x600 without one
+    pub fn virtual_resolution(&self) -> Vec2 {
+        self.window_size
+            .map(|(width, height)| vec2(width as f32, height as f32))
+            .unwrap_or(DEFAULT_VIRTUAL_RESOLUTION)
+    }
+
+    /// Returns the window title, naming the theme and game modes in play.
+    ///
+    /// # Returns
+    ///
+    /// `"Lunar Lander"` for the default setup, e.g. `"Lunar Lander — Mars / Ironman"` otherwise
+    pub fn window_title(&self) -> String {
+        let mut modes = Vec::new();
+        if self.daily {
+            modes.push("Daily Challenge".to_string());
+        }
+        if self.theme != TerrainTheme::default() {
+            let mut name = self.theme.name.to_string();
+            name[..1].make_ascii_uppercase();
+            modes.push(name);
+        }
+        // A Mars theme on Mars already names the body
+        if self.body != CelestialBody::default() && !self.body.name().eq_ignore_ascii_case(self.theme.name) {
+            modes.push(self.body.name().to_string());
+        }
+        if self.two_stage {
+            modes.push("Two-Stage".to_string());
+        }
+        if self.solid_wreckage {
+            modes.push("Ironman".to_string());
+        }
+        if self.practice {
+            modes.push("Practice".to_string());
+        }
+        if self.moving_pad {
+            modes.push("Moving Pad".to_string());
+        }
+        if self.fuel_pickups {
+            modes.push("Fuel Pickups".to_string());
+        }
+        if self.players > 1 {
+            modes.push("Hotseat".to_string());
+        }
+        if self.wind != 0.0 {
+            modes.push("Windy".to_string());
+        }
+        if self.drag_coefficient > 0.0 {
+            modes.push("Atmosphere".to_string());
+        }
+
+        if modes.is_empty() {
+            WINDOW_TITLE.to_string()
+        } else {
+            format!("{} — {}", WINDOW_TITLE, modes.join(" / "))
+        }
+    }
+}
+
+/// Display preferences remembered between runs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct DisplaySettings {
+    pub units: UnitSystem, // HUD units last chosen with U or `--units`
+}
+
+impl DisplaySettings {
+    /// Reads the settings file, using the defaults if it is missing or corrupt.
+    pub fn load(path: &str) -> Self {
+        match fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).unwrap_or_else(|err| {
+                warn!("Ignoring corrupt display settings {}: {}", path, err);
+                Self::default()
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(err) => {
+                warn!("Could not read {}: {}", path, err);
+                Self::default()
+            }
+        }
+    }
+
+    /// Writes the settings file.
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json)
+    }
+}
+
+/// Parses a window size of the form `<width>x<height>`, each at least `MIN_WINDOW_SIZE`.
+fn parse_window_size(value: &str) -> Option<(i32, i32)> {
+    let (width, height) = value.split_once(['x', 'X'])?;
+    let (width, height) = (width.trim().parse().ok()?, height.trim().parse().ok()?);
+    (width >= MIN_WINDOW_SIZE && height >= MIN_WINDOW_SIZE).then_some((width, height))
+}
+
+/// Parses a zone count of the form `n` or `min-max`, both between 1 and `MAX_ZONES`.
+fn parse_zone_count(value: &str) -> Option<RangeInclusive<usize>> {
+    let (min, max) = match value.split_once('-') {
+        Some((min, max)) => (min.trim().parse().ok()?, max.trim().parse().ok()?),
+        None => {
+            let count = value.trim().parse().ok()?;
+            (count, count)
+        }
+    };
+    (1 <= min && min <= max && max <= MAX_ZONES).then_some(min..=max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_flags() {
+        let config = GameConfig::parse(Vec::<String>::new());
+        assert!(config.antialiasing, "Anti-aliasing should default to on");
+        assert_eq!(config.msaa_samples(), 4);
+
+        let config = GameConfig::parse(["--no-aa"]);
+        assert!(!config.antialiasing);
+        assert_eq!(config.msaa_samples(), 1);
+
+        // Later flags win, unknown flags are ignored
+        let config = GameConfig::parse(["--no-aa", "--bogus", "--aa"]);
+        assert!(config.antialiasing);
+        assert!(!config.single_use_zones);
+
+        let config = GameConfig::parse(["--single-use-zones", "--assisted"]);
+        assert!(config.single_use_zones);
+        assert!(config.assisted);
+        assert_eq!(config.theme, TerrainTheme::CLASSIC);
+
+        // Unknown theme names keep the default
+        let config = GameConfig::parse(["--theme", "mars"]);
+        assert_eq!(config.theme, TerrainTheme::MARTIAN);
+        let config = GameConfig::parse(["--theme", "plaid", "--assisted"]);
+        assert_eq!(config.theme, TerrainTheme::CLASSIC);
+        assert!(!config.two_stage);
+
+        let config = GameConfig::parse(["--two-stage", "--ironman"]);
+        assert!(config.two_stage);
+        assert!(config.solid_wreckage);
+
+        let config = GameConfig::parse(["--exhaust-velocity", "4200"]);
+        assert_eq!(config.exhaust_velocity, Some(4200.0));
+        let config = GameConfig::parse(["--exhaust-velocity", "-5"]);
+        assert_eq!(config.exhaust_velocity, None);
+        assert!(!config.vertical_wrap, "Vertical wrap should default to off");
+
+        let config = GameConfig::parse(["--vertical-wrap"]);
+        assert!(config.vertical_wrap);
+        assert_eq!(config.gravity_angle, 0.0);
+
+        let config = GameConfig::parse(["--gravity-angle", "-20"]);
+        assert_eq!(config.gravity_angle, -20.0);
+        let config = GameConfig::parse(["--gravity-angle", "90"]);
+        assert_eq!(config.gravity_angle, 45.0, "Tilt is clamped");
+        assert!(!config.gravity_falloff);
+        assert!(GameConfig::parse(["--gravity-falloff"]).gravity_falloff);
+        assert!(!config.random_spawn);
+
+        let config = GameConfig::parse(["--random-spawn", "--telemetry"]);
+        assert!(config.random_spawn);
+        assert!(config.telemetry);
+        assert!(!config.same_terrain);
+
+        let config = GameConfig::parse(["--same-terrain"]);
+        assert!(config.same_terrain);
+
+        assert_eq!(GameConfig::default().integrator, Integrator::SemiImplicitEuler);
+        let config = GameConfig::parse(["--integrator", "verlet"]);
+        assert_eq!(config.integrator, Integrator::VelocityVerlet);
+        let config = GameConfig::parse(["--integrator", "rk4"]);
+        assert_eq!(config.integrator, Integrator::SemiImplicitEuler);
+        assert!(config.confirm_restart);
+
+        let config = GameConfig::parse(["--instant-restart"]);
+        assert!(!config.confirm_restart);
+        assert_eq!(config.units, None, "Without --units the saved choice is used");
+
+        let config = GameConfig::parse(["--units", "imperial"]);
+        assert_eq!(config.units, Some(UnitSystem::Imperial));
+        assert_eq!(config.zone_count, DEFAULT_ZONE_COUNT);
+
+        assert_eq!(GameConfig::parse(["--zones", "1"]).zone_count, 1..=1);
+        assert_eq!(GameConfig::parse(["--zones", "2-5"]).zone_count, 2..=5);
+        assert_eq!(GameConfig::parse(["--zones", "5-2"]).zone_count, DEFAULT_ZONE_COUNT);
+        assert_eq!(GameConfig::parse(["--zones", "0"]).zone_count, DEFAULT_ZONE_COUNT);
+
+        assert_eq!(config.tuning_file, None);
+        let config = GameConfig::parse(["--tuning", "easy.tuning"]);
+        assert_eq!(config.tuning_file.as_deref(), Some("easy.tuning"));
+
+        assert_eq!(config.window_size, None);
+        assert_eq!(GameConfig::parse(["--window", "1280x800"]).window_size, Some((1280, 800)));
+        assert_eq!(GameConfig::parse(["--window", "100x80"]).window_size, None);
+        assert_eq!(GameConfig::parse(["--window", "wide"]).window_size, None);
+        assert_eq!(config.virtual_resolution(), vec2(800.0, 600.0));
+        assert_eq!(GameConfig::parse(["--window", "1280x800"]).virtual_resolution(), vec2(1280.0, 800.0));
+
+        assert_eq!(GameConfig::default().window_title(), "Lunar Lander");
+        assert!(!config.practice);
+        assert!(GameConfig::parse(["--practice"]).practice);
+        assert!(config.flame_tint);
+        assert!(!GameConfig::parse(["--classic-flame"]).flame_tint);
+        assert_eq!(config.terrain_seed, None);
+        assert_eq!(GameConfig::parse(["--seed", "42"]).terrain_seed, Some(42));
+        assert_eq!(GameConfig::parse(["--seed", "-1"]).terrain_seed, None);
+        assert!(!config.follow_camera);
+        assert!(GameConfig::parse(["--follow-cam"]).follow_camera);
+        assert!(!config.minimap);
+        assert!(GameConfig::parse(["--minimap"]).minimap);
+        assert!(!config.colorblind);
+        assert!(GameConfig::parse(["--colorblind"]).colorblind);
+        assert!(!config.moving_pad);
+        assert!(!config.fuel_pickups);
+        assert_eq!(config.players, 1);
+        let config = GameConfig::parse(["--hotseat"]);
+        assert_eq!(config.players, 2);
+        assert_eq!(config.window_title(), "Lunar Lander — Hotseat");
+        assert_eq!(
+            GameConfig::parse(["--fuel-pickups", "--practice"]).window_title(),
+            "Lunar Lander — Practice / Fuel Pickups"
+        );
+        assert_eq!(config.wind, 0.0);
+        let config = GameConfig::parse(["--wind", "-1500", "--gusts", "2"]);
+        assert_eq!((config.wind, config.gustiness), (-1500.0, 1.0));
+        assert_eq!(config.window_title(), "Lunar Lander — Windy");
+        assert_eq!(GameConfig::parse(["--wind", "inf"]).wind, 0.0);
+        assert_eq!(GameConfig::default().drag_coefficient, 0.0);
+        assert_eq!(GameConfig::default().body, CelestialBody::Moon);
+        let config = GameConfig::parse(["--body", "Europa", "--two-stage"]);
+        assert_eq!(config.body, CelestialBody::Europa);
+        assert_eq!(config.window_title(), "Lunar Lander — Europa / Two-Stage");
+        assert_eq!(GameConfig::parse(["--body", "pluto"]).body, CelestialBody::Moon);
+        assert_eq!(GameConfig::parse(["--drag", "1500"]).drag_coefficient, 1500.0);
+        assert_eq!(GameConfig::parse(["--drag", "-3"]).drag_coefficient, 0.0);
+        assert_eq!(
+            GameConfig::parse(["--moving-pad"]).window_title(),
+            "Lunar Lander — Moving Pad"
+        );
+        assert_eq!(
+            GameConfig::parse(["--theme", "mars", "--ironman"]).window_title(),
+            "Lunar Lander — Mars / Ironman"
+        );
+        assert_eq!(
+            GameConfig::parse(["--theme", "mars", "--body", "mars"]).window_title(),
+            "Lunar Lander — Mars"
+        );
+        assert_eq!(
+            GameConfig::parse(["--theme", "lunar", "--body", "mars"]).window_title(),
+            "Lunar Lander — Lunar / Mars"
+        );
+
+        // The daily challenge keeps display options but not the ones that change the score
+        let config = GameConfig::parse([
+            "--body", "mars", "--two-stage", "--fuel-pickups", "--seed", "42", "--wind", "3", "--daily", "--colorblind",
+        ]);
+        assert!(config.daily);
+        assert_eq!(config.body, CelestialBody::Moon);
+        assert!(!config.two_stage);
+        assert!(!config.fuel_pickups);
+        assert_eq!(config.terrain_seed, None);
+        assert_eq!(config.wind, 0.0);
+        assert!(config.colorblind);
+        assert_eq!(config.window_title(), "Lunar Lander — Daily Challenge");
+    }
+
+    #[test]
+    fn test_display_settings_round_trip() {
+        let path = std::env::temp_dir().join(format!("lunar_lander_display_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        assert_eq!(DisplaySettings::load(path).units, UnitSystem::Metric, "Missing file starts metric");
+
+        DisplaySettings { units: UnitSystem::Imperial }.save(path).unwrap();
+        assert_eq!(DisplaySettings::load(path).units, UnitSystem::Imperial);
+
+        fs::write(path, "imperial").unwrap();
+        assert_eq!(DisplaySettings::load(path), DisplaySettings::default());
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -1,14 +1,35 @@
+use std::ops::RangeInclusive;
+
 use macroquad::prelude::*;
 use macroquad_text::Fonts;
+use ::rand::rngs::StdRng;
+use ::rand::{Rng, SeedableRng};
 
 use crate::assets::{load_fonts,load_lander_textures};
-use crate::physics::{Physics, RocketEngine};
-use crate::surface::{self, LandingZone};
-use crate::utils::transform_axes;
+use crate::body::CelestialBody;
+use crate::physics::{gravity_at_altitude, Integrator, Physics, RocketEngine};
+use crate::particles::ParticleSystem;
+use crate::pickups::{self, FuelPickup, PICKUP_COUNT, PICKUP_FUEL};
+use crate::radio::{RadioLog, GO_FOR_LANDING};
+use crate::replay::{Recorder, ReplayFrame};
+use crate::starfield::Starfield;
+use crate::sim::FixedStepClock;
+use crate::telemetry::TelemetryLog;
+use crate::tuning::LandingTuning;
+use crate::units::UnitSystem;
+use crate::surface::{self, peak_height_under, LandingZone};
+use crate::utils::{pixel_to_terrain_x, transform_axes, DEFAULT_VIRTUAL_RESOLUTION};
+use crate::wind::Wind;
 
 const TERRAIN_Y_OFFSET: f64 = 75.0;
 const TEXTURE_SCALE_LANDER_X: f32 = 0.5;
 const TEXTURE_SCALE_LANDER_Y: f32 = 0.5;
+const SPAWN_OFFSET_FRACTION: f32 = 0.35; // Random start stays within 35% of the width from center
+const MAX_SPAWN_DRIFT: f32 = 8.0;        // Largest random initial horizontal speed (m/s)
+const SPAWN_CLEARANCE_HEIGHTS: f32 = 3.0; // Minimum start height above terrain, in lander heights
+const MAX_INTERPOLATION_JUMP: f32 = 100.0; // Larger moves in one step are wraps, drawn without interpolation
+const MOVING_PAD_SPEED: f32 = 40.0;       // Moving pad speed (terrain points per second)
+const MOVING_PAD_REACH: usize = 150;      // Farthest the moving pad travels from where it was placed (points)
 
 #[derive(Debug)]
 pub struct Line {
@@ -24,6 +45,44 @@ pub struct Transform {
     pub rotation: f32,
 }
 
+impl Transform {
+    /// Moves the transform to a start position, standing upright (rotation 0°).
+    ///
+    /// Used when an attempt starts, so a new attempt never inherits the
+    /// attitude the previous one ended in.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Bottom-left corner in camera coordinates
+    pub fn place_upright(&mut self, position: Vec2) {
+        self.position = position;
+        self.rotation = 0.0;
+    }
+
+    /// Pose between this (previous step) transform and `current`.
+    ///
+    /// Position and rotation both advance per physics step, so both are
+    /// blended. Rotation turns along the shorter arc, so a lander swinging
+    /// through 0°/360° doesn't spin the long way round for a frame. A move
+    /// longer than `MAX_INTERPOLATION_JUMP` is a screen wrap and is not
+    /// blended, so the lander never streaks across the screen.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - Transform after the latest physics step
+    /// * `alpha` - Fraction of the way from this transform to `current`
+    pub fn interpolate(&self, current: &Transform, alpha: f32) -> Transform {
+        let alpha = alpha.clamp(0.0, 1.0);
+        let mut blended = current.clone();
+        if self.position.distance(current.position) <= MAX_INTERPOLATION_JUMP {
+            blended.position = self.position.lerp(current.position, alpha);
+        }
+        let turn = (current.rotation - self.rotation + 180.0).rem_euclid(360.0) - 180.0;
+        blended.rotation = self.rotation + turn * alpha;
+        blended
+    }
+}
+
 pub struct Renderer {
     pub lander_texture: Texture2D,
     // Other rendering properties
@@ -31,6 +90,25 @@ pub struct Renderer {
 
 pub struct Input;
 
+/// HUD verbosity levels, cycled with the H key
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HudMode {
+    Full,    // Mission, fuel, mass, zones, speeds and thrust
+    Minimal, // Just fuel and speed
+    Off,     // No flight HUD
+}
+
+impl HudMode {
+    /// Returns the next verbosity level in the cycle Full -> Minimal -> Off -> Full.
+    pub fn next(self) -> Self {
+        match self {
+            HudMode::Full => HudMode::Minimal,
+            HudMode::Minimal => HudMode::Off,
+            HudMode::Off => HudMode::Full,
+        }
+    }
+}
+
 pub struct Collision {
     pub collider: Rect,
 }
@@ -38,7 +116,9 @@ pub struct Collision {
 // Define entities
 pub struct Entity<'a> {
     pub transform: Transform,
+    pub previous_transform: Transform, // Transform before the latest physics step, for render interpolation
     pub terrain: Vec<f64>,
+    pub world_size: Vec2,                // Virtual resolution gameplay runs in; the terrain spans its width
     pub flat_spots: Vec<(usize, usize)>, // Legacy flat spot ranges for backward compatibility
     pub landing_zones: Vec<LandingZone>, // New multiple landing zones with difficulty
     pub screen_fonts: Fonts<'a>,
@@ -48,14 +128,67 @@ pub struct Entity<'a> {
     pub renderer_thrust: Option<Renderer>,
     pub input: Option<Input>,
     pub collision: Option<Collision>,
+    pub tuning: LandingTuning,        // Leg/body layout and landing limits shared by collision and debug rendering
     pub sound: bool,
-    pub time_elapsed: f32,
+    pub muted: bool,                  // All audio off (M), remembered in the audio settings file
+    pub low_fuel_warned: bool,        // Low-fuel beep is sounding; set on crossing the threshold
+    pub low_fuel_beep_at: f64,        // get_time() of the latest low-fuel beep
+    pub time_elapsed: f32,            // Simulation time, advanced in fixed physics steps
+    pub physics_clock: FixedStepClock, // Turns frame time into fixed physics steps
+    pub attempt_start_time: Option<f32>, // time_elapsed at first thrust input (descent commit)
+    pub distance_traveled: f32, // Horizontal distance integrated from velocity this attempt (m; one world pixel is a meter)
+    pub max_altitude: f32,      // Highest altitude above the terrain reached this attempt
     pub show_debug_info: bool,
+    pub hud_mode: HudMode,
+    pub show_session_status: bool,
+    pub show_lifetime_stats: bool,    // Lifetime statistics screen (L)
+    pub show_leaderboard: bool,       // Top sessions overlay (T); pauses the game while open
+    pub paused: bool,                 // Game paused with P
+    pub show_approach_corridor: bool, // Assisted-mode glide path guidance
+    pub colorblind: bool,             // Mark zone difficulty with shapes as well as colors (K)
+    pub radio: RadioLog,              // Mission-control messages shown in the HUD
+    pub exhaust: ParticleSystem,      // Engine exhaust particles, in camera coordinates
+    pub starfield: Starfield,         // Background stars, generated once from a fixed seed
+    pub shake_intensity: f32,         // Peak camera jitter (pixels) from the latest crash; 0 for none
+    pub shake_started_at: f64,        // get_time() of the crash that started the shake
+    pub confirm_restart: bool,        // Restarting a live attempt needs a second R press
+    pub units: UnitSystem,            // Units used for HUD readouts (physics stays SI)
+    pub zone_count: RangeInclusive<usize>, // How many landing zones each terrain gets
+    pub terrain_seed: Option<u64>,    // Fixed terrain seed; None picks a time-based seed per terrain
+    pub layout_seed: u64,             // Seed the current terrain was generated from
+    pub start_rng: StdRng,            // Spawn offsets, drift and pickup placement, seeded from each new layout's seed
+    pub restart_requested_at: Option<f64>, // Time of the first R press awaiting confirmation
+    pub gravity_angle: f32,           // Gravity direction in degrees from straight down
+    pub gravity_falloff: bool,        // Gravity grows toward the surface instead of staying constant
+    pub vertical_wrap: bool,          // Flying off the top reappears at the bottom instead of hitting a ceiling
+    pub body: CelestialBody,          // World being landed on, which sets the surface gravity
+    pub wind: Wind,                   // Sideways wind force; calm by default
+    pub random_spawn: bool,           // Randomize the start position and drift each attempt
+    pub spawn_offset: f32,            // Horizontal start offset from screen center this attempt
+    pub spawn_drift: f32,             // Initial horizontal velocity this attempt (m/s)
+    pub telemetry: Option<TelemetryLog>, // Flight recording for the current attempt, if enabled
+    pub replay: Recorder,             // Frames of the current attempt for instant replay
+    pub replay_started_at: Option<f64>, // get_time() when the replay began; None when not replaying
+    pub reuse_terrain: bool,          // Keep the same terrain for every attempt of a session
+    pub practice: bool,               // One Easy zone under the start, attempts are not scored
+    pub moving_pad: bool,             // The first landing zone of each terrain slides back and forth
+    pub fuel_pickups_enabled: bool,   // Scatter fuel canisters over the terrain each attempt
+    pub daily_challenge: bool,        // Flying the daily challenge; the body and engine can't be changed
+    pub fuel_pickups: Vec<FuelPickup>, // Canisters not yet collected this attempt
+    pub thrust_blocked_time: f32,     // How long thrust has been wasted against a surface (s)
+    pub integrator: Integrator,       // Numerical integrator for the lander's motion
+    pub drag_coefficient: f32,        // Atmospheric drag k (kg/m); 0 for the airless Moon
     pub dead: bool,
     pub mission_success: bool,
     pub current_audio: Option<String>,
 }
 
+impl Default for Entity<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a> Entity<'a> {
     pub fn new() -> Self {
         Entity {
@@ -64,7 +197,13 @@ impl<'a> Entity<'a> {
                 position: Vec2::new(0.0, 0.0),
                 rotation: 0.0,
             },
+            previous_transform: Transform {
+                size: Vec2::new(0.0, 0.0),
+                position: Vec2::new(0.0, 0.0),
+                rotation: 0.0,
+            },
             terrain: Vec::new(),
+            world_size: DEFAULT_VIRTUAL_RESOLUTION,
             flat_spots: Vec::new(),
             landing_zones: Vec::new(),
             screen_fonts: load_fonts(),
@@ -76,18 +215,239 @@ impl<'a> Entity<'a> {
             collision: Some(Collision {
                 collider: Rect::new(0.0, 0.0, 64.0, 64.0),
             }),
+            tuning: LandingTuning::default(),
             sound: true,
+            muted: false,
+            low_fuel_warned: false,
+            low_fuel_beep_at: 0.0,
             time_elapsed: 0.0,
+            physics_clock: FixedStepClock::default(),
+            attempt_start_time: None,
+            distance_traveled: 0.0,
+            max_altitude: 0.0,
             show_debug_info: false,
+            hud_mode: HudMode::Full,
+            show_session_status: true,
+            show_lifetime_stats: false,
+            show_leaderboard: false,
+            paused: false,
+            show_approach_corridor: false,
+            colorblind: false,
+            radio: RadioLog::new(),
+            exhaust: ParticleSystem::new(),
+            starfield: Starfield::default(),
+            shake_intensity: 0.0,
+            shake_started_at: 0.0,
+            confirm_restart: true,
+            units: UnitSystem::default(),
+            zone_count: surface::DEFAULT_ZONE_COUNT,
+            terrain_seed: None,
+            layout_seed: 0,
+            start_rng: StdRng::seed_from_u64(0),
+            restart_requested_at: None,
+            gravity_angle: 0.0,
+            gravity_falloff: false,
+            vertical_wrap: false,
+            body: CelestialBody::default(),
+            wind: Wind::default(),
+            random_spawn: false,
+            spawn_offset: 0.0,
+            spawn_drift: 0.0,
+            telemetry: None,
+            replay: Recorder::default(),
+            replay_started_at: None,
+            reuse_terrain: false,
+            practice: false,
+            moving_pad: false,
+            fuel_pickups_enabled: false,
+            daily_challenge: false,
+            fuel_pickups: Vec::new(),
+            thrust_blocked_time: 0.0,
+            integrator: Integrator::default(),
+            drag_coefficient: 0.0,
             dead: false,
             mission_success: false,
             current_audio: None,
         }
     }
 
-    pub fn initialize_with_terrain_and_position(&mut self, lander_texture_size: Vec2) {
-        let current_screen_width = screen_width();
-        let num_points = current_screen_width as usize;
+    /// Drops the current terrain so the next reset generates a new layout.
+    ///
+    /// Called when a new session starts, so same-terrain sessions still get a
+    /// fresh map each session.
+    pub fn discard_terrain(&mut self) {
+        self.stop_replay();
+        self.terrain.clear();
+        self.landing_zones.clear();
+        self.flat_spots.clear();
+    }
+
+    /// Starts replaying the finished attempt, showing the terrain as it was flown.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the attempt is still live or nothing was recorded
+    pub fn start_replay(&mut self, now: f64) -> bool {
+        if !self.dead || self.replay.is_empty() {
+            return false;
+        }
+        if self.replay_started_at.is_none() {
+            self.replay.swap_terrain(&mut self.terrain);
+        }
+        self.replay_started_at = Some(now);
+        true
+    }
+
+    /// Ends the replay and puts the current terrain back.
+    pub fn stop_replay(&mut self) {
+        if self.replay_started_at.take().is_some() {
+            self.replay.swap_terrain(&mut self.terrain);
+        }
+    }
+
+    /// Recorded frame to draw at `now`, while a replay is running.
+    pub fn replay_frame(&self, now: f64) -> Option<ReplayFrame> {
+        let started_at = self.replay_started_at?;
+        self.replay.frame_at((now - started_at) as f32)
+    }
+
+    /// Transform to draw this frame, between the last two physics steps.
+    ///
+    /// Collision and physics always use `transform`; this is for rendering
+    /// only. A dead lander is drawn where it stopped.
+    pub fn render_transform(&self) -> Transform {
+        if self.dead {
+            self.transform.clone()
+        } else {
+            self.previous_transform.interpolate(&self.transform, self.physics_clock.alpha())
+        }
+    }
+
+    /// Where the lander appears on screen at `now`: the replay frame while a
+    /// replay runs, otherwise `render_transform`.
+    pub fn displayed_transform(&self, now: f64) -> Transform {
+        match self.replay_frame(now) {
+            Some(frame) => Transform {
+                size: self.transform.size,
+                position: frame.position,
+                rotation: frame.rotation,
+            },
+            None => self.render_transform(),
+        }
+    }
+
+    /// Slides any moving landing pad on by `dt` seconds, carrying its flat ground along.
+    pub fn update_zones(&mut self, dt: f32) {
+        surface::update_zones(&mut self.terrain, &mut self.landing_zones, dt);
+        for (spot, zone) in self.flat_spots.iter_mut().zip(&self.landing_zones) {
+            *spot = (zone.start, zone.end);
+        }
+    }
+
+    /// Tops up the tank from any canister the lander touches, removing it.
+    ///
+    /// # Returns
+    ///
+    /// Fuel taken on in kg; a canister collected with a full tank is still used up
+    pub fn collect_fuel_pickups(&mut self) -> f64 {
+        if self.fuel_pickups.is_empty() {
+            return 0.0;
+        }
+        let bounds = Rect::new(
+            self.transform.position.x,
+            self.transform.position.y,
+            self.transform.size.x,
+            self.transform.size.y,
+        );
+        let collected = pickups::collect_fuel_pickups(&mut self.fuel_pickups, bounds);
+        match &mut self.rocket_physics {
+            Some(rocket) if collected > 0.0 => rocket.add_fuel(collected),
+            _ => 0.0,
+        }
+    }
+
+    /// Camera X of the center of this attempt's start position.
+    pub fn spawn_center_x(&self) -> f32 {
+        self.world_size.x / 2.0 + self.spawn_offset
+    }
+
+    /// Highest terrain point under a horizontal span, in camera Y.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Left edge of the span in camera X
+    /// * `width` - Span width in pixels
+    pub fn ground_under(&self, x: f32, width: f32) -> f32 {
+        let points = self.terrain.len();
+        peak_height_under(
+            &self.terrain,
+            pixel_to_terrain_x(x, points, self.world_size.x),
+            pixel_to_terrain_x(width, points, self.world_size.x),
+        )
+    }
+
+    /// Highest terrain point under the lander, in camera Y.
+    pub fn ground_height(&self) -> f32 {
+        self.ground_under(self.transform.position.x, self.transform.size.x)
+    }
+
+    /// Height of the lander bottom above the terrain beneath it, as shown on the HUD.
+    pub fn altitude(&self) -> f32 {
+        self.transform.position.y - self.ground_height()
+    }
+
+    /// Gravitational acceleration acting on the lander in m/s².
+    ///
+    /// Constant lunar gravity, or with `gravity_falloff` the inverse-square
+    /// value for the lander's height above the terrain directly below it.
+    pub fn gravity_magnitude(&self) -> f32 {
+        if !self.gravity_falloff {
+            return self.body.gravity();
+        }
+        gravity_at_altitude(self.altitude(), self.body.gravity())
+    }
+
+    /// Whether the flight is frozen (paused, or the leaderboard is open).
+    ///
+    /// No physics step, collision check or mission time passes while paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused || self.show_leaderboard
+    }
+
+    /// Marks the start of the descent on the first thrust input.
+    ///
+    /// Subsequent calls are ignored so the start time stays anchored to the
+    /// first commit of the attempt.
+    pub fn commit_descent(&mut self) {
+        if self.attempt_start_time.is_none() {
+            self.attempt_start_time = Some(self.time_elapsed);
+        }
+    }
+
+    /// Returns the mission time in seconds, measured from the first thrust input.
+    ///
+    /// Time spent drifting before the player commits to the descent is not
+    /// counted, so it cannot eat into the time bonus.
+    ///
+    /// # Returns
+    ///
+    /// Seconds since the descent started, or `0.0` if no thrust has been applied yet
+    pub fn mission_time(&self) -> f32 {
+        match self.attempt_start_time {
+            Some(start) => (self.time_elapsed - start).max(0.0),
+            None => 0.0,
+        }
+    }
+
+    /// Starts a fresh attempt: new terrain (unless reused), spawn point and physics.
+    ///
+    /// # Arguments
+    ///
+    /// * `lander_texture_size` - Lander width and height in pixels
+    /// * `now` - Current time in seconds, stamped on the radio call for the new attempt
+    pub fn initialize_with_terrain_and_position(&mut self, lander_texture_size: Vec2, now: f64) {
+        self.stop_replay();
+        let num_points = surface::TERRAIN_POINTS;
         let min_height = 0.0;
         let max_height = 100.0;
         let base_frequency = 0.01;
@@ -95,8 +455,8 @@ impl<'a> Entity<'a> {
         let persistence = 0.5;
 
         // Calculate lander width in terrain coordinate units
-        // Use consistent calculation with 1:1 pixel mapping
-        let lander_width_terrain_points = lander_texture_size.x as usize;
+        let lander_width_terrain_points =
+            pixel_to_terrain_x(lander_texture_size.x, num_points, self.world_size.x) as usize;
         let landing_spot_terrain_points = (lander_width_terrain_points as f32 * 1.5) as usize;
         
         debug!(
@@ -108,39 +468,116 @@ impl<'a> Entity<'a> {
             landing_spot_terrain_points
         );
 
-        // Generate terrain with multiple landing zones
-        let (mut terrain, landing_zones) = surface::generate_terrain_with_multiple_landing_zones(
-            num_points,
-            min_height,
-            max_height,
-            base_frequency,
-            octaves,
-            persistence,
-            lander_width_terrain_points,
-        );
+        // Position lander safely above terrain
+        let initial_world_pos = vec2(0.0, 50.0);
+        let tex_center = initial_world_pos;
+        let screen_center = transform_axes(tex_center, self.world_size);
+        // With same-terrain sessions, keep the current layout
+        let reuse_terrain = self.reuse_terrain && self.terrain.len() == num_points;
+        if !reuse_terrain {
+            let seed = self
+                .terrain_seed
+                .unwrap_or_else(|| (macroquad::miniquad::date::now() * 1_000_000.0) as u64);
+            info!("Terrain seed {}", seed);
+            self.layout_seed = seed;
+            self.start_rng = StdRng::seed_from_u64(seed);
+        }
 
-        // Apply scaling transformation
-        terrain.iter_mut().for_each(|h| {
-            *h = *h * 0.4 + 60.0;
-        });
+        // Optionally shift the start sideways and add some drift; drawn from
+        // the layout's RNG so a given seed replays the same starts
+        if self.random_spawn {
+            (self.spawn_offset, self.spawn_drift) = random_start(&mut self.start_rng, self.world_size.x);
+        } else {
+            self.spawn_offset = 0.0;
+            self.spawn_drift = 0.0;
+        }
 
-        self.terrain = terrain;
-        self.landing_zones = landing_zones.clone();
-        
-        // Update legacy flat_spots for backward compatibility
-        self.flat_spots = landing_zones.iter().map(|zone| (zone.start, zone.end)).collect();
+        if reuse_terrain {
+            debug!("Reusing session terrain ({} zones)", self.landing_zones.len());
+        } else {
+            let seed = self.layout_seed;
+
+            // Generate terrain with multiple landing zones, or the single practice zone under the start
+            let (mut terrain, landing_zones) = if self.practice {
+                let zone = surface::practice_landing_zone(
+                    num_points,
+                    lander_width_terrain_points,
+                    pixel_to_terrain_x(screen_center.x + self.spawn_offset, num_points, self.world_size.x),
+                );
+                let zones = vec![zone];
+                let terrain = surface::generate_terrain_heights(
+                    num_points,
+                    min_height,
+                    max_height,
+                    base_frequency,
+                    octaves,
+                    persistence,
+                    &zones,
+                    surface::noise_seed(seed),
+                );
+                (terrain, zones)
+            } else {
+                surface::generate_terrain_with_multiple_landing_zones(
+                    num_points,
+                    min_height,
+                    max_height,
+                    base_frequency,
+                    octaves,
+                    persistence,
+                    lander_width_terrain_points,
+                    self.zone_count.clone(),
+                    seed,
+                )
+            };
+
+            // Apply scaling transformation
+            terrain.iter_mut().for_each(|h| {
+                *h = *h * 0.4 + 60.0;
+            });
+
+            self.terrain = terrain;
+            self.landing_zones = landing_zones;
+
+            // Challenge mode: set the first zone sliding, once the terrain has its final heights
+            if self.moving_pad && !self.practice && !self.landing_zones.is_empty() {
+                let (min_start, max_start) =
+                    surface::moving_pad_track(&self.landing_zones, 0, num_points, MOVING_PAD_REACH);
+                self.landing_zones[0].set_moving(&mut self.terrain, MOVING_PAD_SPEED, min_start, max_start);
+            }
+
+            // Update legacy flat_spots for backward compatibility
+            self.flat_spots = self.landing_zones.iter().map(|zone| (zone.start, zone.end)).collect();
+        }
+
+        // Fresh canisters every attempt, from the layout's RNG like the spawn
+        self.fuel_pickups = if self.fuel_pickups_enabled {
+            let seed = self.start_rng.gen::<u64>();
+            pickups::place_fuel_pickups(&self.terrain, self.world_size.x, &self.landing_zones, PICKUP_COUNT, PICKUP_FUEL, seed)
+        } else {
+            Vec::new()
+        };
 
         // Set lander size and position
         self.transform.size = lander_texture_size;
-
-        // Position lander safely above terrain
-        let initial_world_pos = vec2(0.0, 50.0);
-        let tex_center = initial_world_pos;
-        let screen_center = transform_axes(tex_center);
-        self.transform.position = vec2(
-            screen_center.x - lander_texture_size.x / 2.0,
+        self.transform.place_upright(vec2(
+            screen_center.x + self.spawn_offset - lander_texture_size.x / 2.0,
             screen_center.y - lander_texture_size.y / 2.0,
-        );
+        ));
+
+        // Never start the lander closer to the ground than the clearance allows
+        let ground = self.ground_height();
+        let min_start_y = ground + lander_texture_size.y * SPAWN_CLEARANCE_HEIGHTS;
+        self.transform.position.y = self.transform.position.y.max(min_start_y);
+        self.previous_transform = self.transform.clone();
+
+        if self.random_spawn {
+            debug!(
+                "Spawn offset {:.1} px, drift {:.1} m/s, clearance {:.1}",
+                self.spawn_offset,
+                self.spawn_drift,
+                self.transform.position.y - ground
+            );
+        }
 
         // Reset physics and state
         let total_mass = if let Some(rocket) = &self.rocket_physics {
@@ -148,14 +585,34 @@ impl<'a> Entity<'a> {
         } else {
             23200.0 // Default Apollo LM mass
         };
-        self.physics = Some(Physics::new(total_mass));
+        let mut physics = Physics::new(total_mass)
+            .with_integrator(self.integrator)
+            .with_drag_coefficient(self.drag_coefficient);
+        physics.velocity.x = self.spawn_drift;
+        self.physics = Some(physics);
 
         if let Some(rocket) = &mut self.rocket_physics {
             rocket.refuel();
-            rocket.stop_thrust();
+            rocket.shutdown();
+            rocket.set_throttle(0.0);
         }
 
         self.time_elapsed = 0.0;
+        self.physics_clock.reset();
+        self.attempt_start_time = None;
+        self.distance_traveled = 0.0;
+        self.thrust_blocked_time = 0.0;
+        self.restart_requested_at = None;
+        self.low_fuel_warned = false;
+        self.max_altitude = self.altitude();
+        self.radio.clear();
+        self.radio.post(GO_FOR_LANDING, now);
+        self.exhaust.clear();
+        self.shake_intensity = 0.0;
+        if let Some(telemetry) = &mut self.telemetry {
+            telemetry.clear();
+        }
+        self.replay.start(&self.terrain);
         self.sound = true;
         self.dead = false;
         self.mission_success = false;
@@ -163,7 +620,16 @@ impl<'a> Entity<'a> {
     }
 }
 
-pub fn add_lander_entity<'a>(entities: &mut Vec<Entity<'a>>) {
+/// Random start offset (pixels from the center) and sideways drift (m/s) for `--random-spawn`.
+fn random_start(rng: &mut StdRng, world_width: f32) -> (f32, f32) {
+    let max_offset = world_width * SPAWN_OFFSET_FRACTION;
+    (
+        rng.gen_range(-max_offset..=max_offset),
+        rng.gen_range(-MAX_SPAWN_DRIFT..=MAX_SPAWN_DRIFT),
+    )
+}
+
+pub fn add_lander_entity<'a>(entities: &mut Vec<Entity<'a>>, virtual_resolution: Vec2) {
     // Load textures first to get actual lander dimensions
     let (lander_texture, thrust_texture) = load_lander_textures();
 
@@ -174,9 +640,8 @@ pub fn add_lander_entity<'a>(entities: &mut Vec<Entity<'a>>) {
     );
 
     // Calculate lander width in terrain coordinate units
-    let current_screen_width = screen_width();
-    let terrain_points_per_pixel = 1000.0 / (current_screen_width * 2.0);
-    let lander_width_terrain_points = (lander_texture_size.x * terrain_points_per_pixel) as usize;
+    let lander_width_terrain_points =
+        pixel_to_terrain_x(lander_texture_size.x, surface::TERRAIN_POINTS, virtual_resolution.x) as usize;
     let landing_spot_terrain_points = (lander_width_terrain_points as f32 * 1.5) as usize;
 
     debug!(
@@ -190,9 +655,10 @@ pub fn add_lander_entity<'a>(entities: &mut Vec<Entity<'a>>) {
 
     // Create lander entity with default constructor
     let mut lander = Entity::new();
+    lander.world_size = virtual_resolution;
     
     // Initialize terrain and position using common method
-    lander.initialize_with_terrain_and_position(lander_texture_size);
+    lander.initialize_with_terrain_and_position(lander_texture_size, get_time());
     
     // Set up renderers with loaded textures
     lander.renderer_lander = Some(Renderer {
@@ -211,3 +677,67 @@ pub fn add_lander_entity<'a>(entities: &mut Vec<Entity<'a>>) {
 
     entities.push(lander);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restart_stands_lander_upright() {
+        // Crashed on its side
+        let mut transform = Transform {
+            size: vec2(32.0, 32.0),
+            position: vec2(120.0, 61.0),
+            rotation: 263.0,
+        };
+
+        transform.place_upright(vec2(384.0, 250.0));
+        assert_eq!(transform.rotation, 0.0);
+        assert_eq!(transform.position, vec2(384.0, 250.0));
+        assert_eq!(transform.size, vec2(32.0, 32.0), "Size is kept");
+    }
+
+    #[test]
+    fn test_interpolate_between_steps() {
+        let previous = Transform {
+            size: vec2(32.0, 32.0),
+            position: vec2(100.0, 200.0),
+            rotation: 0.0,
+        };
+        let current = Transform {
+            position: vec2(104.0, 190.0),
+            rotation: 6.0,
+            ..previous.clone()
+        };
+
+        let halfway = previous.interpolate(&current, 0.5);
+        assert_eq!(halfway.position, vec2(102.0, 195.0));
+        assert_eq!(halfway.rotation, 3.0);
+        assert_eq!(previous.interpolate(&current, 0.0).position, previous.position);
+        assert_eq!(previous.interpolate(&current, 1.0).position, current.position);
+        assert_eq!(previous.interpolate(&current, 1.0).rotation, current.rotation);
+
+        // Rotation through 0°/360° takes the short way round
+        let before_zero = Transform { rotation: 350.0, ..previous.clone() };
+        let after_zero = Transform { rotation: 10.0, ..previous.clone() };
+        let blended = before_zero.interpolate(&after_zero, 0.25);
+        assert!((blended.rotation - 355.0).abs() < 1e-4, "Turned the long way: {}", blended.rotation);
+
+        // A wrap to the other side of the screen is not blended
+        let near_top = Transform { position: vec2(104.0, 595.0), ..previous.clone() };
+        let wrapped = Transform { position: vec2(104.0, 5.0), ..current.clone() };
+        assert_eq!(near_top.interpolate(&wrapped, 0.5).position, wrapped.position);
+    }
+
+    #[test]
+    fn test_seed_replays_random_spawn() {
+        let start = |seed: u64| random_start(&mut StdRng::seed_from_u64(seed), 800.0);
+
+        assert_eq!(start(42), start(42), "The same seed gives the same start");
+        assert_ne!(start(42), start(43));
+
+        let (offset, drift) = start(7);
+        assert!(offset.abs() <= 800.0 * SPAWN_OFFSET_FRACTION);
+        assert!(drift.abs() <= MAX_SPAWN_DRIFT);
+    }
+}
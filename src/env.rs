@@ -0,0 +1,255 @@
+//! Gym-style environment around the headless simulation.
+//!
+//! `LanderEnv` wraps `LanderSim` in the usual reinforcement-learning contract:
+//! `reset(seed)` lays out a fresh terrain and returns the first observation,
+//! `step(action)` advances one fixed 60 Hz step and returns the next
+//! observation, the reward and whether the episode is over.
+//!
+//! # Spaces
+//!
+//! - Action: a `SimInput` (thrust, rotate left, rotate right, dump fuel), each
+//!   held for one step
+//! - Observation: see `Observation`; all values are in the game's units
+//!   (camera coordinates, m/s, degrees, percent)
+//!
+//! # Determinism
+//!
+//! The seed fixes the landing zone layout and the terrain noise, so
+//! `reset(seed)` followed by the same actions always yields the same
+//! observations and rewards (see the determinism contract of `sim.rs`).
+//! Nothing reads the clock, the window or a global RNG.
+
+use macroquad::prelude::*;
+use crate::collision::CollisionType;
+use crate::session::LandingAttempt;
+use crate::sim::{LanderSim, SimInput, FIXED_DT};
+use crate::surface::{self, peak_height_under, LandingZoneDifficulty, DEFAULT_ZONE_COUNT, TERRAIN_POINTS};
+use crate::utils::{pixel_to_terrain_x, terrain_x_to_pixel};
+
+/// Episodes that have not touched down by now end without a landing (s)
+const MAX_EPISODE_TIME: f32 = 180.0;
+/// Reward for any contact that is not a landing
+const CRASH_REWARD: f32 = -100.0;
+/// Landing reward per point of game score (a landing scores ~1300-4300)
+const SCORE_REWARD_SCALE: f32 = 0.05;
+
+/// Action applied for one step
+pub type Action = SimInput;
+
+/// What the agent sees after each step
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Observation {
+    pub position: Vec2,       // Lander bottom-left corner (camera coordinates)
+    pub velocity: Vec2,       // m/s, positive Y is up
+    pub rotation: f32,        // Degrees, 0° = upright, positive counterclockwise
+    pub angular_velocity: f32, // Spin in degrees per second, positive counterclockwise
+    pub fuel_percentage: f32, // 0-100
+    pub altitude: f32,        // Height of the lander bottom above the terrain beneath it
+    pub zone_offset: f32,     // Nearest zone center minus lander center, along X
+    pub zone_difficulty: LandingZoneDifficulty, // Difficulty of that zone
+}
+
+/// Reinforcement-learning environment over `LanderSim`
+pub struct LanderEnv {
+    sim: LanderSim,
+    lander_size: Vec2,
+    world_size: Vec2,
+}
+
+impl LanderEnv {
+    /// Creates an environment, laid out with seed 0 until the first `reset`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lander_size` - Lander width and height in pixels
+    /// * `world_size` - World width and height; the terrain spans the width
+    pub fn new(lander_size: Vec2, world_size: Vec2) -> Self {
+        Self {
+            sim: seeded_sim(0, lander_size, world_size),
+            lander_size,
+            world_size,
+        }
+    }
+
+    /// Starts a new episode on the terrain for `seed`.
+    pub fn reset(&mut self, seed: u64) -> Observation {
+        self.sim = seeded_sim(seed, self.lander_size, self.world_size);
+        self.observe()
+    }
+
+    /// Applies an action for one fixed step.
+    ///
+    /// # Returns
+    ///
+    /// `(observation, reward, done)`; once `done`, further steps keep
+    /// returning the final observation with zero reward
+    pub fn step(&mut self, action: Action) -> (Observation, f32, bool) {
+        if self.is_done() {
+            return (self.observe(), 0.0, true);
+        }
+
+        let before = self.observe();
+        self.sim.step(action, FIXED_DT);
+        let after = self.observe();
+
+        // Contact zeroes the velocity, so the final step is judged on the outcome alone
+        let reward = match self.sim.outcome() {
+            Some((collision, zone)) => terminal_reward(collision, zone, after.fuel_percentage, self.sim.time_elapsed()),
+            None => shaping_potential(&after) - shaping_potential(&before),
+        };
+        (after, reward, self.is_done())
+    }
+
+    /// Whether the episode has ended (contact or time limit)
+    pub fn is_done(&self) -> bool {
+        self.sim.is_finished() || self.sim.time_elapsed() >= MAX_EPISODE_TIME
+    }
+
+    /// The underlying simulation, for inspection
+    pub fn sim(&self) -> &LanderSim {
+        &self.sim
+    }
+
+    fn observe(&self) -> Observation {
+        let position = self.sim.position();
+        let size = self.sim.lander_size();
+        let center_x = position.x + size.x / 2.0;
+        let points = self.sim.terrain().len();
+        let nearest = self
+            .sim
+            .landing_zones()
+            .iter()
+            .map(|zone| {
+                let zone_center = terrain_x_to_pixel((zone.start + zone.end) as f32 / 2.0, points, self.world_size.x);
+                (zone_center - center_x, zone.difficulty)
+            })
+            .min_by(|a, b| a.0.abs().total_cmp(&b.0.abs()));
+        let (zone_offset, zone_difficulty) = nearest.unwrap_or((0.0, LandingZoneDifficulty::Easy));
+
+        Observation {
+            position,
+            velocity: self.sim.velocity(),
+            rotation: self.sim.rotation(),
+            angular_velocity: self.sim.angular_velocity(),
+            fuel_percentage: self.sim.fuel_percentage(),
+            altitude: position.y
+                - peak_height_under(
+                    self.sim.terrain(),
+                    pixel_to_terrain_x(position.x, points, self.world_size.x),
+                    pixel_to_terrain_x(size.x, points, self.world_size.x),
+                ),
+            zone_offset,
+            zone_difficulty,
+        }
+    }
+}
+
+/// Builds the simulation for a seed with the game's terrain settings.
+fn seeded_sim(seed: u64, lander_size: Vec2, world_size: Vec2) -> LanderSim {
+    // Same generator settings and screen scaling as Entity::initialize_with_terrain_and_position
+    let (terrain, zones) = surface::generate_terrain_with_multiple_landing_zones(
+        TERRAIN_POINTS,
+        0.0,
+        100.0,
+        0.01,
+        6,
+        0.5,
+        pixel_to_terrain_x(lander_size.x, TERRAIN_POINTS, world_size.x) as usize,
+        DEFAULT_ZONE_COUNT,
+        seed,
+    );
+    let terrain = terrain.into_iter().map(|h| h * 0.4 + 60.0).collect();
+    LanderSim::new(terrain, zones, lander_size, world_size)
+}
+
+/// Potential for reward shaping: higher when close over a zone, slow and upright.
+///
+/// Rewards for in-flight steps are differences of this potential, so the
+/// shaping adds up to the change from start to touchdown rather than
+/// rewarding time spent in the air.
+pub fn shaping_potential(observation: &Observation) -> f32 {
+    let tilt = observation.rotation.rem_euclid(360.0);
+    let tilt = tilt.min(360.0 - tilt);
+    -(observation.zone_offset.abs() * 0.1 + observation.velocity.length() + tilt * 0.05)
+}
+
+/// One-off reward when the episode ends in contact.
+///
+/// A landing earns the game's score for it (zone, fuel and time), scaled by
+/// `SCORE_REWARD_SCALE`; anything else earns `CRASH_REWARD`.
+pub fn terminal_reward(
+    collision: CollisionType,
+    zone: Option<LandingZoneDifficulty>,
+    fuel_percentage: f32,
+    time: f32,
+) -> f32 {
+    match (collision, zone) {
+        (CollisionType::LandingSuccess, Some(difficulty)) => {
+            LandingAttempt::calculate_score(difficulty, None, fuel_percentage, time) * SCORE_REWARD_SCALE
+        }
+        (CollisionType::None, _) => 0.0,
+        _ => CRASH_REWARD,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env() -> LanderEnv {
+        LanderEnv::new(vec2(32.0, 32.0), vec2(800.0, 600.0))
+    }
+
+    #[test]
+    fn test_reset_is_deterministic_per_seed() {
+        let mut a = env();
+        let mut b = env();
+        assert_eq!(a.reset(7), b.reset(7));
+        let starts = |env: &LanderEnv| env.sim().landing_zones().iter().map(|zone| zone.start).collect::<Vec<_>>();
+        assert_eq!(starts(&a), starts(&b));
+
+        let action = Action { thrust: true, ..Default::default() };
+        for _ in 0..120 {
+            assert_eq!(a.step(action), b.step(action));
+        }
+
+        // Another seed lays out other zones
+        let zones_7 = starts(&a);
+        a.reset(8);
+        assert_ne!(starts(&a), zones_7);
+    }
+
+    #[test]
+    fn test_free_fall_episode_ends_in_crash() {
+        let mut env = env();
+        env.reset(3);
+
+        let mut total = 0.0;
+        let mut last = None;
+        for _ in 0..10_000 {
+            let (observation, reward, done) = env.step(Action::default());
+            total += reward;
+            last = Some(observation);
+            if done {
+                break;
+            }
+        }
+
+        assert!(env.is_done());
+        assert!(total < CRASH_REWARD / 2.0, "Crashing should dominate the return, got {}", total);
+        assert!(last.unwrap().velocity.length() < 1e-6, "Contact stops the lander");
+
+        // Finished episodes stay finished
+        let (_, reward, done) = env.step(Action { thrust: true, ..Default::default() });
+        assert_eq!((reward, done), (0.0, true));
+    }
+
+    #[test]
+    fn test_terminal_reward() {
+        let landing = terminal_reward(CollisionType::LandingSuccess, Some(LandingZoneDifficulty::Hard), 50.0, 30.0);
+        assert!((landing - 3900.0 * SCORE_REWARD_SCALE).abs() < 1e-3);
+        assert_eq!(terminal_reward(CollisionType::LegCollision, Some(LandingZoneDifficulty::Hard), 50.0, 30.0), CRASH_REWARD);
+        assert_eq!(terminal_reward(CollisionType::BodyCollision, None, 50.0, 30.0), CRASH_REWARD);
+        assert_eq!(terminal_reward(CollisionType::None, None, 50.0, 30.0), 0.0);
+    }
+}
@@ -0,0 +1,417 @@
+//! Approach-corridor guidance for the assisted mode.
+//!
+//! The corridor is a funnel rising from the target landing zone: as narrow as
+//! the zone at ground level and widening with height. Its spread and the speed
+//! allowed at each height are derived from the zone's landing limits as
+//! collision detection enforces them, turning those limits into a visible
+//! glide path.
+//!
+//! The landing indicator checks the same three limits live: a ballistic
+//! prediction of the touchdown point for the zone, plus the current speed and
+//! attitude.
+
+use macroquad::prelude::*;
+
+use crate::collision::{angle_from_vertical, surface_normal_angle};
+use crate::entity::Transform;
+use crate::physics::gravity_vector;
+use crate::surface::{peak_height_under, LandingZone};
+use crate::tuning::LandingTuning;
+use crate::utils::{pixel_to_terrain_x, terrain_x_to_pixel};
+
+/// Funnel half-angle as a multiple of the maximum landing angle
+const CORRIDOR_SPREAD: f32 = 3.0;
+/// Time step of the touchdown prediction (s)
+const PREDICTION_STEP: f32 = 0.05;
+/// Longest coast the touchdown prediction follows (s)
+const PREDICTION_HORIZON: f32 = 30.0;
+/// Spool level above which the engine counts as firing for the blocked-thrust check
+const BLOCKED_MIN_SPOOL: f32 = 0.5;
+/// Speed along the thrust direction below which thrust is making no progress (m/s)
+const BLOCKED_MAX_PROGRESS: f32 = 0.5;
+/// Distance to a surface within which the lander counts as pressed against it
+const BLOCKED_SURFACE_GAP: f32 = 2.0;
+
+/// Approach funnel above a landing zone, in camera coordinates (Y increases upward)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApproachCorridor {
+    pub center_x: f32,        // Zone center
+    pub ground_y: f32,        // Terrain height of the zone
+    pub zone_half_width: f32, // Half the zone width at ground level
+    pub max_velocity: f32,    // Landing speed limit on the zone (m/s)
+    pub max_angle: f32,       // Landing angle limit on the zone (degrees)
+    pub gravity: f32,         // Surface gravity the lander decelerates against (m/s²)
+}
+
+/// Whether the lander is currently following the corridor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CorridorStatus {
+    pub inside: bool,   // Lander center within the funnel
+    pub speed_ok: bool, // Speed within the allowance for the current height
+}
+
+impl CorridorStatus {
+    /// True when the lander is inside the corridor at an acceptable speed
+    pub fn on_track(&self) -> bool {
+        self.inside && self.speed_ok
+    }
+}
+
+impl ApproachCorridor {
+    /// Builds the corridor above a landing zone.
+    ///
+    /// # Arguments
+    ///
+    /// * `zone` - Landing zone the corridor leads to
+    /// * `terrain` - Terrain heights in camera coordinates, one per terrain point
+    /// * `world_width` - Width in pixels the terrain spans
+    /// * `tuning` - Landing limits, scaled by the zone's difficulty as on touchdown
+    /// * `gravity` - Surface gravity of the selected body in m/s²
+    pub fn for_zone(zone: &LandingZone, terrain: &[f64], world_width: f32, tuning: &LandingTuning, gravity: f32) -> Self {
+        let ground_y = terrain
+            .get(zone.start)
+            .copied()
+            .unwrap_or(0.0) as f32;
+        let to_pixel = |x: f32| terrain_x_to_pixel(x, terrain.len(), world_width);
+        let (max_velocity, max_angle) = tuning.zone_limits(Some(zone.difficulty));
+
+        Self {
+            center_x: to_pixel((zone.start + zone.end) as f32 / 2.0),
+            ground_y,
+            zone_half_width: to_pixel((zone.end - zone.start) as f32 / 2.0),
+            max_velocity,
+            max_angle,
+            gravity,
+        }
+    }
+
+    /// Half-width of the funnel at a given camera Y coordinate.
+    ///
+    /// The funnel widens at `CORRIDOR_SPREAD` times the landing angle limit.
+    pub fn half_width_at(&self, y: f32) -> f32 {
+        let height = (y - self.ground_y).max(0.0);
+        let spread = (self.max_angle * CORRIDOR_SPREAD).to_radians().tan();
+        self.zone_half_width + height * spread
+    }
+
+    /// Maximum speed allowed at a given height above the zone.
+    ///
+    /// This is the speed that can be shed decelerating at one surface g over the
+    /// remaining height while still touching down at the zone's speed limit:
+    /// v = √(v_land² + 2·g·h)
+    pub fn allowed_speed(&self, y: f32) -> f32 {
+        let height = (y - self.ground_y).max(0.0);
+        (self.max_velocity * self.max_velocity + 2.0 * self.gravity * height).sqrt()
+    }
+
+    /// Checks a lander against the corridor.
+    ///
+    /// # Arguments
+    ///
+    /// * `lander_center` - Bottom-center of the lander in camera coordinates
+    /// * `velocity` - Lander velocity
+    pub fn assess(&self, lander_center: Vec2, velocity: Vec2) -> CorridorStatus {
+        let inside = lander_center.y >= self.ground_y
+            && (lander_center.x - self.center_x).abs() <= self.half_width_at(lander_center.y);
+        let speed_ok = velocity.length() <= self.allowed_speed(lander_center.y);
+
+        CorridorStatus { inside, speed_ok }
+    }
+}
+
+/// Picks the landing zone closest to the lander horizontally.
+///
+/// `lander_center_x` is in terrain points, like the zone bounds.
+///
+/// # Returns
+///
+/// The nearest zone, or `None` if there are no landing zones
+pub fn target_zone(landing_zones: &[LandingZone], lander_center_x: f32) -> Option<&LandingZone> {
+    landing_zones.iter().min_by(|a, b| {
+        let distance_a = ((a.start + a.end) as f32 / 2.0 - lander_center_x).abs();
+        let distance_b = ((b.start + b.end) as f32 / 2.0 - lander_center_x).abs();
+        distance_a.total_cmp(&distance_b)
+    })
+}
+
+/// Live check of the three landing criteria
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LandingReadiness {
+    pub zone_ok: bool,  // Predicted touchdown lies fully within a landing zone
+    pub speed_ok: bool, // Current speed is within the landing limit
+    pub angle_ok: bool, // Current attitude is within the landing limit
+}
+
+impl LandingReadiness {
+    /// True when a touchdown right now would be a successful landing
+    pub fn all_ok(&self) -> bool {
+        self.zone_ok && self.speed_ok && self.angle_ok
+    }
+}
+
+/// Predicts where the lander would touch down if it coasted without thrust.
+///
+/// Steps the lander forward under gravity until its span meets the terrain.
+/// The prediction gives up if the lander would leave the terrain horizontally
+/// or is still airborne after `PREDICTION_HORIZON` seconds.
+///
+/// # Arguments
+///
+/// * `transform` - Lander position (bottom-left, camera coordinates) and size
+/// * `velocity` - Current velocity
+/// * `gravity` - Gravitational acceleration vector in m/s²
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `world_width` - Width in pixels the terrain spans
+///
+/// # Returns
+///
+/// The lander's left X at touchdown, or `None` if no touchdown was found
+pub fn predict_touchdown_x(
+    transform: &Transform,
+    velocity: Vec2,
+    gravity: Vec2,
+    terrain: &[f64],
+    world_width: f32,
+) -> Option<f32> {
+    let width = transform.size.x;
+    let max_x = world_width - width;
+    let mut position = transform.position;
+    let mut velocity = velocity;
+    let mut time = 0.0;
+
+    while time < PREDICTION_HORIZON {
+        if position.x < 0.0 || position.x > max_x {
+            return None;
+        }
+
+        let ground = peak_height_under(
+            terrain,
+            pixel_to_terrain_x(position.x, terrain.len(), world_width),
+            pixel_to_terrain_x(width, terrain.len(), world_width),
+        );
+        if position.y <= ground {
+            return Some(position.x);
+        }
+
+        velocity += gravity * PREDICTION_STEP;
+        position += velocity * PREDICTION_STEP;
+        time += PREDICTION_STEP;
+    }
+
+    None
+}
+
+/// Checks the landing criteria against the current state.
+///
+/// # Arguments
+///
+/// * `transform` - Lander position (bottom-left, camera coordinates), size and rotation
+/// * `velocity` - Current velocity
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `world_width` - Width in pixels the terrain spans
+/// * `landing_zones` - Landing zones on the terrain
+/// * `gravity_angle` - Gravity direction in degrees from straight down, for the touchdown prediction
+/// * `gravity` - Gravitational acceleration in m/s², for the touchdown prediction
+/// * `tuning` - Leg layout and landing limits collision detection uses
+pub fn assess_landing(
+    transform: &Transform,
+    velocity: Vec2,
+    terrain: &[f64],
+    world_width: f32,
+    landing_zones: &[LandingZone],
+    gravity_angle: f32,
+    gravity: f32,
+    tuning: &LandingTuning,
+) -> LandingReadiness {
+    // Same strict containment as collision detection: the whole lander span must fit
+    let touchdown_zone = predict_touchdown_x(transform, velocity, gravity_vector(gravity, gravity_angle), terrain, world_width)
+        .and_then(|x| {
+            let start = pixel_to_terrain_x(x, terrain.len(), world_width) as usize;
+            let end = pixel_to_terrain_x(x + transform.size.x, terrain.len(), world_width) as usize;
+            landing_zones.iter().find(|zone| start >= zone.start && end <= zone.end)
+        });
+    // Harder zones demand gentler landings
+    let (max_velocity, max_angle) = tuning.zone_limits(touchdown_zone.map(|zone| zone.difficulty));
+
+    // Attitude against the ground currently under the legs, as collision judges it; the
+    // gravity tilt only bends the trajectory, not what counts as flat to the ground
+    let surface_angle = surface_normal_angle(terrain, &tuning.shape.zones(transform), world_width);
+
+    LandingReadiness {
+        zone_ok: touchdown_zone.is_some(),
+        speed_ok: velocity.length() <= max_velocity,
+        angle_ok: angle_from_vertical(transform.rotation - surface_angle) <= max_angle,
+    }
+}
+
+/// Detects thrust being wasted against a surface.
+///
+/// Thrust is blocked when the engine is firing toward a surface the lander is
+/// already touching (the terrain below, or the altitude ceiling above) and the
+/// lander is not moving in the thrust direction. A normal touchdown never
+/// qualifies: the engine then pushes away from the ground, not into it.
+///
+/// # Arguments
+///
+/// * `thrust_direction` - Unit direction of the thrust force
+/// * `spool_level` - Actual engine thrust level (0.0-1.0)
+/// * `velocity` - Lander velocity
+/// * `ground_gap` - Height of the lander bottom above the terrain beneath it
+/// * `ceiling_gap` - Distance from the lander top to the ceiling (`f32::INFINITY` if none)
+pub fn thrust_blocked(
+    thrust_direction: Vec2,
+    spool_level: f32,
+    velocity: Vec2,
+    ground_gap: f32,
+    ceiling_gap: f32,
+) -> bool {
+    if spool_level < BLOCKED_MIN_SPOOL || velocity.dot(thrust_direction) > BLOCKED_MAX_PROGRESS {
+        return false;
+    }
+
+    let into_ground = thrust_direction.y < 0.0 && ground_gap <= BLOCKED_SURFACE_GAP;
+    let into_ceiling = thrust_direction.y > 0.0 && ceiling_gap <= BLOCKED_SURFACE_GAP;
+    into_ground || into_ceiling
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::body::CelestialBody;
+    use crate::surface::LandingZoneDifficulty;
+    use crate::ACCEL_GRAV_Y;
+
+    fn zone(start: usize, end: usize) -> LandingZone {
+        LandingZone {
+            start,
+            end,
+            difficulty: LandingZoneDifficulty::Medium,
+            width_points: end - start + 1,
+            motion: None,
+        }
+    }
+
+    #[test]
+    fn test_corridor_narrows_toward_ground() {
+        let terrain = vec![60.0; 800];
+        let tuning = LandingTuning::default();
+        let corridor = ApproachCorridor::for_zone(&zone(300, 340), &terrain, 800.0, &tuning, ACCEL_GRAV_Y);
+
+        assert_eq!(corridor.center_x, 320.0);
+        assert_eq!(corridor.half_width_at(60.0), 20.0, "Funnel matches zone width at ground");
+        assert!(corridor.half_width_at(200.0) > corridor.half_width_at(100.0));
+
+        // Speed allowance tapers to the zone's landing limit at the ground
+        let (max_velocity, _) = tuning.zone_limits(Some(LandingZoneDifficulty::Medium));
+        assert_eq!(corridor.allowed_speed(60.0), max_velocity);
+        assert!(max_velocity < tuning.max_landing_velocity, "Medium zones are stricter than the tuned limit");
+        assert!(corridor.allowed_speed(300.0) > max_velocity);
+
+        // A stricter zone gets a narrower funnel
+        let hard = LandingZone { difficulty: LandingZoneDifficulty::Hard, ..zone(300, 340) };
+        let hard = ApproachCorridor::for_zone(&hard, &terrain, 800.0, &tuning, ACCEL_GRAV_Y);
+        assert!(hard.half_width_at(200.0) < corridor.half_width_at(200.0));
+        assert!(hard.allowed_speed(60.0) < corridor.allowed_speed(60.0));
+
+        // Stronger gravity lets the lander shed more speed over the same height
+        let mars = ApproachCorridor::for_zone(&zone(300, 340), &terrain, 800.0, &tuning, CelestialBody::Mars.gravity());
+        assert_eq!(mars.allowed_speed(60.0), max_velocity);
+        assert!(mars.allowed_speed(300.0) > corridor.allowed_speed(300.0));
+    }
+
+    #[test]
+    fn test_corridor_assessment() {
+        let terrain = vec![60.0; 800];
+        let corridor = ApproachCorridor::for_zone(&zone(300, 340), &terrain, 800.0, &LandingTuning::default(), ACCEL_GRAV_Y);
+
+        // Directly above the zone, descending slowly
+        let status = corridor.assess(vec2(320.0, 200.0), vec2(0.0, -5.0));
+        assert!(status.on_track());
+
+        // Above the zone but far too fast near the ground
+        let status = corridor.assess(vec2(320.0, 70.0), vec2(0.0, -30.0));
+        assert!(status.inside && !status.speed_ok);
+
+        // Low and well off to the side
+        let status = corridor.assess(vec2(500.0, 80.0), vec2(0.0, -5.0));
+        assert!(!status.inside);
+    }
+
+    #[test]
+    fn test_landing_readiness() {
+        let terrain = vec![60.0; 800];
+        let zones = vec![zone(300, 340)];
+        let tuning = LandingTuning::default();
+        let mut transform = Transform {
+            size: vec2(32.0, 32.0),
+            position: vec2(304.0, 160.0),
+            rotation: 0.0,
+        };
+
+        // Straight down into the zone, slow and upright
+        let readiness = assess_landing(&transform, vec2(0.0, -2.0), &terrain, 800.0, &zones, 0.0, ACCEL_GRAV_Y, &tuning);
+        assert!(readiness.all_ok());
+
+        // Drifting sideways carries the touchdown past the zone edge
+        let readiness = assess_landing(&transform, vec2(5.0, -2.0), &terrain, 800.0, &zones, 0.0, ACCEL_GRAV_Y, &tuning);
+        assert!(!readiness.zone_ok && readiness.speed_ok);
+
+        // Fast and tilted past the limits
+        transform.rotation = 340.0;
+        let readiness = assess_landing(&transform, vec2(0.0, -15.0), &terrain, 800.0, &zones, 0.0, ACCEL_GRAV_Y, &tuning);
+        assert_eq!((readiness.speed_ok, readiness.angle_ok), (false, false));
+        transform.rotation = 350.0;
+        assert!(assess_landing(&transform, vec2(0.0, -2.0), &terrain, 800.0, &zones, 0.0, ACCEL_GRAV_Y, &tuning).angle_ok);
+
+        // Tilted gravity doesn't change what flat to the ground is: leaning into it tips the lander
+        assert!(assess_landing(&transform, vec2(0.0, -2.0), &terrain, 800.0, &zones, 30.0, ACCEL_GRAV_Y, &tuning).angle_ok);
+        transform.rotation = 25.0;
+        assert!(!assess_landing(&transform, vec2(0.0, -2.0), &terrain, 800.0, &zones, 30.0, ACCEL_GRAV_Y, &tuning).angle_ok);
+    }
+
+    #[test]
+    fn test_touchdown_prediction() {
+        let terrain = vec![60.0; 800];
+        let transform = Transform {
+            size: vec2(32.0, 32.0),
+            position: vec2(100.0, 160.0),
+            rotation: 0.0,
+        };
+
+        // Falling straight down lands where it started
+        assert_eq!(predict_touchdown_x(&transform, Vec2::ZERO, gravity_vector(ACCEL_GRAV_Y, 0.0), &terrain, 800.0), Some(100.0));
+
+        // Drifting right lands further right
+        let x = predict_touchdown_x(&transform, vec2(10.0, 0.0), gravity_vector(ACCEL_GRAV_Y, 0.0), &terrain, 800.0).unwrap();
+        assert!(x > 150.0, "Predicted touchdown at {}", x);
+
+        // Drifting off the terrain has no prediction
+        assert!(predict_touchdown_x(&transform, vec2(-200.0, 0.0), gravity_vector(ACCEL_GRAV_Y, 0.0), &terrain, 800.0).is_none());
+    }
+
+    #[test]
+    fn test_thrust_blocked() {
+        let up = Vec2::Y;
+
+        // Full thrust pinned against the ceiling
+        assert!(thrust_blocked(up, 1.0, Vec2::ZERO, 400.0, 0.0));
+
+        // Gentle touchdown: thrusting up, just above the ground, slow descent
+        assert!(!thrust_blocked(up, 1.0, vec2(0.0, -1.0), 0.5, f32::INFINITY));
+
+        // Thrusting down into the ground
+        assert!(thrust_blocked(-up, 0.8, Vec2::ZERO, 0.0, f32::INFINITY));
+
+        // Still climbing toward the ceiling, or engine barely lit
+        assert!(!thrust_blocked(up, 1.0, vec2(0.0, 5.0), 400.0, 1.0));
+        assert!(!thrust_blocked(up, 0.1, Vec2::ZERO, 400.0, 0.0));
+    }
+
+    #[test]
+    fn test_target_zone_is_nearest() {
+        let zones = vec![zone(100, 130), zone(400, 440), zone(700, 730)];
+        assert_eq!(target_zone(&zones, 380.0).unwrap().start, 400);
+        assert_eq!(target_zone(&zones, 0.0).unwrap().start, 100);
+        assert!(target_zone(&[], 0.0).is_none());
+    }
+}
@@ -1,74 +1,249 @@
 use macroquad::prelude::*;
-use macroquad::logging::info;
+use macroquad::logging::{info, warn};
 use rusty_audio::Audio;
 
-use crate::audio::{update_audio, shutdown_audio};
+use crate::audio::{update_audio, shutdown_audio, AudioSettings, AUDIO_SETTINGS_FILE};
+use crate::config::{DisplaySettings, DISPLAY_SETTINGS_FILE};
 use crate::entity::{Entity, Collision};
+use crate::radio::CONFIRM_RESTART;
+use crate::result_card::save_result_card;
 use crate::session::SessionManager;
 
-const ROTATION_INCREMENT: f32 = 3.0;
-const FULL_CIRCLE_DEGREES: f32 = 360.0;
+pub const ROTATION_TORQUE: f32 = 125000.0; // N·m while Left or Right is held (~120°/s² on the LM)
+const EXHAUST_VELOCITY_STEP: f64 = 100.0; // m/s per key press when tuning the engine
+const RESTART_CONFIRM_WINDOW: f64 = 3.0; // Seconds to press R again to confirm a restart
+const THROTTLE_RATE: f32 = 1.25; // Throttle change per second while Up or Down is held
+
+/// Keyboard layout: the key for each player action
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyBindings {
+    pub throttle_up: KeyCode,
+    pub throttle_down: KeyCode,
+    pub rotate_left: KeyCode,
+    pub rotate_right: KeyCode,
+    pub rcs_left: KeyCode,
+    pub rcs_right: KeyCode,
+    pub fuel_dump: KeyCode,
+    pub jettison: KeyCode,
+    pub restart: KeyCode,
+    pub quit: KeyCode,
+    pub sound: KeyCode,
+    pub debug: KeyCode,
+    pub exhaust_velocity_up: KeyCode,   // Engine tuning, debug overlay only
+    pub exhaust_velocity_down: KeyCode, // Engine tuning, debug overlay only
+    pub hud_mode: KeyCode,
+    pub session_status: KeyCode,
+    pub approach_corridor: KeyCode,
+    pub lifetime_stats: KeyCode,
+    pub leaderboard: KeyCode,
+    pub units: KeyCode,
+    pub result_card: KeyCode,
+    pub pause: KeyCode,
+    pub mute: KeyCode,
+    pub colorblind: KeyCode,
+    pub replay: KeyCode,
+    pub export_csv: KeyCode,
+    pub body: KeyCode,
+}
+
+impl Default for KeyBindings {
+    /// The classic layout: arrow keys to fly, letters for everything else.
+    fn default() -> Self {
+        Self {
+            throttle_up: KeyCode::Up,
+            throttle_down: KeyCode::Down,
+            rotate_left: KeyCode::Left,
+            rotate_right: KeyCode::Right,
+            rcs_left: KeyCode::Q,
+            rcs_right: KeyCode::E,
+            fuel_dump: KeyCode::V,
+            jettison: KeyCode::J,
+            restart: KeyCode::R,
+            quit: KeyCode::Escape,
+            sound: KeyCode::S,
+            debug: KeyCode::D,
+            exhaust_velocity_up: KeyCode::RightBracket,
+            exhaust_velocity_down: KeyCode::LeftBracket,
+            hud_mode: KeyCode::H,
+            session_status: KeyCode::B,
+            approach_corridor: KeyCode::G,
+            lifetime_stats: KeyCode::L,
+            leaderboard: KeyCode::T,
+            units: KeyCode::U,
+            result_card: KeyCode::C,
+            pause: KeyCode::P,
+            mute: KeyCode::M,
+            colorblind: KeyCode::K,
+            replay: KeyCode::W,
+            export_csv: KeyCode::X,
+            body: KeyCode::N,
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Every binding with its action name, for listing or checking the layout.
+    pub fn all(&self) -> [(&'static str, KeyCode); 27] {
+        [
+            ("throttle up", self.throttle_up),
+            ("throttle down", self.throttle_down),
+            ("rotate left", self.rotate_left),
+            ("rotate right", self.rotate_right),
+            ("RCS left", self.rcs_left),
+            ("RCS right", self.rcs_right),
+            ("fuel dump", self.fuel_dump),
+            ("jettison", self.jettison),
+            ("restart", self.restart),
+            ("quit", self.quit),
+            ("sound", self.sound),
+            ("debug", self.debug),
+            ("exhaust velocity up", self.exhaust_velocity_up),
+            ("exhaust velocity down", self.exhaust_velocity_down),
+            ("HUD mode", self.hud_mode),
+            ("session status", self.session_status),
+            ("approach corridor", self.approach_corridor),
+            ("lifetime stats", self.lifetime_stats),
+            ("leaderboard", self.leaderboard),
+            ("units", self.units),
+            ("result card", self.result_card),
+            ("pause", self.pause),
+            ("mute", self.mute),
+            ("colorblind markers", self.colorblind),
+            ("replay", self.replay),
+            ("export CSV", self.export_csv),
+            ("celestial body", self.body),
+        ]
+    }
+}
+
+/// Applies keyboard input to the lander, session and audio.
+///
+/// # Arguments
+///
+/// * `keys` - Key for each action (see `KeyBindings::default` for the standard layout)
+///
+/// # Returns
+///
+/// `true` when the player asked to quit (`keys.quit`); the caller runs the shutdown path
+pub fn handle_input(
+    lander: &mut Entity,
+    audio: &mut Audio,
+    session_manager: &mut SessionManager,
+    keys: &KeyBindings,
+) -> bool {
+    let was_paused = lander.is_paused();
+    if is_key_released(keys.pause) {
+        lander.paused = !lander.paused;
+    }
+    if is_key_released(keys.leaderboard) {
+        lander.show_leaderboard = !lander.show_leaderboard;
+    }
+    if lander.is_paused() {
+        if !was_paused {
+            // Silence the engine while paused; the audio below picks the right track on resume
+            shutdown_audio(audio);
+            lander.current_audio = None;
+        }
+        // Nothing but the quit key gets through while paused
+        return is_key_down(keys.quit);
+    }
 
-pub fn handle_input(lander: &mut Entity, audio: &mut Audio, session_manager: &mut SessionManager) {
     // Handle input
-    if is_key_released(KeyCode::R) {
-        if session_manager.session.session_complete {
+    if is_key_released(keys.restart) {
+        if session_manager.is_complete() {
             // Session is complete - start a new session
             session_manager.reset_session();
-            reset_lander(lander);
+            lander.discard_terrain();
+            reset_lander(lander, get_time());
             update_audio(audio);
             info!("Starting new game session (3 attempts)");
         } else if lander.dead && session_manager.can_start_next_attempt() {
-            // Current attempt failed but session continues - start next attempt
-            reset_lander(lander);
+            // Current attempt failed but session continues - start next attempt.
+            // In hotseat the next player takes over; this lander waits, reset, for its turn
+            reset_lander(lander, get_time());
             update_audio(audio);
+            if session_manager.pass_turn() {
+                // The waiting lander sits out the rest of this frame's keys
+                info!("Player {} to fly attempt {}/{}",
+                      session_manager.active_player + 1,
+                      session_manager.current_attempt_display(),
+                      session_manager.session.max_attempts);
+                lander.current_audio = None;
+                return false;
+            }
             info!("Starting attempt {}/{}", 
                   (session_manager.session.current_attempt + 1).min(session_manager.session.max_attempts), 
                   session_manager.session.max_attempts);
         } else if lander.dead {
             // Session complete but not processed yet
             session_manager.reset_session();
-            reset_lander(lander);
+            lander.discard_terrain();
+            reset_lander(lander, get_time());
             update_audio(audio);
             info!("Starting new game session (3 attempts)");
+        } else if needs_restart_confirmation(lander, get_time()) {
+            // Currently playing - ask before throwing the descent away
+            lander.restart_requested_at = Some(get_time());
+            lander.radio.post(CONFIRM_RESTART, get_time());
         } else {
             // Currently playing - restart current attempt
-            reset_lander(lander);
+            reset_lander(lander, get_time());
             update_audio(audio);
             info!("Restarting current attempt");
         }
     }
-    if is_key_down(KeyCode::Escape) {
-        shutdown_audio(audio);
-        std::process::exit(0);
+    if is_key_down(keys.quit) {
+        return true;
     }
-    if is_key_released(KeyCode::S) {
-        lander.sound = !lander.sound;
+    // Watch the finished attempt again; W again stops the replay
+    if is_key_released(keys.replay) {
+        if lander.replay_started_at.is_some() {
+            lander.stop_replay();
+        } else if !lander.start_replay(get_time()) {
+            info!("Nothing to replay until the attempt is over");
+        }
+    }
+    // Share the results once the session is over
+    if is_key_released(keys.result_card) && session_manager.session.session_complete {
+        save_result_card(&session_manager.session, &lander.screen_fonts);
     }
-    if is_key_down(KeyCode::Right) {
-        lander.transform.rotation =
-            (lander.transform.rotation - ROTATION_INCREMENT).rem_euclid(FULL_CIRCLE_DEGREES);
+    if is_key_released(keys.export_csv) && session_manager.session.session_complete {
+        let path = format!("lunar_lander_session_{}.csv", macroquad::miniquad::date::now() as u64);
+        match session_manager.save_csv(&path) {
+            Ok(()) => info!("Saved session statistics to {}", path),
+            Err(err) => warn!("Could not write session statistics to {}: {}", path, err),
+        }
     }
-    if is_key_down(KeyCode::Left) {
-        lander.transform.rotation =
-            (lander.transform.rotation + ROTATION_INCREMENT).rem_euclid(FULL_CIRCLE_DEGREES);
+    if is_key_released(keys.sound) {
+        lander.sound = !lander.sound;
+    }
+
+    // Left/Right apply torque; the spin coasts once released and needs the opposite key to stop
+    if let Some(physics) = &mut lander.physics {
+        let torque = match (is_key_down(keys.rotate_left), is_key_down(keys.rotate_right)) {
+            _ if lander.dead => 0.0,
+            (true, false) => ROTATION_TORQUE,
+            (false, true) => -ROTATION_TORQUE,
+            _ => 0.0,
+        };
+        physics.apply_torque(torque);
     }
 
     // Improved thrust handling using proper rocket physics
     let mut should_play_thrust = false;
     let mut should_play_ambient = false;
+    let mut start_descent = false;
 
     if let Some(rocket) = &mut lander.rocket_physics {
-        if is_key_down(KeyCode::Up) && rocket.has_fuel() && !lander.dead {
-            // Calculate thrust direction based on lander orientation
-            // 0° = up, so add 90° to convert to standard math coordinates
-            let angle = (lander.transform.rotation + 90.0).to_radians();
-            let thrust_direction = vec2(angle.cos(), angle.sin());
-
-            // Apply thrust vector (magnitude determined by max_thrust)
-            rocket.thrust_vector = thrust_direction * rocket.max_thrust as f32;
-            rocket.is_thrusting = true;
+        // Up opens the throttle and Down closes it; the setting holds when both are released
+        let (up, down) = (is_key_down(keys.throttle_up), is_key_down(keys.throttle_down));
+        rocket.set_throttle(next_throttle(rocket.throttle, up, down, get_frame_time()));
+
+        if rocket.throttle > 0.0 && rocket.has_fuel() && !lander.dead {
+            // Thrust along the lander orientation at the throttle setting
+            rocket.fire(lander.transform.rotation);
             should_play_thrust = true;
+            start_descent = true;
         } else {
             // Stop thrusting
             rocket.stop_thrust();
@@ -78,12 +253,91 @@ pub fn handle_input(lander: &mut Entity, audio: &mut Audio, session_manager: &mu
         should_play_ambient = lander.sound;
     }
 
-    if is_key_released(KeyCode::D) {
+    if start_descent {
+        lander.commit_descent();
+    }
+
+    // Fuel dump: vent while V is held to lighten the craft
+    if let Some(rocket) = &mut lander.rocket_physics {
+        rocket.is_dumping = is_key_down(keys.fuel_dump) && !lander.dead;
+    }
+
+    // Lateral RCS jets: Q pushes left, E pushes right
+    if let Some(rocket) = &mut lander.rocket_physics {
+        rocket.rcs_direction = match (is_key_down(keys.rcs_left), is_key_down(keys.rcs_right)) {
+            _ if lander.dead => 0.0,
+            (true, false) => -1.0,
+            (false, true) => 1.0,
+            _ => 0.0,
+        };
+    }
+
+    // Staging is a one-time action during flight
+    if is_key_released(keys.jettison) && !lander.dead {
+        if let Some(rocket) = &mut lander.rocket_physics {
+            if let Some(dropped) = rocket.jettison() {
+                info!("Descent stage jettisoned ({:.0} kg dropped)", dropped);
+            }
+        }
+    }
+
+    if is_key_released(keys.debug) {
         lander.show_debug_info = !lander.show_debug_info;
     }
+    // Engine efficiency tuning, only while the debug overlay is up and never in the daily challenge
+    if lander.show_debug_info && !lander.daily_challenge {
+        if let Some(rocket) = &mut lander.rocket_physics {
+            if is_key_released(keys.exhaust_velocity_up) {
+                rocket.set_exhaust_velocity(rocket.exhaust_velocity + EXHAUST_VELOCITY_STEP);
+            }
+            if is_key_released(keys.exhaust_velocity_down) {
+                rocket.set_exhaust_velocity(rocket.exhaust_velocity - EXHAUST_VELOCITY_STEP);
+            }
+        }
+    }
+    if is_key_released(keys.hud_mode) {
+        lander.hud_mode = lander.hud_mode.next();
+    }
+    if is_key_released(keys.session_status) {
+        lander.show_session_status = !lander.show_session_status;
+    }
+    if is_key_released(keys.approach_corridor) {
+        lander.show_approach_corridor = !lander.show_approach_corridor;
+    }
+    if is_key_released(keys.lifetime_stats) {
+        lander.show_lifetime_stats = !lander.show_lifetime_stats;
+    }
+    if is_key_released(keys.units) {
+        lander.units = lander.units.toggle();
+        if let Err(err) = (DisplaySettings { units: lander.units }).save(DISPLAY_SETTINGS_FILE) {
+            warn!("Could not write display settings to {}: {}", DISPLAY_SETTINGS_FILE, err);
+        }
+    }
+    if is_key_released(keys.colorblind) {
+        lander.colorblind = !lander.colorblind;
+    }
+    // The world can only change before the descent starts, so an attempt is flown on one body;
+    // the daily challenge is always flown on the default body
+    if is_key_released(keys.body) && !lander.dead && lander.attempt_start_time.is_none() && !lander.daily_challenge {
+        lander.body = lander.body.next();
+        info!("Landing on {} ({} m/s²)", lander.body.name(), lander.body.gravity());
+    }
+    if is_key_released(keys.mute) {
+        lander.muted = !lander.muted;
+        info!("Audio {}", if lander.muted { "muted" } else { "unmuted" });
+        if let Err(err) = (AudioSettings { muted: lander.muted }).save(AUDIO_SETTINGS_FILE) {
+            warn!("Could not write audio settings to {}: {}", AUDIO_SETTINGS_FILE, err);
+        }
+    }
 
     // Simplified audio management - keep ambient sound playing during free fall
-    if should_play_thrust {
+    if lander.muted {
+        // Muted overrides everything, including restarts that started the ambient loop
+        if lander.current_audio.is_some() || audio.is_playing() {
+            shutdown_audio(audio);
+            lander.current_audio = None;
+        }
+    } else if should_play_thrust {
         // Switch to thrust audio
         if lander.current_audio != Some("acceleration".to_string()) {
             shutdown_audio(audio);
@@ -107,6 +361,44 @@ pub fn handle_input(lander: &mut Entity, audio: &mut Audio, session_manager: &mu
             lander.current_audio = None;
         }
     }
+
+    false
+}
+
+/// Ramps the throttle at `THROTTLE_RATE` for one frame.
+///
+/// # Arguments
+///
+/// * `throttle` - Current throttle setting (0.0-1.0)
+/// * `up` - Whether the throttle is being opened
+/// * `down` - Whether the throttle is being closed
+/// * `frame_time` - Duration of the frame in seconds
+///
+/// # Returns
+///
+/// The new throttle setting, clamped to 0.0-1.0
+fn next_throttle(throttle: f32, up: bool, down: bool, frame_time: f32) -> f32 {
+    let step = match (up, down) {
+        (true, false) => THROTTLE_RATE * frame_time,
+        (false, true) => -THROTTLE_RATE * frame_time,
+        _ => 0.0,
+    };
+    (throttle + step).clamp(0.0, 1.0)
+}
+
+/// Whether an R press during flight should only arm the restart.
+///
+/// A descent the player has committed to (first thrust given) is only thrown
+/// away by a second R press within `RESTART_CONFIRM_WINDOW` seconds. Before
+/// the first thrust, or with confirmation turned off, R restarts at once.
+fn needs_restart_confirmation(lander: &Entity, now: f64) -> bool {
+    if !lander.confirm_restart || lander.attempt_start_time.is_none() {
+        return false;
+    }
+    match lander.restart_requested_at {
+        Some(requested_at) => now - requested_at > RESTART_CONFIRM_WINDOW,
+        None => true,
+    }
 }
 
 pub fn stop_lander(lander: &mut Entity) {
@@ -115,15 +407,80 @@ pub fn stop_lander(lander: &mut Entity) {
         phys.forces = vec2(0.0, 0.0);
     }
     if let Some(rocket) = &mut lander.rocket_physics {
-        rocket.stop_thrust();
+        rocket.shutdown();
     }
     lander.collision = Some(Collision {
         collider: Rect::new(0.0, 0.0, 0.0, 0.0),
     });
 }
 
-pub fn reset_lander(lander: &mut Entity) {
+/// Puts the lander back at the start of a fresh attempt, upright and at rest.
+///
+/// # Arguments
+///
+/// * `lander` - Lander to reset
+/// * `now` - Current time in seconds, stamped on the radio call for the new attempt
+pub fn reset_lander(lander: &mut Entity, now: f64) {
     // Reset lander using common initialization method
     let lander_texture_size = lander.transform.size; // Preserve existing size
-    lander.initialize_with_terrain_and_position(lander_texture_size);
-}
\ No newline at end of file
+    lander.initialize_with_terrain_and_position(lander_texture_size, now);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_key_bindings_are_unique() {
+        let bindings = KeyBindings::default().all();
+        for (i, (action, key)) in bindings.iter().enumerate() {
+            for (other, other_key) in &bindings[i + 1..] {
+                assert_ne!(key, other_key, "{} and {} share a key", action, other);
+            }
+        }
+        assert_eq!(KeyBindings::default().restart, KeyCode::R);
+    }
+
+    #[test]
+    fn test_reset_lander_stands_crashed_lander_upright() {
+        let mut lander = Entity::new();
+        lander.terrain_seed = Some(42);
+        lander.transform.size = vec2(32.0, 32.0);
+
+        // Crashed on its side, still spinning
+        lander.transform.rotation = 263.0;
+        if let Some(physics) = lander.physics.as_mut() {
+            physics.angular_velocity = 40.0;
+            physics.velocity = vec2(3.0, -12.0);
+        }
+        lander.dead = true;
+
+        reset_lander(&mut lander, 0.0);
+        assert_eq!(lander.transform.rotation, 0.0);
+        assert_eq!(lander.physics.as_ref().map(|physics| physics.angular_velocity), Some(0.0));
+        assert!(!lander.dead);
+    }
+
+    #[test]
+    fn test_throttle_ramps_and_holds() {
+        let mut throttle = 0.0;
+        for _ in 0..24 {
+            throttle = next_throttle(throttle, true, false, 1.0 / 60.0);
+        }
+        assert!((throttle - 0.5).abs() < 1e-4, "0.4 s of Up opens the throttle halfway");
+
+        // The ramp depends on time held, not on the frame rate
+        let mut slow = 0.0;
+        for _ in 0..10 {
+            slow = next_throttle(slow, true, false, 0.04);
+        }
+        assert!((slow - throttle).abs() < 1e-4);
+
+        assert_eq!(next_throttle(throttle, false, false, 0.1), throttle, "Releasing both keys holds the setting");
+        assert_eq!(next_throttle(throttle, true, true, 0.1), throttle);
+        assert!(next_throttle(throttle, false, true, 0.1) < throttle);
+
+        assert_eq!(next_throttle(1.0, true, false, 0.1), 1.0);
+        assert_eq!(next_throttle(0.02, false, true, 0.1), 0.0);
+    }
+}
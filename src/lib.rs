@@ -0,0 +1,44 @@
+//! Library target holding the game's modules.
+//!
+//! The game itself is the `lunar_lander` binary (`main.rs`), which only runs
+//! the frame loop and drives everything through this crate. Benchmarks and
+//! embedders reach the terrain generator, the headless `LanderSim` and the
+//! `LanderEnv` environment through the same modules.
+#![allow(dead_code)]
+#![allow(unused_imports)]
+
+pub mod assets;
+pub mod audio;
+pub mod body;
+pub mod collision;
+pub mod config;
+pub mod entity;
+pub mod env;
+pub mod guidance;
+pub mod input;
+pub mod particles;
+pub mod physics;
+pub mod pickups;
+pub mod radio;
+pub mod replay;
+pub mod rendering;
+pub mod result_card;
+pub mod session;
+pub mod sim;
+pub mod starfield;
+pub mod stats;
+pub mod surface;
+pub mod telemetry;
+pub mod theme;
+pub mod tuning;
+pub mod units;
+pub mod utils;
+pub mod wind;
+
+pub use env::{Action, LanderEnv, Observation};
+pub use sim::{LanderSim, SimInput};
+
+// acceleration due to gravity on earth
+//pub const ACCEL_GRAV_Y: f32 = 9.8;
+// acceleration due to gravity on the moon
+pub const ACCEL_GRAV_Y: f32 = 1.625;
@@ -1,128 +1,339 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
-use core::time;
-use std::thread::sleep;
-
 use macroquad::prelude::*;
 use rusty_audio::Audio;
 
-mod assets;
-mod audio;
-mod collision;
-mod entity;
-mod input;
-mod physics;
-mod rendering;
-mod session;
-mod surface;
-mod utils;
-
-use audio::{load_audio, shutdown_audio};
-use collision::{check_collision, check_collision_with_zone_info, CollisionType};
-use entity::{add_lander_entity, Entity};
-use input::{handle_input, stop_lander};
-use physics::{Physics, RocketEngine};
-use rendering::{configure_camera, render};
-use session::{SessionManager, AttemptResult};
-
-const MILLIS_DELAY: u64 = 40;
-// acceleration due to gravity on earth
-//const ACCEL_GRAV_Y: f32 = 9.8;
-// acceleration due to gravity on the moon
-const ACCEL_GRAV_Y: f32 = 1.625;
+use lunar_lander::assets;
+use lunar_lander::audio::{load_audio, play_oneshot, shutdown_audio, AudioSettings, AUDIO_SETTINGS_FILE};
+use lunar_lander::collision::{check_collision, check_collision_with_zone_info, check_wreckage_collision, landing_grade, wreckage_boxes, zone_edge_distances, CollisionType};
+use lunar_lander::config::{DisplaySettings, GameConfig, DISPLAY_SETTINGS_FILE};
+use lunar_lander::entity::{add_lander_entity, Entity};
+use lunar_lander::input::{handle_input, reset_lander, stop_lander, KeyBindings};
+use lunar_lander::physics::{gravity_vector, DescentStage, Physics, RocketEngine};
+use lunar_lander::radio::{EAGLE_HAS_LANDED, FUEL_CRITICAL, LANDER_LOST, SESSION_OVER};
+use lunar_lander::replay::ReplayFrame;
+use lunar_lander::rendering::{configure_camera, configure_follow_camera, render, shake_camera};
+use lunar_lander::session::{daily_seed, today, SessionManager, AttemptResult, SCORES_FILE};
+use lunar_lander::sim::{simulate_step, FIXED_DT};
+use lunar_lander::surface::{deform_terrain_crater, LandingZoneDifficulty};
+use lunar_lander::stats::{LifetimeStats, STATS_FILE};
+use lunar_lander::telemetry::TelemetryLog;
+use lunar_lander::tuning::LandingTuning;
+use lunar_lander::utils::pixel_to_terrain_x;
+use lunar_lander::wind::Wind;
+
+/// Fuel level (%) below which mission control calls fuel critical
+const LOW_FUEL_PERCENT: f32 = 10.0;
+/// Fuel level (%) below which the low-fuel warning beeps
+const LOW_FUEL_WARNING_PERCENT: f32 = 15.0;
+/// Seconds between low-fuel beeps
+const LOW_FUEL_BEEP_INTERVAL: f64 = 1.0;
+/// Impact speed (in multiples of the safe landing speed) beyond which craters stop growing
+const CRATER_MAX_SEVERITY: f32 = 4.0;
+/// Camera shake (pixels) per m/s of impact speed
+const SHAKE_PIXELS_PER_MPS: f32 = 1.5;
+/// Largest camera shake (pixels), reached by the hardest crashes
+const MAX_SHAKE_PIXELS: f32 = 24.0;
+/// Crater depth per multiple of the safe landing speed
+const CRATER_DEPTH_PER_SEVERITY: f32 = 4.0;
+
+fn window_conf() -> Conf {
+    let config = GameConfig::from_args();
+    let window = config.virtual_resolution();
+    Conf {
+        window_title: config.window_title(),
+        window_width: window.x as i32,
+        window_height: window.y as i32,
+        sample_count: config.msaa_samples(),
+        icon: assets::window_icon(),
+        ..Default::default()
+    }
+}
 
 // Main game loop
-#[macroquad::main("Lunar Lander")]
+#[macroquad::main(window_conf)]
 async fn main() {
+    // read command-line options
+    let mut config = GameConfig::from_args();
     // initialize random numbers
     rand::srand(macroquad::miniquad::date::now() as _);
     // load sounds
     let mut audio = load_audio();
     // create session manager
     let mut session_manager = SessionManager::new();
-    // create lander
+    session_manager.single_use_zones = config.single_use_zones;
+    session_manager.lifetime = LifetimeStats::load(STATS_FILE);
+    session_manager.high_scores = SessionManager::load_high_scores();
+    session_manager.practice = config.practice;
+    session_manager.set_player_count(config.players);
+    if config.daily {
+        // Everyone flies the same layout today
+        let date = today();
+        let seed = daily_seed(&date);
+        info!("Daily challenge {} (seed {})", date, seed);
+        config.terrain_seed = Some(seed);
+        session_manager.daily = Some(date);
+    }
+    // create one lander per player
     let mut entities = Vec::new();
-    add_lander_entity(&mut entities);
+    for _ in 0..config.players {
+        add_lander_entity(&mut entities, config.virtual_resolution());
+        configure_lander(entities.last_mut().unwrap(), &config);
+    }
+
+    let keys = KeyBindings::default();
+
+    // Route window-close through the same shutdown path as Escape
+    prevent_quit();
 
-    // main loop forever
+    // Gameplay stays at the virtual resolution; a resized window only changes the letterboxing
+    let mut window_size = vec2(screen_width(), screen_height());
+
+    // main loop until the player quits
     loop {
         clear_background(BLACK);
 
-        let lander: &mut Entity = entities.first_mut().unwrap();
+        let current_window = vec2(screen_width(), screen_height());
+        if current_window != window_size {
+            let world = config.virtual_resolution();
+            info!(
+                "Window resized to {}x{}; letterboxing the {}x{} world",
+                current_window.x, current_window.y, world.x, world.y
+            );
+            window_size = current_window;
+        }
+
+        // Only the active player's lander flies; in hotseat the others wait their turn
+        let player = session_manager.active_player;
+        let lander: &mut Entity = &mut entities[player];
 
         // Handle input
-        handle_input(lander, &mut audio, &mut session_manager);
+        let quit_requested = handle_input(lander, &mut audio, &mut session_manager, &keys);
+        if quit_requested || is_quit_requested() {
+            shutdown(&mut audio, &session_manager);
+            break;
+        }
 
-        if !lander.dead {
-            handle_collision(lander, &mut audio, &mut session_manager);
-            check_fuel(lander);
+        // Pausing (P or the leaderboard) freezes physics, collisions and the mission clock
+        if !lander.dead && !lander.is_paused() {
+            handle_collision(lander, &mut audio, &mut session_manager, &config);
+            check_fuel(lander, &mut audio);
+            check_pickups(lander, &mut audio);
 
             // Update systems
-            update_physics(&mut entities);
+            update_physics(&mut entities[player..=player]);
+            record_replay_frame(&mut entities[player]);
         }
 
-        // Render systems
-        // Create camera once at start of main loop
-        let camera = configure_camera();
-        render(&entities, &camera, &session_manager.session);
+        // Exhaust keeps drifting and fading after a crash, but freezes with the game
+        let lander: &mut Entity = &mut entities[player];
+        if !lander.is_paused() {
+            update_exhaust(lander, get_frame_time());
+        }
 
-        // Pause for the next frame
-        sleep(std::time::Duration::from_millis(MILLIS_DELAY));
+        // Render systems
+        // Create camera once at start of main loop; R may have handed over the controls
+        let player = session_manager.active_player;
+        let lander = &entities[player];
+        let camera = if config.follow_camera {
+            configure_follow_camera(lander)
+        } else {
+            configure_camera(lander.world_size)
+        };
+        let camera = shake_camera(camera, lander.shake_intensity, get_time() - lander.shake_started_at);
+        render(&entities[player..=player], &camera, &session_manager, &config);
 
         next_frame().await
     }
 }
 
+/// Applies the startup options to a freshly added lander.
+///
+/// Options that shape the terrain or the start re-roll the first attempt so
+/// it is flown with them too.
+fn configure_lander(lander: &mut Entity, config: &GameConfig) {
+    lander.show_approach_corridor = config.assisted;
+    lander.colorblind = config.colorblind;
+    lander.muted = AudioSettings::load(AUDIO_SETTINGS_FILE).muted;
+    lander.gravity_angle = config.gravity_angle;
+    lander.gravity_falloff = config.gravity_falloff;
+    lander.vertical_wrap = config.vertical_wrap;
+    lander.body = config.body;
+    lander.wind = Wind::new(config.wind, config.gustiness);
+    lander.reuse_terrain = config.same_terrain;
+    lander.integrator = config.integrator;
+    lander.drag_coefficient = config.drag_coefficient;
+    lander.confirm_restart = config.confirm_restart;
+    lander.daily_challenge = config.daily;
+    lander.units = config.units.unwrap_or_else(|| DisplaySettings::load(DISPLAY_SETTINGS_FILE).units);
+    if let Some(path) = &config.tuning_file {
+        match LandingTuning::load(path) {
+            Ok(tuning) => {
+                info!("Landing tuning loaded from {}", path);
+                lander.tuning = tuning;
+            }
+            Err(err) => warn!("Ignoring tuning file, using default landing limits: {}", err),
+        }
+    }
+    if config.terrain_seed.is_some() {
+        // Re-roll the first terrain from the requested seed
+        lander.terrain_seed = config.terrain_seed;
+        reset_lander(lander, get_time());
+    }
+    if lander.zone_count != config.zone_count {
+        // Re-roll the first terrain with the requested zone count
+        lander.zone_count = config.zone_count.clone();
+        reset_lander(lander, get_time());
+    }
+    if let Some(physics) = lander.physics.as_mut() {
+        physics.integrator = config.integrator;
+        physics.drag_coefficient = config.drag_coefficient;
+    }
+    if config.telemetry {
+        lander.telemetry = Some(TelemetryLog::default());
+    }
+    if config.practice {
+        // Re-roll the first terrain with the practice zone under the start
+        lander.practice = true;
+        reset_lander(lander, get_time());
+    }
+    if config.moving_pad {
+        // Re-roll the first terrain with its moving pad
+        lander.moving_pad = true;
+        reset_lander(lander, get_time());
+    }
+    if config.fuel_pickups {
+        // Re-roll the first attempt so it also gets its canisters
+        lander.fuel_pickups_enabled = true;
+        reset_lander(lander, get_time());
+    }
+    if config.random_spawn {
+        // Re-roll the first attempt so it also gets a random start
+        lander.random_spawn = true;
+        reset_lander(lander, get_time());
+    }
+    if let (Some(exhaust_velocity), Some(rocket)) = (config.exhaust_velocity, lander.rocket_physics.as_mut()) {
+        rocket.set_exhaust_velocity(exhaust_velocity);
+    }
+    if config.two_stage {
+        if let Some(rocket) = lander.rocket_physics.take() {
+            lander.rocket_physics = Some(rocket.with_descent_stage(DescentStage::APOLLO));
+        }
+    }
+}
+
+/// Cleanly ends the game before the process exits.
+///
+/// Stops audio and logs the session so far. This is the single exit path for
+/// both Escape and window-close, so anything that must be saved on exit
+/// belongs here.
+fn shutdown(audio: &mut Audio, session_manager: &SessionManager) {
+    shutdown_audio(audio);
+
+    let session = &session_manager.session;
+    info!(
+        "Exiting: {} of {} attempts landed, score {:.1}",
+        session.success_count(),
+        session.max_attempts,
+        session.total_score
+    );
+}
+
 // Define systems
-fn update_physics(entities: &mut Vec<Entity>) {
-    let dt = get_frame_time();
+fn update_physics(entities: &mut [Entity]) {
+    let frame_time = get_frame_time();
 
     for entity in entities {
         if entity.dead {
             continue;
         }
 
-        if let Some(physics) = &mut entity.physics {
-            // Reset forces for this frame
-            physics.reset_forces();
-
-            // Apply gravity force
-            let gravity_force = Vec2::new(0.0, -ACCEL_GRAV_Y * physics.mass as f32);
-            physics.add_force(gravity_force);
+        // Physics, moving pads and the mission timer run on fixed steps, independent of
+        // frame rate: the clock accumulates real frame time and pays it out in FIXED_DT steps
+        let steps = entity.physics_clock.advance(frame_time);
+        for _ in 0..steps {
+            entity.update_zones(FIXED_DT);
+            let gravity = gravity_vector(entity.gravity_magnitude(), entity.gravity_angle);
+            simulate_step(entity, FIXED_DT, gravity);
 
-            // Generate thrust force if rocket engine present
-            if let Some(rocket) = &mut entity.rocket_physics {
-                // Update physics mass based on current rocket mass
-                physics.mass = rocket.total_mass();
-
-                let thrust_force = rocket.generate_thrust(dt);
-                physics.add_force(thrust_force);
+            // Leave contact for handle_collision next frame rather than stepping into the ground
+            if check_collision_with_zone_info(entity).0 != CollisionType::None {
+                break;
             }
+        }
+    }
+}
 
-            // Integrate forces into motion
-            physics.integrate(dt);
-            entity.transform.position += physics.velocity * dt;
-
-            // Wrap around screen (maintain lunar lander behavior)
-            entity.transform.position.x = entity.transform.position.x.rem_euclid(screen_width());
-            entity.transform.position.y = entity.transform.position.y.rem_euclid(screen_height());
+// Captures this frame of a live attempt for instant replay
+fn record_replay_frame(lander: &mut Entity) {
+    if lander.dead {
+        return;
+    }
+    let thrust = match &lander.rocket_physics {
+        Some(rocket) if rocket.has_fuel() => rocket.spool_level,
+        _ => 0.0,
+    };
+    let velocity_y = lander.physics.as_ref().map_or(0.0, |physics| physics.velocity.y);
+    lander.replay.record(ReplayFrame {
+        time: lander.time_elapsed,
+        position: lander.transform.position,
+        rotation: lander.transform.rotation,
+        thrust,
+        velocity_y,
+    });
+}
 
-            // Update elapsed time with proper precision
-            entity.time_elapsed += dt;
-        }
+// Emits exhaust particles at the nozzle in proportion to the engine's thrust level
+fn update_exhaust(lander: &mut Entity, dt: f32) {
+    let level = match &lander.rocket_physics {
+        Some(rocket) if rocket.has_fuel() && !lander.dead => rocket.spool_level,
+        _ => 0.0,
+    };
+    if level > 0.0 {
+        let transform = &lander.transform;
+        let angle = transform.rotation.to_radians();
+        let center = transform.position + transform.size * 0.5;
+        // Since 0° is "up", the exhaust leaves downward along the lander's axis
+        let direction = vec2(angle.sin(), -angle.cos());
+        let nozzle = center + direction * transform.size.y * 0.5;
+        let velocity = lander.physics.as_ref().map_or(Vec2::ZERO, |physics| physics.velocity);
+        lander.exhaust.emit(nozzle, direction, velocity, level, dt);
     }
+    lander.exhaust.update(dt);
 }
 
-fn handle_collision(lander: &mut Entity, audio: &mut Audio, session_manager: &mut SessionManager) {
+fn handle_collision(lander: &mut Entity, audio: &mut Audio, session_manager: &mut SessionManager, config: &GameConfig) {
+    // Attempts count toward the body the active lander is flying on
+    session_manager.body = lander.body;
+
     // Check for collision with enhanced zone information
-    let (collision_type, zone_difficulty) = check_collision_with_zone_info(lander);
+    let (mut collision_type, mut zone_difficulty) = check_collision_with_zone_info(lander);
+
+    // A crash into the ground leaves a crater
+    if matches!(collision_type, CollisionType::BodyCollision | CollisionType::LegCollision) {
+        make_impact_crater(lander, zone_difficulty);
+    }
+
+    // Ironman: earlier wrecks are solid, and hitting one is never a landing
+    if config.solid_wreckage && collision_type == CollisionType::None {
+        let wreckage = wreckage_boxes(
+            &session_manager.session.attempt_sites,
+            &lander.terrain,
+            lander.world_size.x,
+            lander.transform.size,
+            lander.spawn_center_x(),
+        );
+        collision_type = check_wreckage_collision(&lander.transform, &lander.tuning.shape, &wreckage);
+        zone_difficulty = None;
+    }
     
     match collision_type {
         CollisionType::BodyCollision => {
             debug!("Body Collision - Mission Failed!");
+            start_crash_shake(lander);
             stop_lander(lander);
-            shutdown_audio(audio);
-            lander.sound = false;
+            silence_lander(lander, audio);
+            play_effect(lander, audio, "crash");
             lander.dead = true;
             
             // Record failed attempt in session
@@ -135,14 +346,22 @@ fn handle_collision(lander: &mut Entity, audio: &mut Audio, session_manager: &mu
                 AttemptResult::Failure,
                 fuel_remaining,
                 None,
-                lander.time_elapsed
+                None,
+                lander.mission_time()
             );
+            session_manager.record_attempt_site(touchdown_site(lander), AttemptResult::Failure);
+            lander.radio.post(LANDER_LOST, get_time());
         }
-        CollisionType::LegCollision => {
-            debug!("Hard Landing - Mission Failed!");
+        CollisionType::LegCollision | CollisionType::UnstableLanding => {
+            if collision_type == CollisionType::UnstableLanding {
+                debug!("One-Legged Touchdown - Lander Tipped Over!");
+            } else {
+                debug!("Hard Landing - Mission Failed!");
+            }
+            start_crash_shake(lander);
             stop_lander(lander);
-            shutdown_audio(audio);
-            lander.sound = false;
+            silence_lander(lander, audio);
+            play_effect(lander, audio, "crash");
             lander.dead = true;
             
             // Record failed attempt in session
@@ -155,14 +374,34 @@ fn handle_collision(lander: &mut Entity, audio: &mut Audio, session_manager: &mu
                 AttemptResult::Failure,
                 fuel_remaining,
                 zone_difficulty, // Might have been on a zone but failed requirements
-                lander.time_elapsed
+                None,
+                lander.mission_time()
             );
+            session_manager.record_attempt_site(touchdown_site(lander), AttemptResult::Failure);
+            lander.radio.post(LANDER_LOST, get_time());
         }
         CollisionType::LandingSuccess => {
             debug!("Successful Landing - Mission Complete!");
+            // Grade before stop_lander zeroes the touchdown velocity
+            let velocity = lander.physics.as_ref().map_or(Vec2::ZERO, |physics| physics.velocity);
+            let grade = landing_grade(
+                &lander.transform,
+                &lander.tuning,
+                velocity,
+                &lander.terrain,
+                lander.world_size.x,
+                &lander.landing_zones,
+            );
+            let edge_distances = zone_edge_distances(
+                &lander.transform,
+                &lander.landing_zones,
+                lander.terrain.len(),
+                lander.world_size.x,
+            )
+                .map(|(_, left, right)| (left, right));
             stop_lander(lander);
-            shutdown_audio(audio);
-            lander.sound = false;
+            silence_lander(lander, audio);
+            play_effect(lander, audio, "landing_chime");
             lander.dead = true;
             lander.mission_success = true;
             
@@ -176,23 +415,159 @@ fn handle_collision(lander: &mut Entity, audio: &mut Audio, session_manager: &mu
                 AttemptResult::Success,
                 fuel_remaining,
                 zone_difficulty,
-                lander.time_elapsed
+                edge_distances,
+                lander.mission_time()
             );
+            session_manager.record_landing_grade(grade);
+            session_manager.record_best_replay(lander.replay.frames(), lander.layout_seed);
+            session_manager.record_attempt_site(touchdown_site(lander), AttemptResult::Success);
+            lander.radio.post(EAGLE_HAS_LANDED, get_time());
         }
         CollisionType::None => {
             // No collision, continue normal gameplay
         }
     }
+
+    // Keep the lifetime totals on disk after every counted attempt
+    if collision_type != CollisionType::None && !session_manager.practice {
+        if let Err(err) = session_manager.lifetime.save(STATS_FILE) {
+            warn!("Could not write lifetime stats to {}: {}", STATS_FILE, err);
+        }
+        if session_manager.session.session_complete {
+            if let Err(err) = session_manager.save_session() {
+                warn!("Could not write high scores to {}: {}", SCORES_FILE, err);
+            }
+        }
+    }
+
+    // Write out the flight log of the attempt that just ended
+    if collision_type != CollisionType::None {
+        if let Some(telemetry) = &lander.telemetry {
+            let attempt_number = session_manager
+                .session
+                .attempts
+                .iter()
+                .filter(|attempt| attempt.result != AttemptResult::InProgress)
+                .count();
+            let path = format!(
+                "telemetry_attempt_{}_{}.csv",
+                attempt_number,
+                macroquad::miniquad::date::now() as u64
+            );
+            match telemetry.write_csv(&path) {
+                Ok(()) => info!("Wrote {} telemetry samples to {}", telemetry.len(), path),
+                Err(err) => warn!("Could not write telemetry to {}: {}", path, err),
+            }
+        }
+    }
+
+    // Sign off once the final attempt of the session (every player's, in hotseat) is in
+    if collision_type != CollisionType::None && session_manager.is_complete() {
+        lander.radio.post(SESSION_OVER, get_time());
+    }
 }
 
-fn check_fuel(lander: &mut Entity) {
+/// Deforms the terrain under a crashed lander, scaled by impact speed.
+///
+/// Impacts below twice the safe landing speed (the zone's limit when the crash
+/// was on a zone) leave no mark. Must run before `stop_lander` zeroes the velocity.
+fn make_impact_crater(lander: &mut Entity, zone: Option<LandingZoneDifficulty>) {
+    let speed = lander.physics.as_ref().map_or(0.0, |physics| physics.velocity.length());
+    let (max_velocity, _) = lander.tuning.zone_limits(zone);
+    let severity = (speed / max_velocity).min(CRATER_MAX_SEVERITY);
+    if severity < 2.0 {
+        return;
+    }
+
+    let points = lander.terrain.len();
+    let center = pixel_to_terrain_x(touchdown_site(lander).x, points, lander.world_size.x).max(0.0) as usize;
+    let radius = pixel_to_terrain_x(lander.transform.size.x * 0.5 * severity, points, lander.world_size.x) as usize;
+    let depth = (severity * CRATER_DEPTH_PER_SEVERITY) as f64;
+    debug!("Impact at {:.1} m/s: crater r={} depth={:.1}", speed, radius, depth);
+    deform_terrain_crater(&mut lander.terrain, center, radius, depth, &lander.landing_zones);
+}
+
+// Shakes the camera in proportion to the impact speed; call before stop_lander zeroes it
+fn start_crash_shake(lander: &mut Entity) {
+    let speed = lander.physics.as_ref().map_or(0.0, |physics| physics.velocity.length());
+    lander.shake_intensity = (speed * SHAKE_PIXELS_PER_MPS).min(MAX_SHAKE_PIXELS);
+    lander.shake_started_at = get_time();
+}
+
+/// Stops all lander audio when an attempt ends.
+///
+/// Clears `current_audio` along with the playback, so the audio state in
+/// `handle_input` starts from nothing on the next attempt and always
+/// switches ambient back on.
+fn silence_lander(lander: &mut Entity, audio: &mut Audio) {
+    shutdown_audio(audio);
+    lander.sound = false;
+    lander.current_audio = None;
+}
+
+/// Plays the end-of-attempt effect, after `silence_lander` so it isn't cut off.
+///
+/// `silence_lander` clears the sound flag and `current_audio`, so `handle_input`
+/// leaves the effect alone until the next attempt starts the ambient loop.
+fn play_effect(lander: &Entity, audio: &mut Audio, name: &str) {
+    if !lander.muted {
+        play_oneshot(audio, name);
+    }
+}
+
+// Bottom-center of the lander, where wreckage or a landing marker is left
+fn touchdown_site(lander: &Entity) -> Vec2 {
+    vec2(
+        lander.transform.position.x + lander.transform.size.x / 2.0,
+        lander.transform.position.y,
+    )
+}
+
+fn check_fuel(lander: &mut Entity, audio: &mut Audio) {
     // Check for empty fuel using rocket engine
     // Note: Running out of fuel doesn't end the mission - just prevents thrust
     if let Some(rocket) = &lander.rocket_physics {
+        if rocket.fuel_percentage() < LOW_FUEL_PERCENT {
+            lander.radio.post_once(FUEL_CRITICAL, get_time());
+        }
         if !rocket.has_fuel() {
             debug!("Out of fuel! Free fall mode.");
             // Don't stop audio or kill lander - let physics continue
             // Player can still try to land safely without thrust
         }
+
+        // Beep while fuel is low but thrust is still possible; the flag is
+        // cleared on reset (refuel) or once the tanks run dry
+        let warning = rocket.fuel_percentage() < LOW_FUEL_WARNING_PERCENT && rocket.has_fuel();
+        let now = get_time();
+        if warning && !lander.low_fuel_warned {
+            info!("Low fuel warning ({:.1}%)", rocket.fuel_percentage());
+            lander.low_fuel_warned = true;
+            lander.low_fuel_beep_at = now;
+            beep_low_fuel(lander, audio);
+        } else if warning && now - lander.low_fuel_beep_at >= LOW_FUEL_BEEP_INTERVAL {
+            lander.low_fuel_beep_at = now;
+            beep_low_fuel(lander, audio);
+        } else if !warning {
+            lander.low_fuel_warned = false;
+        }
+    }
+}
+
+fn check_pickups(lander: &mut Entity, audio: &mut Audio) {
+    let added = lander.collect_fuel_pickups();
+    if added > 0.0 {
+        info!("Fuel canister collected: {:.0} kg", added);
+        // Like the low-fuel beep, the chime plays over the engine sound
+        if !lander.muted {
+            audio.play("fuel_pickup");
+        }
+    }
+}
+
+// Plays the beep alongside the engine sound rather than cutting it off
+fn beep_low_fuel(lander: &Entity, audio: &mut Audio) {
+    if !lander.muted {
+        audio.play("low_fuel_beep");
     }
 }
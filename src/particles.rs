@@ -0,0 +1,148 @@
+//! Exhaust particles streaming from the descent engine.
+//!
+//! Particles live in the same camera coordinates as the lander transform.
+//! Each one leaves the nozzle against the thrust direction, drifts with the
+//! velocity it was given, and fades out over its lifetime. The emission rate
+//! follows the engine's spool level, so a low throttle makes a thin plume.
+
+use macroquad::prelude::*;
+
+/// Particles emitted per second at full thrust
+const EMISSION_RATE: f32 = 240.0;
+/// Exhaust speed range relative to the lander (pixels per second)
+const EXHAUST_SPEED: (f32, f32) = (120.0, 220.0);
+/// Half-angle of the exhaust cone (degrees)
+const SPREAD_DEGREES: f32 = 12.0;
+/// Particle lifetime range (seconds)
+const LIFETIME: (f32, f32) = (0.25, 0.6);
+/// Particle radius at emission (pixels); shrinks as it fades
+const PARTICLE_RADIUS: f32 = 2.5;
+/// Hard cap so a long burn can't grow the list without bound
+const MAX_PARTICLES: usize = 512;
+
+/// A single exhaust particle
+#[derive(Debug, Clone, PartialEq)]
+struct Particle {
+    position: Vec2,
+    velocity: Vec2,
+    age: f32,
+    lifetime: f32,
+}
+
+impl Particle {
+    // Remaining fraction of the lifetime, 1.0 when new and 0.0 when expired
+    fn life_fraction(&self) -> f32 {
+        (1.0 - self.age / self.lifetime).clamp(0.0, 1.0)
+    }
+}
+
+/// Short-lived exhaust particles for one engine
+#[derive(Debug, Clone, Default)]
+pub struct ParticleSystem {
+    particles: Vec<Particle>,
+    pending: f32, // Fractional particles carried to the next frame
+}
+
+impl ParticleSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of live particles
+    pub fn len(&self) -> usize {
+        self.particles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.particles.is_empty()
+    }
+
+    /// Removes every particle, e.g. when the lander is reset.
+    pub fn clear(&mut self) {
+        self.particles.clear();
+        self.pending = 0.0;
+    }
+
+    /// Emits exhaust for one frame of burning.
+    ///
+    /// # Arguments
+    ///
+    /// * `nozzle` - Emission point in camera coordinates
+    /// * `direction` - Unit vector the exhaust leaves along (opposite the thrust)
+    /// * `base_velocity` - Lander velocity, which the particles inherit
+    /// * `level` - Engine thrust level (0.0-1.0); scales the particle count
+    /// * `dt` - Frame time in seconds
+    pub fn emit(&mut self, nozzle: Vec2, direction: Vec2, base_velocity: Vec2, level: f32, dt: f32) {
+        self.pending += EMISSION_RATE * level.clamp(0.0, 1.0) * dt;
+        let count = self.pending.floor();
+        self.pending -= count;
+
+        for _ in 0..count as usize {
+            if self.particles.len() >= MAX_PARTICLES {
+                break;
+            }
+            let spread = rand::gen_range(-SPREAD_DEGREES, SPREAD_DEGREES).to_radians();
+            let speed = rand::gen_range(EXHAUST_SPEED.0, EXHAUST_SPEED.1);
+            self.particles.push(Particle {
+                position: nozzle,
+                velocity: base_velocity + Vec2::from_angle(spread).rotate(direction) * speed,
+                age: 0.0,
+                lifetime: rand::gen_range(LIFETIME.0, LIFETIME.1),
+            });
+        }
+    }
+
+    /// Moves and ages every particle, dropping the expired ones.
+    pub fn update(&mut self, dt: f32) {
+        for particle in &mut self.particles {
+            particle.position += particle.velocity * dt;
+            particle.age += dt;
+        }
+        self.particles.retain(|particle| particle.age < particle.lifetime);
+    }
+
+    /// Draws the particles, fading from yellow-white to a dim orange.
+    ///
+    /// Expects the lander's camera to be set.
+    pub fn draw(&self) {
+        for particle in &self.particles {
+            let life = particle.life_fraction();
+            let color = Color::new(1.0, 0.55 + 0.4 * life, 0.2 + 0.6 * life * life, life);
+            draw_circle(
+                particle.position.x,
+                particle.position.y,
+                PARTICLE_RADIUS * (0.4 + 0.6 * life),
+                color,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_particles_scale_with_level_and_expire() {
+        let mut full = ParticleSystem::new();
+        let mut half = ParticleSystem::new();
+        for _ in 0..4 {
+            full.emit(Vec2::ZERO, vec2(0.0, -1.0), Vec2::ZERO, 1.0, 0.0625);
+            half.emit(Vec2::ZERO, vec2(0.0, -1.0), Vec2::ZERO, 0.5, 0.0625);
+        }
+        assert_eq!(full.len(), 60, "240 particles/s for 0.25 s");
+        assert_eq!(half.len(), 30, "Half throttle emits half as many");
+
+        // Exhaust leaves against the thrust, inside the spread cone
+        full.update(0.1);
+        assert!(full.particles.iter().all(|p| p.position.y < 0.0));
+
+        // Nothing outlives the longest lifetime
+        full.update(LIFETIME.1);
+        assert!(full.is_empty());
+
+        let mut idle = ParticleSystem::new();
+        idle.emit(Vec2::ZERO, vec2(0.0, -1.0), Vec2::ZERO, 0.0, 1.0);
+        assert!(idle.is_empty(), "No particles with the engine off");
+    }
+}
@@ -12,6 +12,95 @@
 
 use macroquad::prelude::*;
 
+/// Spool level below which the engine is considered fully shut down
+const SPOOL_CUTOFF: f32 = 0.01;
+
+/// Fuel vented per second while dumping (kg/s)
+const FUEL_DUMP_RATE: f64 = 250.0;
+/// Fraction of the tank a fuel dump always leaves behind
+const FUEL_DUMP_RESERVE: f64 = 0.05;
+
+/// Exhaust velocity of the RCS jets (m/s), lower than the descent engine's
+const RCS_EXHAUST_VELOCITY: f64 = 2800.0;
+
+/// Moment of inertia of the lander about its center (kg·m²), a ~23 t craft about 4 m across
+const DEFAULT_MOMENT_OF_INERTIA: f64 = 60000.0;
+
+/// Radius of gyration of the lander (m); the fully fueled LM comes to about
+/// `DEFAULT_MOMENT_OF_INERTIA`
+const RADIUS_OF_GYRATION: f64 = 1.6;
+
+/// Radius of the gravity well for the altitude falloff mode, in world units.
+///
+/// Far smaller than the Moon's real radius so the change is noticeable over
+/// a screen of descent: gravity at 500 units up is about 83% of the surface value.
+const GRAVITY_FALLOFF_RADIUS: f32 = 5000.0;
+
+/// Degrees in a full turn, for wrapping the rotation
+const FULL_CIRCLE_DEGREES: f32 = 360.0;
+
+/// Standard gravity g₀ used to express exhaust velocity as specific impulse (m/s²)
+pub const STANDARD_GRAVITY: f64 = 9.80665;
+
+/// Jettisonable descent stage of a two-stage lander.
+///
+/// The stage's dry mass and tank capacity are included in the owning
+/// `RocketEngine`'s `dry_mass` and `max_fuel_mass` until it is jettisoned.
+/// Both stages draw from their tanks proportionally, so jettisoning drops
+/// the stage's share of whatever fuel remains.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DescentStage {
+    pub dry_mass: f64,      // Structure and engine mass dropped on jettison (kg)
+    pub fuel_capacity: f64, // Share of the total tank capacity held by this stage (kg)
+}
+
+impl DescentStage {
+    /// Apollo-style split of the LM: 9,000 kg of the dry mass and 6,000 kg of
+    /// the tank capacity belong to the descent stage.
+    pub const APOLLO: DescentStage = DescentStage {
+        dry_mass: 9000.0,
+        fuel_capacity: 6000.0,
+    };
+}
+
+/// Gravity at a height above the surface, following the inverse-square law.
+///
+/// g(h) = g₀ · (R / (R + h))² with R = `GRAVITY_FALLOFF_RADIUS`, so gravity
+/// grows as the lander nears the surface and reaches `surface_gravity` at
+/// touchdown. Heights below the surface count as the surface.
+///
+/// # Arguments
+///
+/// * `altitude` - Height above the terrain directly below, in world units
+/// * `surface_gravity` - Gravitational acceleration at the surface in m/s²
+///
+/// # Returns
+///
+/// Gravitational acceleration in m/s²
+pub fn gravity_at_altitude(altitude: f32, surface_gravity: f32) -> f32 {
+    let ratio = GRAVITY_FALLOFF_RADIUS / (GRAVITY_FALLOFF_RADIUS + altitude.max(0.0));
+    surface_gravity * ratio * ratio
+}
+
+/// Builds the gravitational acceleration vector.
+///
+/// An angle of 0° points straight down; positive angles rotate gravity the
+/// same way positive lander rotation does (counterclockwise), so a lander at
+/// `rotation == angle_degrees` is upright relative to the effective "down".
+///
+/// # Arguments
+///
+/// * `magnitude` - Gravitational acceleration in m/s²
+/// * `angle_degrees` - Gravity direction relative to straight down
+///
+/// # Returns
+///
+/// Acceleration vector in camera coordinates (Y up)
+pub fn gravity_vector(magnitude: f32, angle_degrees: f32) -> Vec2 {
+    let angle = angle_degrees.to_radians();
+    vec2(angle.sin(), -angle.cos()) * magnitude
+}
+
 /// Rocket engine component with realistic propulsion parameters
 #[derive(Debug, Clone)]
 pub struct RocketEngine {
@@ -22,6 +111,17 @@ pub struct RocketEngine {
     pub max_thrust: f64,         // Maximum thrust force (N)
     pub thrust_vector: Vec2,     // Current thrust as 2D vector (N)
     pub is_thrusting: bool,      // Whether engine is firing
+    pub spool_time_constant: f32, // Engine lag time constant (s), 0 = instant response
+    pub spool_level: f32,        // Actual thrust level after engine lag (0.0-1.0)
+    pub thrust_direction: Vec2,  // Unit direction of the last commanded thrust
+    pub descent_stage: Option<DescentStage>, // Droppable stage for two-stage craft
+    pub stage_jettisoned: bool,  // Whether the descent stage has been dropped
+    pub is_dumping: bool,        // Whether fuel is being vented overboard
+    pub throttle: f32,           // Throttle setting (0.0-1.0) scaling the commanded thrust
+    pub rcs_thrust: f64,         // Force of the lateral RCS jets (N)
+    pub rcs_fuel_mass: f64,      // RCS propellant left, part of the total mass until burned (kg)
+    pub max_rcs_fuel_mass: f64,  // RCS propellant capacity (kg)
+    pub rcs_direction: f32,      // Commanded RCS firing: -1 left, 1 right, 0 off
 }
 
 impl RocketEngine {
@@ -29,11 +129,13 @@ impl RocketEngine {
     ///
     /// # Specifications
     ///
-    /// - **Dry mass**: 15,000 kg (unfueled spacecraft)
+    /// - **Dry mass**: 14,900 kg (unfueled spacecraft; 15,000 kg with the RCS propellant)
     /// - **Fuel capacity**: 8,200 kg
     /// - **Exhaust velocity**: 3,050 m/s
     /// - **Maximum thrust**: 150,000 N (4x realistic for better gameplay)
     /// - **Thrust-to-weight ratio**: 4.0+ (excellent controllability)
+    /// - **Spool time constant**: 0.3 s (engine lag between command and thrust)
+    /// - **RCS**: 10,000 N lateral jets with 100 kg of their own propellant
     ///
     /// These values are based on the Apollo Lunar Module but enhanced for gameplay.
     ///
@@ -42,23 +144,83 @@ impl RocketEngine {
     /// A new `RocketEngine` instance with Apollo LM specifications
     pub fn new_apollo_lm() -> Self {
         Self {
-            dry_mass: 15000.0,        // Apollo LM dry mass (~15,000 kg)
+            dry_mass: 14900.0,        // Apollo LM dry mass (~15,000 kg) less the RCS propellant
             fuel_mass: 8200.0,        // Apollo LM fuel mass (~8,200 kg)
             max_fuel_mass: 8200.0,    // Maximum fuel capacity
             exhaust_velocity: 3050.0, // Apollo LM engine exhaust velocity
             max_thrust: 150000.0,     // Enhanced thrust for better gameplay (4x realistic)
             thrust_vector: Vec2::ZERO,
             is_thrusting: false,
+            spool_time_constant: 0.3, // Descent engine takes ~0.3s to respond
+            spool_level: 0.0,
+            thrust_direction: Vec2::Y,
+            descent_stage: None,
+            stage_jettisoned: false,
+            is_dumping: false,
+            throttle: 1.0,
+            rcs_thrust: 10000.0,      // Gentle sideways push (~0.4 m/s² fully fueled)
+            rcs_fuel_mass: 100.0,     // About 28 s of firing
+            max_rcs_fuel_mass: 100.0,
+            rcs_direction: 0.0,
+        }
+    }
+
+    /// Turns this engine into a two-stage craft with the given descent stage.
+    ///
+    /// The stage's mass and tank capacity are carved out of the existing
+    /// `dry_mass` and `max_fuel_mass`, so the fully fueled total is unchanged.
+    pub fn with_descent_stage(mut self, stage: DescentStage) -> Self {
+        self.descent_stage = Some(stage);
+        self.stage_jettisoned = false;
+        self
+    }
+
+    /// Whether a descent stage is attached and can still be jettisoned.
+    pub fn can_jettison(&self) -> bool {
+        self.descent_stage.is_some() && !self.stage_jettisoned
+    }
+
+    /// Drops the descent stage, shedding its dry mass and its share of the fuel.
+    ///
+    /// This can only happen once per flight; `refuel` reattaches the stage.
+    ///
+    /// # Returns
+    ///
+    /// Total mass dropped in kilograms, or `None` if there was no stage to drop
+    pub fn jettison(&mut self) -> Option<f64> {
+        if !self.can_jettison() {
+            return None;
         }
+        let stage = self.descent_stage?;
+
+        let fuel_dropped = self.fuel_mass * (stage.fuel_capacity / self.max_fuel_mass);
+        self.fuel_mass -= fuel_dropped;
+        self.max_fuel_mass -= stage.fuel_capacity;
+        self.dry_mass -= stage.dry_mass;
+        self.stage_jettisoned = true;
+
+        Some(stage.dry_mass + fuel_dropped)
     }
 
-    /// Returns the total mass of the spacecraft (dry mass + fuel).
+    /// Returns the total mass of the spacecraft (dry mass + fuel + RCS propellant).
     ///
     /// # Returns
     ///
     /// Total mass in kilograms
     pub fn total_mass(&self) -> f64 {
-        self.dry_mass + self.fuel_mass
+        self.dry_mass + self.fuel_mass + self.rcs_fuel_mass
+    }
+
+    /// Returns the moment of inertia of the spacecraft about its center.
+    ///
+    /// Modeled as the total mass at a fixed radius of gyration, I = m·r², so
+    /// the lander turns more readily as fuel burns off or a stage is dropped.
+    ///
+    /// # Returns
+    ///
+    /// Moment of inertia in kg·m²
+    pub fn moment_of_inertia(&self) -> f64 {
+        self.total_mass() * RADIUS_OF_GYRATION * RADIUS_OF_GYRATION
     }
 
     /// Returns the current fuel level as a percentage.
@@ -79,27 +241,182 @@ impl RocketEngine {
         self.fuel_mass > 0.0
     }
 
+    /// Vents fuel overboard without producing thrust.
+    ///
+    /// Fuel flows out at `FUEL_DUMP_RATE` but never below `FUEL_DUMP_RESERVE`
+    /// of the tank, so a dump cannot leave the engine dry for the touchdown.
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - Time step in seconds
+    ///
+    /// # Returns
+    ///
+    /// Mass of fuel vented this step in kg
+    pub fn dump_fuel(&mut self, dt: f32) -> f64 {
+        let reserve = self.max_fuel_mass * FUEL_DUMP_RESERVE;
+        let dumped = (FUEL_DUMP_RATE * dt as f64).min(self.fuel_mass - reserve).max(0.0);
+        self.fuel_mass -= dumped;
+        dumped
+    }
+
+    /// Adds fuel to the tank, up to its capacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Fuel offered in kg
+    ///
+    /// # Returns
+    ///
+    /// Mass of fuel actually taken on in kg
+    pub fn add_fuel(&mut self, amount: f64) -> f64 {
+        let added = amount.min(self.max_fuel_mass - self.fuel_mass).max(0.0);
+        self.fuel_mass += added;
+        added
+    }
+
     /// Refuels the rocket to full capacity.
     ///
-    /// Resets fuel_mass to max_fuel_mass for mission restart scenarios.
+    /// Resets fuel_mass to max_fuel_mass for mission restart scenarios,
+    /// reattaching the descent stage first if it was jettisoned.
     pub fn refuel(&mut self) {
+        if let (Some(stage), true) = (self.descent_stage, self.stage_jettisoned) {
+            self.dry_mass += stage.dry_mass;
+            self.max_fuel_mass += stage.fuel_capacity;
+            self.stage_jettisoned = false;
+        }
         self.fuel_mass = self.max_fuel_mass;
+        self.rcs_fuel_mass = self.max_rcs_fuel_mass;
+    }
+
+    /// Returns the specific impulse of the engine.
+    ///
+    /// Isp = v_e / g₀, the conventional way to quote engine efficiency.
+    ///
+    /// # Returns
+    ///
+    /// Specific impulse in seconds
+    pub fn specific_impulse(&self) -> f64 {
+        self.exhaust_velocity / STANDARD_GRAVITY
+    }
+
+    /// Sets the effective exhaust velocity, rejecting values that are not positive.
+    ///
+    /// A higher exhaust velocity burns less fuel for the same thrust, so the same
+    /// tank yields more delta-V.
+    ///
+    /// # Arguments
+    ///
+    /// * `exhaust_velocity` - New exhaust velocity in m/s
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value was applied, `false` if it was rejected
+    pub fn set_exhaust_velocity(&mut self, exhaust_velocity: f64) -> bool {
+        if exhaust_velocity.is_finite() && exhaust_velocity > 0.0 {
+            self.exhaust_velocity = exhaust_velocity;
+            true
+        } else {
+            warn!("Rejected exhaust velocity {} m/s, keeping {}", exhaust_velocity, self.exhaust_velocity);
+            false
+        }
     }
 
     /// Stops thrust generation and resets thrust vector to zero.
     ///
-    /// This is a convenience method for input handling and emergency stops.
+    /// This is a convenience method for input handling. The engine still spools
+    /// down over `spool_time_constant`; use `shutdown` to cut it immediately.
     pub fn stop_thrust(&mut self) {
         self.thrust_vector = Vec2::ZERO;
         self.is_thrusting = false;
     }
 
+    /// Sets the throttle, clamped to 0.0-1.0.
+    ///
+    /// # Arguments
+    ///
+    /// * `throttle` - Fraction of `max_thrust` the engine delivers when firing
+    pub fn set_throttle(&mut self, throttle: f32) {
+        self.throttle = throttle.clamp(0.0, 1.0);
+    }
+
+    /// Cuts the engine immediately, skipping the spool-down.
+    ///
+    /// Used for crashes, landings and resets where no residual thrust should remain.
+    pub fn shutdown(&mut self) {
+        self.stop_thrust();
+        self.spool_level = 0.0;
+        self.rcs_direction = 0.0;
+    }
+
+    /// RCS propellant remaining as a percentage of the full RCS tank.
+    pub fn rcs_fuel_percentage(&self) -> f32 {
+        (self.rcs_fuel_mass / self.max_rcs_fuel_mass * 100.0) as f32
+    }
+
+    /// Checks if the RCS jets have propellant remaining.
+    pub fn has_rcs_fuel(&self) -> bool {
+        self.rcs_fuel_mass > 0.0
+    }
+
+    /// Fires the lateral RCS jets for one time step.
+    ///
+    /// The jets push along the lander's own sideways axis, so the craft
+    /// translates without rotating. They burn their own propellant at
+    /// dm/dt = F / v_e; once it runs out they produce nothing, while the
+    /// main engine is unaffected.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - Firing direction: negative pushes left, positive right, 0 is off
+    /// * `rotation_degrees` - Lander rotation in degrees (0° = pointing up)
+    /// * `dt` - Time step in seconds
+    ///
+    /// # Returns
+    ///
+    /// RCS force vector in Newtons, or zero vector if off or out of propellant
+    pub fn fire_rcs(&mut self, direction: f32, rotation_degrees: f32, dt: f32) -> Vec2 {
+        if direction == 0.0 || !self.has_rcs_fuel() {
+            return Vec2::ZERO;
+        }
+
+        let fuel_consumed = self.rcs_thrust / RCS_EXHAUST_VELOCITY * dt as f64;
+        self.rcs_fuel_mass = (self.rcs_fuel_mass - fuel_consumed).max(0.0);
+
+        // The lander's right-hand side, perpendicular to its thrust axis
+        let angle = rotation_degrees.to_radians();
+        let lateral = vec2(angle.cos(), angle.sin());
+        lateral * direction.signum() * self.rcs_thrust as f32
+    }
+
+    /// Commands thrust along the lander's orientation.
+    ///
+    /// The delivered thrust is `max_thrust` scaled by the current `throttle`.
+    ///
+    /// The lander's "up" is 0°, so 90° is added to convert to standard math
+    /// coordinates before building the thrust direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `rotation_degrees` - Lander rotation in degrees (0° = pointing up)
+    pub fn fire(&mut self, rotation_degrees: f32) {
+        let angle = (rotation_degrees + 90.0).to_radians();
+        let thrust_direction = vec2(angle.cos(), angle.sin());
+
+        // Apply thrust vector (magnitude determined by max_thrust)
+        self.thrust_vector = thrust_direction * self.max_thrust as f32;
+        self.is_thrusting = true;
+    }
+
     /// Generates thrust force and consumes fuel based on current thrust settings.
     ///
     /// This function implements realistic rocket physics:
-    /// - Thrust force is applied in the direction of `thrust_vector`
-    /// - Fuel consumption follows: dm/dt = F / v_e
-    /// - Only consumes fuel when actively thrusting
+    /// - The commanded thrust is `max_thrust` scaled by `throttle`
+    /// - The engine spools toward the commanded thrust with a first-order lag
+    ///   (`spool_time_constant`), so quick taps deliver less than full thrust
+    /// - Thrust force is applied in the direction of the last commanded `thrust_vector`
+    /// - Fuel consumption follows: dm/dt = F / v_e, using the spooled thrust
+    /// - Running out of fuel flames the engine out immediately
     ///
     /// # Arguments
     ///
@@ -107,15 +424,38 @@ impl RocketEngine {
     ///
     /// # Returns
     ///
-    /// Thrust force vector in Newtons, or zero vector if no fuel/not thrusting
+    /// Thrust force vector in Newtons, or zero vector if no fuel/spooled down
     pub fn generate_thrust(&mut self, dt: f32) -> Vec2 {
-        if !self.is_thrusting || self.fuel_mass <= 0.0 {
+        if self.fuel_mass <= 0.0 {
             self.thrust_vector = Vec2::ZERO;
+            self.spool_level = 0.0;
             return Vec2::ZERO;
         }
 
-        // Calculate thrust magnitude (clamped to available fuel and max thrust)
-        let thrust_magnitude = self.thrust_vector.length().min(self.max_thrust as f32);
+        // Commanded thrust level (0-1) from the player's input
+        let commanded_level = if self.is_thrusting {
+            let commanded_thrust = self.thrust_vector.length().min(self.max_thrust as f32);
+            if commanded_thrust > 0.0 {
+                self.thrust_direction = self.thrust_vector / self.thrust_vector.length();
+            }
+            commanded_thrust / self.max_thrust as f32 * self.throttle.clamp(0.0, 1.0)
+        } else {
+            self.thrust_vector = Vec2::ZERO;
+            0.0
+        };
+
+        // Engine lag: move toward the commanded level with time constant tau
+        self.spool_level = if self.spool_time_constant > 0.0 {
+            let blend = 1.0 - (-dt / self.spool_time_constant).exp();
+            self.spool_level + (commanded_level - self.spool_level) * blend
+        } else {
+            commanded_level
+        };
+        if commanded_level == 0.0 && self.spool_level < SPOOL_CUTOFF {
+            self.spool_level = 0.0;
+        }
+
+        let thrust_magnitude = self.spool_level * self.max_thrust as f32;
 
         if thrust_magnitude > 0.0 {
             // Calculate mass flow rate from thrust and exhaust velocity
@@ -127,18 +467,68 @@ impl RocketEngine {
             self.fuel_mass = (self.fuel_mass - fuel_consumed).max(0.0);
 
             // Return thrust force vector
-            self.thrust_vector
+            self.thrust_direction * thrust_magnitude
         } else {
             Vec2::ZERO
         }
     }
 }
 
+/// Numerical integration scheme used by `Physics::integrate`.
+///
+/// The schemes differ in how well they conserve energy, which matters most at
+/// low frame rates where each step is long.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Integrator {
+    /// Position advances with the velocity from the start of the step (first order)
+    ExplicitEuler,
+    /// Velocity is updated first and the new velocity moves the position (symplectic)
+    #[default]
+    SemiImplicitEuler,
+    /// Moves with the mean velocity over the step (second order, exact under constant forces)
+    VelocityVerlet,
+}
+
+impl Integrator {
+    /// All integrators, in the order they are listed on the command line.
+    pub const ALL: [Integrator; 3] = [
+        Integrator::ExplicitEuler,
+        Integrator::SemiImplicitEuler,
+        Integrator::VelocityVerlet,
+    ];
+
+    /// Short name used on the command line and in the debug overlay.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Integrator::ExplicitEuler => "euler",
+            Integrator::SemiImplicitEuler => "semi-implicit",
+            Integrator::VelocityVerlet => "verlet",
+        }
+    }
+
+    /// Looks up an integrator by name, ignoring case.
+    ///
+    /// # Returns
+    ///
+    /// The matching integrator, or `None` for an unknown name
+    pub fn from_name(name: &str) -> Option<Integrator> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|integrator| integrator.name().eq_ignore_ascii_case(name))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Physics {
     pub velocity: Vec2,
     pub mass: f64,
     pub forces: Vec2,  // Accumulated forces for this frame
+    pub integrator: Integrator,
+    pub angular_velocity: f32,  // Spin rate in degrees per second, positive counterclockwise
+    pub moment_of_inertia: f64, // Resistance to spin about the center (kg·m²)
+    pub torque: f32,            // Commanded torque (N·m), held until changed
+    pub drag_coefficient: f32,  // Atmospheric drag k in F = -k·v·|v| (kg/m); 0 in vacuum
 }
 
 impl Physics {
@@ -150,15 +540,45 @@ impl Physics {
     ///
     /// # Returns
     ///
-    /// A new `Physics` instance with zero velocity and forces
+    /// A new `Physics` instance with zero velocity, spin and forces
     pub fn new(mass: f64) -> Self {
         Self {
             velocity: Vec2::ZERO,
             mass,
             forces: Vec2::ZERO,
+            integrator: Integrator::default(),
+            angular_velocity: 0.0,
+            moment_of_inertia: DEFAULT_MOMENT_OF_INERTIA,
+            torque: 0.0,
+            drag_coefficient: 0.0,
         }
     }
 
+    /// Selects the integration scheme, builder style.
+    pub fn with_integrator(mut self, integrator: Integrator) -> Self {
+        self.integrator = integrator;
+        self
+    }
+
+    /// Sets the atmospheric drag coefficient, builder style.
+    pub fn with_drag_coefficient(mut self, drag_coefficient: f32) -> Self {
+        self.drag_coefficient = drag_coefficient;
+        self
+    }
+
+    /// Atmospheric drag at the current velocity, F = -k·v·|v|.
+    ///
+    /// Drag grows with the square of the speed, so a body falling under
+    /// gravity `g` settles at the terminal speed √(m·g / k) instead of
+    /// speeding up forever. Zero in vacuum.
+    ///
+    /// # Returns
+    ///
+    /// Drag force in Newtons, opposite the velocity
+    pub fn drag_force(&self) -> Vec2 {
+        -self.drag_coefficient * self.velocity * self.velocity.length()
+    }
+
     /// Clears accumulated forces for the next physics step.
     ///
     /// This should be called at the start of each frame to prevent
@@ -178,19 +598,88 @@ impl Physics {
         self.forces += force;
     }
 
+    /// Sets the torque applied on every following step until changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `torque` - Torque in N·m, positive turns counterclockwise; 0 lets the spin coast
+    pub fn apply_torque(&mut self, torque: f32) {
+        self.torque = torque;
+    }
+
+    /// Integrates the spin into the rotation (semi-implicit Euler).
+    ///
+    /// The commanded torque accelerates the spin by τ / I. Nothing damps it,
+    /// so a spinning lander keeps turning until an opposite torque stops it.
+    /// A bad inertia or step leaves the spin unchanged, and a non-finite spin
+    /// is reset to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `rotation` - Rotation in degrees, updated in place and kept in 0-360
+    /// * `dt` - Time step in seconds
+    pub fn integrate_rotation(&mut self, rotation: &mut f32, dt: f32) {
+        if self.moment_of_inertia > 0.0 && self.moment_of_inertia.is_finite() && dt.is_finite() {
+            let angular_acceleration = (self.torque as f64 / self.moment_of_inertia).to_degrees() as f32;
+            self.angular_velocity += angular_acceleration * dt;
+        }
+        if !self.angular_velocity.is_finite() {
+            warn!("Non-finite angular velocity {} reset to zero", self.angular_velocity);
+            self.angular_velocity = 0.0;
+        }
+        let next = *rotation + self.angular_velocity * dt;
+        if next.is_finite() {
+            *rotation = next.rem_euclid(FULL_CIRCLE_DEGREES);
+        }
+    }
+
     /// Integrates motion using accumulated forces.
     ///
-    /// This function performs numerical integration using:
-    /// - F = ma (force equals mass times acceleration)
-    /// - v = v₀ + at (velocity integration)
+    /// Advances both velocity and position by one step with the selected
+    /// `Integrator`, using F = ma for the acceleration:
+    /// - Explicit Euler: x += v·dt, then v += a·dt
+    /// - Semi-implicit Euler: v += a·dt, then x += v·dt
+    /// - Velocity Verlet: x += v·dt + ½a·dt², then v += a·dt
+    ///
+    /// Forces are accumulated once per step and held for its duration, so the
+    /// Verlet step uses the same acceleration at both ends. Gravity and thrust
+    /// are constant within a frame, which makes it exact for the lander.
+    ///
+    /// Non-finite inputs are caught rather than propagated: NaN/Inf forces are
+    /// discarded, a zero or non-finite mass skips the step, and a velocity that
+    /// still ends up non-finite is reset to zero. Each case logs a warning.
     ///
     /// # Arguments
     ///
+    /// * `position` - Body position, updated in place
     /// * `dt` - Time step in seconds
-    pub fn integrate(&mut self, dt: f32) {
-        if self.mass > 0.0 {
+    pub fn integrate(&mut self, position: &mut Vec2, dt: f32) {
+        if !self.forces.is_finite() {
+            warn!("Non-finite force {:?} discarded", self.forces);
+            self.forces = Vec2::ZERO;
+        }
+
+        if self.mass > 0.0 && self.mass.is_finite() && dt.is_finite() {
             let acceleration = self.forces / self.mass as f32;
-            self.velocity += acceleration * dt;
+            match self.integrator {
+                Integrator::ExplicitEuler => {
+                    *position += self.velocity * dt;
+                    self.velocity += acceleration * dt;
+                }
+                Integrator::SemiImplicitEuler => {
+                    self.velocity += acceleration * dt;
+                    *position += self.velocity * dt;
+                }
+                Integrator::VelocityVerlet => {
+                    *position += self.velocity * dt + 0.5 * acceleration * dt * dt;
+                    self.velocity += acceleration * dt;
+                }
+            }
+        }
+
+        if !self.velocity.is_finite() {
+            warn!("Non-finite velocity {:?} reset to zero", self.velocity);
+            self.velocity = Vec2::ZERO;
         }
     }
 }
@@ -219,8 +708,9 @@ pub fn calculate_delta_v(rocket: &RocketEngine) -> f64 {
         return 0.0;
     }
 
+    // The main engine can't burn the RCS propellant, so it stays in the final mass
     let initial_mass = rocket.total_mass();
-    let final_mass = rocket.dry_mass;
+    let final_mass = rocket.total_mass() - rocket.fuel_mass;
 
     // Tsiolkovsky rocket equation: Δv = v_e * ln(m_initial / m_final)
     rocket.exhaust_velocity * (initial_mass / final_mass).ln()
@@ -234,18 +724,110 @@ mod tests {
     #[test]
     fn test_apollo_lm_specs() {
         let rocket = RocketEngine::new_apollo_lm();
-        assert_eq!(rocket.dry_mass, 15000.0);
+        assert_eq!(rocket.dry_mass, 14900.0);
+        assert_eq!(rocket.rcs_fuel_mass, 100.0);
         assert_eq!(rocket.fuel_mass, 8200.0);
         assert_eq!(rocket.total_mass(), 23200.0);
         assert_eq!(rocket.fuel_percentage(), 100.0);
     }
 
+    #[test]
+    fn test_fuel_dump_keeps_reserve() {
+        let mut rocket = RocketEngine::new_apollo_lm();
+        let full = rocket.fuel_mass;
+
+        let dumped = rocket.dump_fuel(1.0);
+        assert_eq!(dumped, FUEL_DUMP_RATE);
+        assert_eq!(rocket.fuel_mass, full - FUEL_DUMP_RATE);
+        assert_eq!(rocket.spool_level, 0.0, "Dumping produces no thrust");
+
+        // A long dump stops at the reserve and then vents nothing
+        rocket.dump_fuel(1000.0);
+        let reserve = rocket.max_fuel_mass * FUEL_DUMP_RESERVE;
+        assert!((rocket.fuel_mass - reserve).abs() < 1e-6);
+        assert_eq!(rocket.dump_fuel(1.0), 0.0);
+        assert!(rocket.has_fuel());
+    }
+
+    #[test]
+    fn test_add_fuel_stops_at_capacity() {
+        let mut rocket = RocketEngine::new_apollo_lm();
+        assert_eq!(rocket.add_fuel(500.0), 0.0, "A full tank takes nothing");
+
+        rocket.fuel_mass = 7000.0;
+        assert_eq!(rocket.add_fuel(500.0), 500.0);
+        assert_eq!(rocket.add_fuel(1500.0), 700.0);
+        assert_eq!(rocket.fuel_mass, rocket.max_fuel_mass);
+    }
+
+    #[test]
+    fn test_jettison_descent_stage() {
+        let mut rocket = RocketEngine::new_apollo_lm().with_descent_stage(DescentStage::APOLLO);
+        assert_eq!(rocket.total_mass(), 23200.0, "Staging should not change the fueled mass");
+        let delta_v_before = calculate_delta_v(&rocket);
+
+        // Burn half the fuel, then drop the stage
+        rocket.fuel_mass = 4100.0;
+        let dropped = rocket.jettison().unwrap();
+
+        // 9000 kg of structure plus the stage's 6000/8200 share of the remaining fuel
+        assert!((dropped - (9000.0 + 3000.0)).abs() < 1e-6, "Dropped {}", dropped);
+        assert!((rocket.total_mass() - 7100.0).abs() < 1e-6);
+        assert!((rocket.fuel_percentage() - 50.0).abs() < 1e-3, "Fuel level should be unchanged");
+        assert!(calculate_delta_v(&rocket) > delta_v_before / 2.0);
+
+        // One-time only
+        assert!(!rocket.can_jettison());
+        assert_eq!(rocket.jettison(), None);
+
+        // Refueling reattaches the stage for the next attempt
+        rocket.refuel();
+        assert_eq!(rocket.total_mass(), 23200.0);
+        assert!(rocket.can_jettison());
+
+        // Single-stage craft have nothing to drop
+        assert_eq!(RocketEngine::new_apollo_lm().jettison(), None);
+    }
+
+    #[test]
+    fn test_exhaust_velocity_tuning() {
+        let mut rocket = RocketEngine::new_apollo_lm();
+        assert!((rocket.specific_impulse() - 311.0).abs() < 0.1, "Isp was {}", rocket.specific_impulse());
+
+        // A more efficient engine gets more delta-V from the same fuel
+        let delta_v_before = calculate_delta_v(&rocket);
+        assert!(rocket.set_exhaust_velocity(4000.0));
+        assert!(calculate_delta_v(&rocket) > delta_v_before);
+
+        // Non-positive or non-finite values are rejected
+        assert!(!rocket.set_exhaust_velocity(0.0));
+        assert!(!rocket.set_exhaust_velocity(-100.0));
+        assert!(!rocket.set_exhaust_velocity(f64::NAN));
+        assert_eq!(rocket.exhaust_velocity, 4000.0);
+    }
+
+    #[test]
+    fn test_gravity_vector() {
+        assert_eq!(gravity_vector(1.625, 0.0), vec2(0.0, -1.625));
+
+        // Tilted gravity keeps its magnitude and points down-right for positive angles
+        let tilted = gravity_vector(1.625, 30.0);
+        assert!((tilted.length() - 1.625).abs() < 1e-6);
+        assert!(tilted.x > 0.0 && tilted.y < 0.0);
+
+        // A lander rotated by the gravity angle thrusts straight against gravity
+        let mut rocket = RocketEngine::new_apollo_lm();
+        rocket.fire(30.0);
+        let thrust_direction = rocket.thrust_vector.normalize();
+        assert!((thrust_direction + tilted.normalize()).length() < 1e-5);
+    }
+
     #[test]
     fn test_delta_v_calculation() {
         let rocket = RocketEngine::new_apollo_lm();
         let delta_v = calculate_delta_v(&rocket);
 
-        // With our current values: dry_mass=15000, fuel_mass=8200, exhaust_velocity=3050
+        // With our current values: dry_mass=14900 + 100 RCS, fuel_mass=8200, exhaust_velocity=3050
         // Δv = 3050 * ln(23200/15000) = 3050 * ln(1.547) = 3050 * 0.436 = 1330 m/s
         // This is reasonable for a lunar lander with limited fuel capacity
         assert!((delta_v - 1330.0).abs() < 50.0, "Delta-V was {}, expected ~1330", delta_v);
@@ -267,7 +849,286 @@ mod tests {
         
         // Test force integration
         physics.add_force(thrust_force);
-        physics.integrate(1.0);
+        physics.integrate(&mut Vec2::ZERO, 1.0);
         assert!(physics.velocity.length() > 0.0, "Should have velocity from thrust");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_integrate_degenerate_inputs_stay_finite() {
+        // Zero mass with garbage forces
+        let mut physics = Physics::new(0.0);
+        physics.add_force(Vec2::new(f32::NAN, f32::INFINITY));
+        physics.integrate(&mut Vec2::ZERO, 1.0 / 60.0);
+        assert!(physics.velocity.is_finite());
+        assert!(physics.forces.is_finite());
+
+        // Mass so small it rounds to zero as f32, producing an infinite acceleration
+        let mut physics = Physics::new(1e-60);
+        physics.velocity = Vec2::new(3.0, -4.0);
+        physics.add_force(Vec2::new(0.0, 1000.0));
+        physics.integrate(&mut Vec2::ZERO, 1.0 / 60.0);
+        assert!(physics.velocity.is_finite(), "Velocity was {:?}", physics.velocity);
+
+        // NaN mass skips the step but keeps the existing velocity
+        let mut physics = Physics::new(f64::NAN);
+        physics.velocity = Vec2::new(1.0, 2.0);
+        physics.add_force(Vec2::new(10.0, 10.0));
+        physics.integrate(&mut Vec2::ZERO, 1.0);
+        assert_eq!(physics.velocity, Vec2::new(1.0, 2.0));
+    }
+
+    /// Runs `steps` steps of a unit mass under `force(position)` and returns
+    /// the largest relative change in total energy `energy(position, velocity)`.
+    fn energy_drift(
+        integrator: Integrator,
+        steps: usize,
+        dt: f32,
+        force: impl Fn(Vec2) -> Vec2,
+        energy: impl Fn(Vec2, Vec2) -> f32,
+    ) -> f32 {
+        let mut physics = Physics::new(1.0).with_integrator(integrator);
+        physics.velocity = Vec2::new(0.0, 20.0);
+        let mut position = Vec2::new(0.0, 100.0);
+        let initial = energy(position, physics.velocity);
+
+        let mut drift: f32 = 0.0;
+        for _ in 0..steps {
+            physics.reset_forces();
+            physics.add_force(force(position));
+            physics.integrate(&mut position, dt);
+            drift = drift.max((energy(position, physics.velocity) - initial).abs() / initial);
+        }
+        drift
+    }
+
+    #[test]
+    fn test_integrator_energy_drift_under_gravity() {
+        // Lunar free fall stepped at 10 fps
+        let gravity = |_: Vec2| Vec2::new(0.0, -1.625);
+        let energy = |position: Vec2, velocity: Vec2| 0.5 * velocity.length_squared() + 1.625 * position.y;
+
+        let euler = energy_drift(Integrator::ExplicitEuler, 200, 0.1, gravity, energy);
+        let semi = energy_drift(Integrator::SemiImplicitEuler, 200, 0.1, gravity, energy);
+        let verlet = energy_drift(Integrator::VelocityVerlet, 200, 0.1, gravity, energy);
+
+        assert!(euler > 1e-3, "Euler drift {}", euler);
+        assert!(semi > 1e-3, "Semi-implicit drift {}", semi);
+        assert!(verlet < 1e-4, "Verlet is exact under constant force, drift {}", verlet);
+    }
+
+    #[test]
+    fn test_integrator_energy_drift_in_oscillator() {
+        // Spring pulling back to (0, 100): explicit Euler gains energy every step,
+        // semi-implicit Euler stays bounded
+        let spring = |position: Vec2| -(position - Vec2::new(0.0, 100.0));
+        let energy = |position: Vec2, velocity: Vec2| {
+            0.5 * velocity.length_squared() + 0.5 * (position - Vec2::new(0.0, 100.0)).length_squared()
+        };
+
+        let euler = energy_drift(Integrator::ExplicitEuler, 500, 0.1, spring, energy);
+        let semi = energy_drift(Integrator::SemiImplicitEuler, 500, 0.1, spring, energy);
+
+        assert!(euler > 1.0, "Euler drift {}", euler);
+        assert!(semi < 0.1, "Semi-implicit drift {}", semi);
+    }
+
+    #[test]
+    fn test_integrator_from_name() {
+        assert_eq!(Integrator::from_name("Verlet"), Some(Integrator::VelocityVerlet));
+        assert_eq!(Integrator::from_name("euler"), Some(Integrator::ExplicitEuler));
+        assert_eq!(Integrator::from_name("rk4"), None);
+        assert_eq!(Integrator::default(), Integrator::SemiImplicitEuler);
+    }
+
+    #[test]
+    fn test_engine_spool_lag() {
+        let mut rocket = RocketEngine::new_apollo_lm();
+        rocket.fire(0.0);
+
+        // A quick tap does not reach full thrust
+        let tap = rocket.generate_thrust(0.05);
+        assert!(tap.length() < rocket.max_thrust as f32 * 0.5, "Tap thrust was {}", tap.length());
+
+        // Holding for several time constants approaches full thrust
+        let mut thrust = Vec2::ZERO;
+        for _ in 0..60 {
+            thrust = rocket.generate_thrust(1.0 / 30.0);
+        }
+        assert!(thrust.length() > rocket.max_thrust as f32 * 0.95);
+        assert!(thrust.y > 0.0, "Upright lander should thrust upward");
+
+        // Releasing the key spools down instead of snapping to zero
+        rocket.stop_thrust();
+        let residual = rocket.generate_thrust(0.05);
+        assert!(residual.length() > 0.0 && residual.length() < thrust.length());
+
+        // Eventually the engine shuts down completely and stops burning fuel
+        for _ in 0..120 {
+            rocket.generate_thrust(1.0 / 30.0);
+        }
+        let fuel = rocket.fuel_mass;
+        assert_eq!(rocket.generate_thrust(1.0 / 30.0), Vec2::ZERO);
+        assert_eq!(rocket.fuel_mass, fuel);
+
+        // Zero time constant responds instantly
+        rocket.spool_time_constant = 0.0;
+        rocket.fire(0.0);
+        let instant = rocket.generate_thrust(0.01);
+        assert!((instant.length() - rocket.max_thrust as f32).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_throttle_scales_thrust_and_fuel_burn() {
+        let burn = |throttle: f32| {
+            let mut rocket = RocketEngine::new_apollo_lm();
+            rocket.spool_time_constant = 0.0;
+            rocket.set_throttle(throttle);
+            rocket.fire(0.0);
+            let thrust = rocket.generate_thrust(1.0);
+            (thrust.length(), rocket.max_fuel_mass - rocket.fuel_mass)
+        };
+
+        let (full_thrust, full_fuel) = burn(1.0);
+        let (half_thrust, half_fuel) = burn(0.5);
+        assert!((half_thrust - full_thrust * 0.5).abs() < 1.0);
+        assert!((half_fuel - full_fuel * 0.5).abs() < 1e-3, "Fuel burn follows thrust");
+        assert_eq!(burn(0.0), (0.0, 0.0), "Closed throttle burns nothing");
+
+        let mut rocket = RocketEngine::new_apollo_lm();
+        rocket.set_throttle(1.5);
+        assert_eq!(rocket.throttle, 1.0);
+        rocket.set_throttle(-0.2);
+        assert_eq!(rocket.throttle, 0.0);
+    }
+
+    #[test]
+    fn test_rcs_translates_until_dry() {
+        let mut rocket = RocketEngine::new_apollo_lm();
+        let fuel = rocket.fuel_mass;
+
+        // Upright, the jets push straight sideways
+        let right = rocket.fire_rcs(1.0, 0.0, 1.0);
+        assert!((right - vec2(rocket.rcs_thrust as f32, 0.0)).length() < 1e-3);
+        let left = rocket.fire_rcs(-1.0, 0.0, 1.0);
+        assert!(left.x < 0.0 && left.y.abs() < 1e-3);
+
+        // Tilted 90° left, "right" points straight up along the body axis
+        let tilted = rocket.fire_rcs(1.0, 90.0, 1.0);
+        assert!(tilted.x.abs() < 1e-3 && tilted.y > 0.0);
+
+        assert_eq!(rocket.fire_rcs(0.0, 0.0, 1.0), Vec2::ZERO);
+        assert_eq!(rocket.fuel_mass, fuel, "RCS draws on its own propellant");
+        assert!(rocket.rcs_fuel_mass < rocket.max_rcs_fuel_mass);
+        assert_eq!(rocket.total_mass(), rocket.dry_mass + fuel + rocket.rcs_fuel_mass, "Burned RCS propellant leaves the craft");
+
+        // Empty jets go quiet but the main engine still fires
+        rocket.rcs_fuel_mass = 0.0;
+        assert_eq!(rocket.fire_rcs(1.0, 0.0, 1.0), Vec2::ZERO);
+        rocket.spool_time_constant = 0.0;
+        rocket.fire(0.0);
+        assert!(rocket.generate_thrust(0.1).length() > 0.0);
+
+        rocket.refuel();
+        assert_eq!(rocket.rcs_fuel_mass, rocket.max_rcs_fuel_mass);
+    }
+
+    #[test]
+    fn test_rotation_coasts_until_countered() {
+        const DT: f32 = 1.0 / 60.0;
+        let mut physics = Physics::new(23200.0);
+        let mut rotation = 0.0;
+
+        // Half a second of torque spins the lander up
+        physics.apply_torque(60000.0);
+        for _ in 0..30 {
+            physics.integrate_rotation(&mut rotation, DT);
+        }
+        let spin = physics.angular_velocity;
+        assert!((spin - 1.0f32.to_degrees() * 0.5).abs() < 0.1, "1 rad/s² for 0.5 s, got {}°/s", spin);
+        assert!(rotation > 0.0);
+
+        // Released, it keeps turning at the same rate
+        physics.apply_torque(0.0);
+        let before = rotation;
+        physics.integrate_rotation(&mut rotation, DT);
+        assert_eq!(physics.angular_velocity, spin);
+        assert!((rotation - before - spin * DT).abs() < 1e-3);
+
+        // An equal counter-torque for the same time stops it
+        physics.apply_torque(-60000.0);
+        for _ in 0..30 {
+            physics.integrate_rotation(&mut rotation, DT);
+        }
+        assert!(physics.angular_velocity.abs() < 1e-2);
+
+        // Clockwise spin wraps below zero into 0-360
+        let mut rotation = 1.0;
+        physics.angular_velocity = -120.0;
+        physics.apply_torque(0.0);
+        physics.integrate_rotation(&mut rotation, DT);
+        assert!((rotation - 359.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_inertia_drops_as_fuel_burns() {
+        let mut rocket = RocketEngine::new_apollo_lm();
+        let full = rocket.moment_of_inertia();
+        assert!((full - DEFAULT_MOMENT_OF_INERTIA).abs() / DEFAULT_MOMENT_OF_INERTIA < 0.05);
+
+        rocket.fire(0.0);
+        let mut previous = full;
+        while rocket.has_fuel() {
+            rocket.generate_thrust(0.5);
+            let inertia = rocket.moment_of_inertia();
+            assert!(inertia < previous, "Every burn lightens the craft");
+            previous = inertia;
+        }
+        let left = rocket.dry_mass + rocket.rcs_fuel_mass;
+        assert!((previous / full - left / 23200.0).abs() < 1e-9, "Main tanks dry, the structure and RCS propellant are left");
+        rocket.fire_rcs(1.0, 0.0, 1.0);
+        assert!(rocket.moment_of_inertia() < previous, "RCS firing lightens the craft too");
+    }
+
+    #[test]
+    fn test_gravity_at_altitude() {
+        assert_eq!(gravity_at_altitude(0.0, 1.625), 1.625, "Full gravity at the surface");
+        assert_eq!(gravity_at_altitude(-10.0, 1.625), 1.625, "Below the surface counts as the surface");
+
+        // One falloff radius up, gravity is a quarter
+        assert!((gravity_at_altitude(GRAVITY_FALLOFF_RADIUS, 1.625) - 1.625 / 4.0).abs() < 1e-6);
+
+        // Weaker with height, but barely over the playing field
+        let at_top = gravity_at_altitude(600.0, 1.625);
+        assert!(at_top < 1.625 && at_top > 1.625 * 0.75);
+    }
+
+    #[test]
+    fn test_drag_gives_terminal_velocity() {
+        const DT: f32 = 1.0 / 60.0;
+        // Terminal speed √(m·g / k) = √(1000 × 1.625 / 65) = 5 m/s
+        let fall = |drag_coefficient: f32, seconds: usize| {
+            let mut physics = Physics::new(1000.0).with_drag_coefficient(drag_coefficient);
+            let mut position = Vec2::new(0.0, 10_000.0);
+            let mut fastest: f32 = 0.0;
+            for _ in 0..seconds * 60 {
+                physics.reset_forces();
+                physics.add_force(Vec2::new(0.0, -1.625 * 1000.0));
+                physics.add_force(physics.drag_force());
+                physics.integrate(&mut position, DT);
+                fastest = fastest.max(physics.velocity.length());
+            }
+            (physics.velocity.length(), fastest)
+        };
+
+        let (after_30s, fastest) = fall(65.0, 30);
+        let (after_60s, _) = fall(65.0, 60);
+        assert!((after_30s - 5.0).abs() < 1e-2, "Settles at terminal speed, got {}", after_30s);
+        assert!((after_60s - after_30s).abs() < 1e-3, "Stops speeding up");
+        assert!(fastest <= 5.0 + 1e-3, "Never overshoots from rest");
+
+        // Vacuum: no drag, so free fall keeps accelerating
+        assert_eq!(Physics::new(1000.0).drag_force(), Vec2::ZERO);
+        let (vacuum, _) = fall(0.0, 30);
+        assert!((vacuum - 1.625 * 30.0).abs() < 1e-2);
+    }
+}
@@ -0,0 +1,198 @@
+//! Fuel canisters scattered over the terrain.
+//!
+//! With `--fuel-pickups` each attempt places a few canisters on the ground
+//! between the landing zones. Flying the lander into one tops up the descent
+//! tank (never past its capacity) and removes the canister for the rest of
+//! the attempt. Canisters are kept clear of every landing zone, including the
+//! whole track of a moving pad, so a pad is never blocked.
+
+use macroquad::math::{vec2, Rect, Vec2};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::surface::LandingZone;
+use crate::utils::terrain_x_to_pixel;
+
+/// Canisters placed per attempt
+pub const PICKUP_COUNT: usize = 3;
+/// Fuel in each canister (kg), about a sixth of the LM's tank
+pub const PICKUP_FUEL: f64 = 1500.0;
+/// Canister width and height in camera units
+pub const PICKUP_SIZE: Vec2 = Vec2::new(8.0, 12.0);
+/// Terrain points kept free between a canister and a landing zone or another canister
+const PICKUP_CLEARANCE: usize = 20;
+/// Random positions tried per canister before giving up on a crowded terrain
+const PLACEMENT_TRIES: usize = 50;
+
+/// A fuel canister resting on the terrain
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FuelPickup {
+    pub position: Vec2, // Bottom-center on the terrain surface, camera coordinates
+    pub amount: f64,    // Fuel it holds (kg)
+}
+
+impl FuelPickup {
+    /// Area the lander has to touch to collect the canister, in camera coordinates.
+    pub fn rect(&self) -> Rect {
+        Rect::new(
+            self.position.x - PICKUP_SIZE.x / 2.0,
+            self.position.y,
+            PICKUP_SIZE.x,
+            PICKUP_SIZE.y,
+        )
+    }
+}
+
+/// Places fuel canisters on the terrain, away from the landing zones.
+///
+/// # Arguments
+///
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `world_width` - Width in pixels the terrain spans
+/// * `zones` - Landing zones to keep clear
+/// * `count` - Number of canisters wanted
+/// * `amount` - Fuel per canister (kg)
+/// * `seed` - Layout seed; the same seed and terrain give the same canisters
+///
+/// # Returns
+///
+/// Up to `count` canisters, fewer if the terrain has no room left for them
+pub fn place_fuel_pickups(
+    terrain: &[f64],
+    world_width: f32,
+    zones: &[LandingZone],
+    count: usize,
+    amount: f64,
+    seed: u64,
+) -> Vec<FuelPickup> {
+    if terrain.len() <= 2 * PICKUP_CLEARANCE {
+        return Vec::new();
+    }
+
+    // Spans the canisters must stay out of; a moving pad blocks its whole track
+    let blocked: Vec<(usize, usize)> = zones
+        .iter()
+        .map(|zone| match &zone.motion {
+            Some(motion) => (motion.min_start, motion.max_start + (zone.end - zone.start)),
+            None => (zone.start, zone.end),
+        })
+        .collect();
+    let is_clear = |x: usize, taken: &[usize]| {
+        blocked
+            .iter()
+            .all(|&(start, end)| x + PICKUP_CLEARANCE < start || x > end + PICKUP_CLEARANCE)
+            && taken.iter().all(|&other| x.abs_diff(other) > PICKUP_CLEARANCE)
+    };
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut taken = Vec::with_capacity(count);
+    for _ in 0..count * PLACEMENT_TRIES {
+        if taken.len() == count {
+            break;
+        }
+        let x = rng.gen_range(PICKUP_CLEARANCE..terrain.len() - PICKUP_CLEARANCE);
+        if is_clear(x, &taken) {
+            taken.push(x);
+        }
+    }
+
+    taken
+        .into_iter()
+        .map(|x| FuelPickup {
+            position: vec2(terrain_x_to_pixel(x as f32, terrain.len(), world_width), terrain[x] as f32),
+            amount,
+        })
+        .collect()
+}
+
+/// Removes every canister the lander touches.
+///
+/// # Arguments
+///
+/// * `pickups` - Canisters still on the terrain
+/// * `lander` - Lander bounds in camera coordinates
+///
+/// # Returns
+///
+/// Total fuel in the collected canisters (kg), `0.0` if none were touched
+pub fn collect_fuel_pickups(pickups: &mut Vec<FuelPickup>, lander: Rect) -> f64 {
+    let mut collected = 0.0;
+    pickups.retain(|pickup| {
+        let touched = pickup.rect().overlaps(&lander);
+        if touched {
+            collected += pickup.amount;
+        }
+        !touched
+    });
+    collected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::surface::LandingZoneDifficulty;
+
+    #[test]
+    fn test_pickups_avoid_landing_zones() {
+        let terrain: Vec<f64> = (0..800).map(|i| 60.0 + (i % 40) as f64).collect();
+        let zones = vec![
+            LandingZone {
+                start: 100,
+                end: 150,
+                difficulty: LandingZoneDifficulty::Easy,
+                width_points: 50,
+                motion: None,
+            },
+            LandingZone {
+                start: 500,
+                end: 540,
+                difficulty: LandingZoneDifficulty::Medium,
+                width_points: 40,
+                motion: None,
+            },
+        ];
+
+        for seed in 0..20 {
+            let pickups = place_fuel_pickups(&terrain, 800.0, &zones, PICKUP_COUNT, PICKUP_FUEL, seed);
+            assert_eq!(pickups.len(), PICKUP_COUNT);
+            for pickup in &pickups {
+                let x = pickup.position.x as usize;
+                assert!(!(100 - PICKUP_CLEARANCE..=150 + PICKUP_CLEARANCE).contains(&x), "seed {} x {}", seed, x);
+                assert!(!(500 - PICKUP_CLEARANCE..=540 + PICKUP_CLEARANCE).contains(&x), "seed {} x {}", seed, x);
+                assert_eq!(pickup.position.y, terrain[x] as f32, "Canisters rest on the ground");
+            }
+        }
+        assert_eq!(
+            place_fuel_pickups(&terrain, 800.0, &zones, 3, 1.0, 7),
+            place_fuel_pickups(&terrain, 800.0, &zones, 3, 1.0, 7),
+            "Same seed, same canisters"
+        );
+
+        // No room at all leaves the terrain empty rather than crowding a zone
+        let narrow = vec![LandingZone {
+            start: 0,
+            end: 799,
+            difficulty: LandingZoneDifficulty::Easy,
+            width_points: 800,
+            motion: None,
+        }];
+        assert!(place_fuel_pickups(&terrain, 800.0, &narrow, 3, 1.0, 1).is_empty());
+    }
+
+    #[test]
+    fn test_collecting_removes_touched_canisters() {
+        let mut pickups = vec![
+            FuelPickup { position: vec2(100.0, 50.0), amount: 500.0 },
+            FuelPickup { position: vec2(300.0, 50.0), amount: 700.0 },
+        ];
+
+        // Hovering well above takes nothing
+        assert_eq!(collect_fuel_pickups(&mut pickups, Rect::new(84.0, 90.0, 32.0, 32.0)), 0.0);
+        assert_eq!(pickups.len(), 2);
+
+        // Skimming over the first canister collects it once
+        assert_eq!(collect_fuel_pickups(&mut pickups, Rect::new(84.0, 55.0, 32.0, 32.0)), 500.0);
+        assert_eq!(collect_fuel_pickups(&mut pickups, Rect::new(84.0, 55.0, 32.0, 32.0)), 0.0);
+        assert_eq!(pickups, vec![FuelPickup { position: vec2(300.0, 50.0), amount: 700.0 }]);
+    }
+}
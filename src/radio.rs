@@ -0,0 +1,129 @@
+//! Mission-control radio chatter shown in the HUD.
+//!
+//! Key moments of an attempt post a short scripted message to the `RadioLog`.
+//! Messages stay up for a few seconds, fade out, and stack as separate lines
+//! so they never overlap. Times are wall-clock seconds (`get_time()`), so
+//! messages keep fading while the lander is dead and the simulation is stopped.
+
+use std::collections::VecDeque;
+
+pub const GO_FOR_LANDING: &str = "Houston: You are go for landing";
+pub const FUEL_CRITICAL: &str = "Houston: Fuel critical";
+pub const EAGLE_HAS_LANDED: &str = "Tranquility Base here. The Eagle has landed";
+pub const LANDER_LOST: &str = "Houston: We've lost the lander";
+pub const SESSION_OVER: &str = "Houston: That's the last attempt. Press R for a new session";
+pub const CONFIRM_RESTART: &str = "Houston: Abort this descent? Press R again to restart";
+
+/// Seconds a message stays fully visible before fading
+const MESSAGE_HOLD: f64 = 3.0;
+/// Seconds a message takes to fade out
+const MESSAGE_FADE: f64 = 1.0;
+/// Most messages shown at once; older ones are dropped
+const MAX_MESSAGES: usize = 3;
+
+/// A message and the time it was posted
+#[derive(Debug, Clone, PartialEq)]
+struct RadioMessage {
+    text: &'static str,
+    posted_at: f64,
+}
+
+/// Timed radio messages for the HUD
+#[derive(Debug, Clone, Default)]
+pub struct RadioLog {
+    messages: VecDeque<RadioMessage>,
+    sent_once: Vec<&'static str>, // Messages already sent this attempt via `post_once`
+}
+
+impl RadioLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Posts a message, dropping expired ones and the oldest if the log is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Message text
+    /// * `now` - Current time in seconds
+    pub fn post(&mut self, text: &'static str, now: f64) {
+        self.messages.retain(|message| now - message.posted_at < MESSAGE_HOLD + MESSAGE_FADE);
+        if self.messages.len() == MAX_MESSAGES {
+            self.messages.pop_front();
+        }
+        self.messages.push_back(RadioMessage { text, posted_at: now });
+    }
+
+    /// Posts a message only the first time it is sent since the last `clear`.
+    ///
+    /// Used for conditions checked every frame, such as low fuel.
+    pub fn post_once(&mut self, text: &'static str, now: f64) {
+        if !self.sent_once.contains(&text) {
+            self.sent_once.push(text);
+            self.post(text, now);
+        }
+    }
+
+    /// Removes all messages and forgets which one-time messages were sent.
+    pub fn clear(&mut self) {
+        self.messages.clear();
+        self.sent_once.clear();
+    }
+
+    /// Messages still on screen, oldest first, with their opacity.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - Current time in seconds
+    ///
+    /// # Returns
+    ///
+    /// `(text, alpha)` pairs with alpha fading from 1.0 to 0.0 over the last second
+    pub fn visible(&self, now: f64) -> Vec<(&'static str, f32)> {
+        self.messages
+            .iter()
+            .filter_map(|message| {
+                let age = now - message.posted_at;
+                if age >= MESSAGE_HOLD + MESSAGE_FADE {
+                    return None;
+                }
+                let alpha = ((MESSAGE_HOLD + MESSAGE_FADE - age) / MESSAGE_FADE).min(1.0);
+                Some((message.text, alpha as f32))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_fade_and_expire() {
+        let mut radio = RadioLog::new();
+        radio.post(GO_FOR_LANDING, 10.0);
+
+        assert_eq!(radio.visible(11.0), vec![(GO_FOR_LANDING, 1.0)]);
+        assert_eq!(radio.visible(13.5), vec![(GO_FOR_LANDING, 0.5)]);
+        assert!(radio.visible(14.0).is_empty());
+    }
+
+    #[test]
+    fn test_log_is_bounded_and_post_once() {
+        let mut radio = RadioLog::new();
+        radio.post(GO_FOR_LANDING, 0.0);
+        radio.post_once(FUEL_CRITICAL, 0.1);
+        radio.post_once(FUEL_CRITICAL, 0.2);
+        assert_eq!(radio.visible(0.5).len(), 2, "One-time messages are not repeated");
+
+        radio.post(LANDER_LOST, 0.3);
+        radio.post(SESSION_OVER, 0.4);
+        let texts: Vec<_> = radio.visible(0.5).into_iter().map(|(text, _)| text).collect();
+        assert_eq!(texts, vec![FUEL_CRITICAL, LANDER_LOST, SESSION_OVER]);
+
+        radio.clear();
+        assert!(radio.visible(0.5).is_empty());
+        radio.post_once(FUEL_CRITICAL, 1.0);
+        assert_eq!(radio.visible(1.0).len(), 1, "Clearing re-arms one-time messages");
+    }
+}
@@ -10,10 +10,30 @@
 use macroquad::prelude::*;
 use macroquad_text::Fonts;
 
-use crate::entity::Entity;
-use crate::physics::Physics;
-use crate::session::{GameSession, AttemptResult};
-use crate::surface::LandingZoneDifficulty;
+use crate::collision::{local_slope_angle, wreckage_boxes};
+use crate::config::GameConfig;
+use crate::entity::{Entity, HudMode, Transform};
+use crate::guidance::{assess_landing, target_zone, ApproachCorridor};
+use crate::physics::{calculate_delta_v, gravity_vector, Physics};
+use crate::replay::{ghost_frame, ReplayFrame};
+use crate::session::{AttemptResult, GameSession, HighScores, SessionManager, SessionRecord};
+use crate::stats::LifetimeStats;
+use crate::surface::{terrain_height_at, terrain_slope_at, LandingZone, LandingZoneDifficulty, ZoneSymbol};
+use crate::theme::TerrainTheme;
+use crate::utils::{letterbox, pixel_to_terrain_x, terrain_x_to_pixel};
+
+/// Altitude at which the landing legs start to unfold
+const LEG_DEPLOY_START_ALTITUDE: f32 = 80.0;
+/// Altitude by which the landing legs are fully down
+const LEG_DEPLOY_FULL_ALTITUDE: f32 = 20.0;
+/// Seconds the camera shakes after a crash
+pub const SHAKE_DURATION: f64 = 0.5;
+/// Altitude below which the follow-cam starts zooming in
+const FOLLOW_ZOOM_START_ALTITUDE: f32 = 300.0;
+/// Follow-cam zoom on the ground
+const FOLLOW_MAX_ZOOM: f32 = 2.5;
+/// Tint of the best-landing ghost: pale blue, mostly transparent
+const GHOST_TINT: Color = Color::new(0.7, 0.85, 1.0, 0.35);
 
 /// Main rendering function that draws all game entities and UI elements.
 ///
@@ -24,31 +44,101 @@ use crate::surface::LandingZoneDifficulty;
 ///
 /// # Arguments
 ///
-/// * `entities` - Vector of all game entities to render
+/// * `entities` - Game entities to render (in hotseat, just the active player's lander)
 /// * `camera` - Camera configuration for coordinate transformations
-/// * `session` - Game session state for status display
-pub fn render(entities: &Vec<Entity>, camera: &Camera2D, session: &GameSession) {
+/// * `session_manager` - Session state, lifetime totals and high scores for status display
+/// * `config` - Runtime options (anti-aliasing, etc.)
+pub fn render(entities: &[Entity], camera: &Camera2D, session_manager: &SessionManager, config: &GameConfig) {
+    let session = &session_manager.session;
     for entity in entities {
         if let Some(phys) = &entity.physics {
+            // Stars first, so everything else draws over them
+            let transform = entity.displayed_transform(get_time());
+            entity.starfield.draw(transform.position + transform.size * 0.5, camera, &entity.terrain, entity.world_size.x);
+
             render_debug_info(entity, phys, camera);
 
-            render_lander(entity, camera);
+            render_attempt_sites(session, camera);
+
+            if config.solid_wreckage {
+                render_wreckage(entity, session, camera);
+            }
+
+            // During a replay the recorded flight stands in for the live lander
+            let replay_frame = entity.replay_frame(get_time());
+
+            // The session's best landing flies alongside live attempts over the same terrain
+            if let Some(ghost) = session_manager.ghost_for(entity.layout_seed).filter(|_| !entity.dead) {
+                render_ghost(entity, ghost, camera);
+            }
+
+            match &replay_frame {
+                Some(frame) => render_replay(entity, frame, camera, config.flame_tint),
+                None => render_lander(entity, camera, config.flame_tint),
+            }
+
+            if !entity.dead {
+                render_velocity_vector(entity, phys, camera);
+            }
+
+            render_terrain(entity, camera, config, session);
 
-            render_terrain(entity, camera);
+            if entity.show_approach_corridor && !entity.dead {
+                render_approach_corridor(entity, phys, camera);
+            }
 
             if entity.show_debug_info {
                 debug_render(entity);
             }
 
+            // Cover anything drawn past the world edges before the HUD goes on top
+            render_letterbox(entity.world_size);
+
+            // A finished session gets the full-screen summary instead of the alert box
+            let show_summary = entity.dead && replay_frame.is_none() && session_manager.is_complete() && !entity.practice;
+
             if entity.dead {
-                set_default_camera();
-                draw_alert_box(entity, session);
+                if replay_frame.is_none() && !show_summary {
+                    set_default_camera();
+                    draw_alert_box(entity, session_manager);
+                }
             } else {
-                draw_text(&entity);
+                draw_text(&entity, &config.theme);
+                if entity.hud_mode != HudMode::Off {
+                    render_fuel_gauge(entity);
+                }
             }
             
-            // Always render session status
-            render_session_status(entity, session);
+            if entity.hud_mode != HudMode::Off {
+                render_radio_log(entity);
+
+                // The whole surface at a glance when the camera shows only part of it
+                if config.minimap || config.follow_camera {
+                    render_minimap(entity, session, &config.theme);
+                }
+            }
+
+            // Session status bar is toggled independently of the HUD
+            if entity.show_session_status && !show_summary {
+                render_session_status(entity, session, session_manager.best_score(), session_manager.turn_label());
+            }
+
+            if show_summary {
+                render_session_summary(entity, session, &session_manager.high_scores, session_manager.daily.as_deref());
+                if session_manager.is_hotseat() {
+                    render_hotseat_standings(entity, session_manager);
+                }
+            }
+
+            if entity.show_lifetime_stats {
+                render_lifetime_stats(entity, &session_manager.lifetime);
+            }
+
+            if entity.show_leaderboard {
+                render_leaderboard(entity, &session_manager.high_scores.sessions);
+            } else if entity.paused {
+                render_paused(entity);
+            }
         }
     }
 }
@@ -70,6 +160,7 @@ pub fn render_debug_info(entity: &Entity, phys: &Physics, camera: &Camera2D) {
         debug!("position: {:?}", entity.transform.position);
         debug!("velocity: {:?}", phys.velocity);
         debug!("forces: {:?}", phys.forces);
+        debug!("local_slope: {:.1}°", local_slope_angle(&entity.terrain, &entity.transform, entity.world_size.x));
         if let Some(rocket) = &entity.rocket_physics {
             debug!("fuel_mass: {:.1} kg", rocket.fuel_mass);
             debug!("total_mass: {:.1} kg", rocket.total_mass());
@@ -81,32 +172,199 @@ pub fn render_debug_info(entity: &Entity, phys: &Physics, camera: &Camera2D) {
     }
 }
 
+/// Renders faint markers where earlier attempts in this session ended.
+///
+/// - Failures leave a wreckage cross
+/// - Successes leave a small landing flag
+///
+/// # Arguments
+///
+/// * `session` - Game session holding the recorded attempt sites
+/// * `camera` - Camera for coordinate transformations
+pub fn render_attempt_sites(session: &GameSession, camera: &Camera2D) {
+    set_camera(camera);
+
+    const MARKER_SIZE: f32 = 6.0;
+    const MARKER_ALPHA: f32 = 0.45;
+
+    for (site, result) in &session.attempt_sites {
+        match result {
+            AttemptResult::Failure => {
+                let color = Color::new(RED.r, RED.g, RED.b, MARKER_ALPHA);
+                draw_line(site.x - MARKER_SIZE, site.y, site.x + MARKER_SIZE, site.y + 2.0 * MARKER_SIZE, 2.0, color);
+                draw_line(site.x - MARKER_SIZE, site.y + 2.0 * MARKER_SIZE, site.x + MARKER_SIZE, site.y, 2.0, color);
+            }
+            AttemptResult::Success => {
+                let color = Color::new(GREEN.r, GREEN.g, GREEN.b, MARKER_ALPHA);
+                // Camera Y increases upward, so the pole rises from the touchdown point
+                draw_line(site.x, site.y, site.x, site.y + 3.0 * MARKER_SIZE, 1.5, color);
+                draw_triangle(
+                    vec2(site.x, site.y + 3.0 * MARKER_SIZE),
+                    vec2(site.x, site.y + 2.0 * MARKER_SIZE),
+                    vec2(site.x + MARKER_SIZE, site.y + 2.5 * MARKER_SIZE),
+                    color,
+                );
+            }
+            AttemptResult::InProgress => {}
+        }
+    }
+}
+
+/// Renders solid wreckage from failed attempts (ironman mode).
+///
+/// Wreckage is laid out on the current terrain with `wreckage_boxes`, the same
+/// boxes used for collision, and drawn as dark hulks with a red outline.
+///
+/// # Arguments
+///
+/// * `entity` - Lander entity holding the current terrain and size
+/// * `session` - Game session holding the recorded attempt sites
+/// * `camera` - Camera for coordinate transformations
+pub fn render_wreckage(entity: &Entity, session: &GameSession, camera: &Camera2D) {
+    set_camera(camera);
+
+    let wreckage = wreckage_boxes(
+        &session.attempt_sites,
+        &entity.terrain,
+        entity.world_size.x,
+        entity.transform.size,
+        entity.spawn_center_x(),
+    );
+    for debris in wreckage {
+        draw_rectangle(debris.x, debris.y, debris.w, debris.h, DARKGRAY);
+        draw_rectangle_lines(debris.x, debris.y, debris.w, debris.h, 2.0, RED);
+    }
+}
+
 /// Renders the lunar lander and thrust flames as separate components.
 ///
 /// The rendering consists of:
 /// - Lander texture: Always rendered at the entity position
-/// - Thrust texture: Rendered beneath lander when thrusting, scaled and rotated with lander
+/// - Thrust texture: Rendered beneath lander when thrusting, scaled by the engine's
+///   spool level and rotated with lander
+/// - Flame splash: When the flame reaches the terrain it is cut off at the
+///   surface and spreads along the local slope instead
 ///
 /// # Arguments
 ///
 /// * `entity` - The lander entity to render
 /// * `camera` - Camera for coordinate transformations
-pub fn render_lander(entity: &Entity, camera: &Camera2D) {
+/// * `tint_flame` - Color the flame by descent rate (see `flame_tint`) instead of white
+pub fn render_lander(entity: &Entity, camera: &Camera2D, tint_flame: bool) {
     set_camera(camera);
-    
+
+    // Drawn between the last two physics steps; collision keeps using entity.transform
+    let transform = entity.render_transform();
+
+    // Exhaust particles sit behind the lander and its flame
+    entity.exhaust.draw();
+
+    // Flame size follows the actual (lagged) thrust level rather than the key state
+    let flame_level = if let Some(rocket) = &entity.rocket_physics {
+        if rocket.has_fuel() { rocket.spool_level } else { 0.0 }
+    } else if let Some(phys) = &entity.physics {
+        // Fallback to force-based detection
+        if phys.forces.length() > 0.0 { 1.0 } else { 0.0 }
+    } else {
+        0.0
+    };
+    let venting = entity
+        .rocket_physics
+        .as_ref()
+        .is_some_and(|rocket| rocket.is_dumping && rocket.has_fuel() && !entity.dead);
+    let velocity_y = entity.physics.as_ref().map(|phys| phys.velocity.y);
+
+    draw_lander(entity, &transform, flame_level, venting, velocity_y, tint_flame);
+}
+
+/// Draws a recorded replay frame in place of the live lander, with a
+/// "REPLAY" banner and the replay clock at the top of the screen.
+///
+/// # Arguments
+///
+/// * `entity` - Lander entity providing textures, size and terrain
+/// * `frame` - Recorded frame to draw
+/// * `camera` - Camera for coordinate transformations
+/// * `tint_flame` - Color the flame by descent rate instead of white
+pub fn render_replay(entity: &Entity, frame: &ReplayFrame, camera: &Camera2D, tint_flame: bool) {
+    set_camera(camera);
+    let transform = Transform {
+        size: entity.transform.size,
+        position: frame.position,
+        rotation: frame.rotation,
+    };
+    draw_lander(entity, &transform, frame.thrust, false, Some(frame.velocity_y), tint_flame);
+
+    set_default_camera();
+    let banner = format!("REPLAY  T+{:.1}s  (W to stop)", frame.time);
+    let width = measure_text(&banner, None, 18, 1.0).width;
+    let alpha = if (get_time() * 2.0) as i64 % 2 == 0 { 1.0 } else { 0.6 };
+    entity.screen_fonts.draw_text(&banner, (screen_width() - width) / 2.0, 60.0, 18.0, Color::new(1.0, 0.3, 0.3, alpha));
+}
+
+/// Draws the translucent ghost of the session's best landing.
+///
+/// The ghost is placed by the live attempt's simulation time, so it shows
+/// where the best run was at the same point in its descent.
+///
+/// # Arguments
+///
+/// * `entity` - Live lander entity providing the texture, size and mission clock
+/// * `frames` - Recorded frames of the best landing
+/// * `camera` - Camera for coordinate transformations
+pub fn render_ghost(entity: &Entity, frames: &[ReplayFrame], camera: &Camera2D) {
+    let (Some(frame), Some(lander_renderer)) = (ghost_frame(frames, entity.time_elapsed), &entity.renderer_lander) else {
+        return;
+    };
+    set_camera(camera);
+    draw_texture_ex(
+        &lander_renderer.lander_texture,
+        frame.position.x,
+        frame.position.y,
+        GHOST_TINT,
+        DrawTextureParams {
+            dest_size: Some(entity.transform.size),
+            rotation: frame.rotation.to_radians(),
+            flip_x: false,
+            flip_y: true,
+            ..Default::default()
+        },
+    );
+}
+
+/// Draws the lander texture, landing legs, fuel vent and thrust flame.
+///
+/// Shared by the live lander and replays; expects the world camera to be set.
+///
+/// # Arguments
+///
+/// * `entity` - Lander entity providing textures, tuning and terrain
+/// * `transform` - Where to draw the lander
+/// * `flame_level` - Thrust level (0.0-1.0) scaling the flame; 0 for none
+/// * `venting` - Whether fuel is streaming out of the vents
+/// * `velocity_y` - Vertical velocity for the flame tint, if known
+/// * `tint_flame` - Color the flame by descent rate instead of white
+fn draw_lander(
+    entity: &Entity,
+    transform: &Transform,
+    flame_level: f32,
+    venting: bool,
+    velocity_y: Option<f32>,
+    tint_flame: bool,
+) {
     // Thrust flame scale factor relative to lander (easy to adjust)
     const THRUST_SCALE_FACTOR: f32 = 0.75;
-    
+
     // Always render the main lander texture
     if let Some(lander_renderer) = &entity.renderer_lander {
         draw_texture_ex(
             &lander_renderer.lander_texture,
-            entity.transform.position.x,
-            entity.transform.position.y,
+            transform.position.x,
+            transform.position.y,
             WHITE,
             DrawTextureParams {
-                dest_size: Some(entity.transform.size),
-                rotation: entity.transform.rotation.to_radians(),
+                dest_size: Some(transform.size),
+                rotation: transform.rotation.to_radians(),
                 flip_x: false,
                 flip_y: true,
                 ..Default::default()
@@ -114,46 +372,65 @@ pub fn render_lander(entity: &Entity, camera: &Camera2D) {
         );
     }
     
-    // Render thrust flames when thrusting
-    let should_render_thrust = if let Some(rocket) = &entity.rocket_physics {
-        rocket.is_thrusting && rocket.has_fuel()
-    } else if let Some(phys) = &entity.physics {
-        // Fallback to force-based detection
-        phys.forces.length() > 0.0
-    } else {
-        false
-    };
-    
-    if should_render_thrust {
+    // Landing legs unfold as the ground comes up
+    let altitude = transform.position.y - entity.ground_under(transform.position.x, transform.size.x);
+    draw_landing_legs(transform, leg_deployment(altitude));
+
+    // Vented fuel streams out of both sides while dumping
+    if venting {
+        draw_fuel_vent(transform);
+    }
+
+    // Render thrust flames while the engine is spooled up
+    if flame_level > 0.0 {
         if let Some(thrust_renderer) = &entity.renderer_thrust {
             // Calculate thrust flame size
-            let thrust_size = entity.transform.size * THRUST_SCALE_FACTOR;
+            let mut thrust_size = transform.size * THRUST_SCALE_FACTOR * flame_level;
             
             // Calculate position beneath lander (touching lander bottom)
-            let angle = entity.transform.rotation.to_radians();
-            let lander_center_x = entity.transform.position.x + entity.transform.size.x * 0.5;
-            let lander_center_y = entity.transform.position.y + entity.transform.size.y * 0.5;
+            let angle = transform.rotation.to_radians();
+            let lander_center_x = transform.position.x + transform.size.x * 0.5;
+            let lander_center_y = transform.position.y + transform.size.y * 0.5;
+
+            // Since 0° is "up", thrust points in the opposite direction (180° rotated)
+            let flame_direction = vec2(angle.sin(), -angle.cos());
+            let nozzle = vec2(lander_center_x, lander_center_y) + flame_direction * transform.size.y * 0.5;
+
+            // Near the ground the flame stops at the surface and splashes along it
+            let splash = flame_ground_contact(nozzle, flame_direction, thrust_size.y, &entity.terrain, entity.world_size.x);
+            if let Some(splash) = &splash {
+                thrust_size.y = splash.flame_length;
+            }
             
             // Offset distance: half lander height + half thrust height (so they touch)
-            let offset_distance = (entity.transform.size.y + thrust_size.y) * 0.5;
+            let offset_distance = (transform.size.y + thrust_size.y) * 0.5;
             
             // Calculate thrust position (beneath lander in the direction opposite to lander's "up")
-            // Since 0° is "up", thrust should be in the opposite direction (180° rotated)
             let thrust_center_x = lander_center_x + (angle.sin() * offset_distance);
             let thrust_center_y = lander_center_y - (angle.cos() * offset_distance);
             
             // Position thrust texture (top-left corner for rendering)
             let thrust_x = thrust_center_x - thrust_size.x * 0.5;
             let thrust_y = thrust_center_y - thrust_size.y * 0.5;
+
+            if let Some(splash) = &splash {
+                draw_flame_splash(splash, thrust_size.x * 0.5);
+            }
             
+            // Flame color warns of the descent rate, unless the classic look is selected
+            let flame_color = match (velocity_y, tint_flame) {
+                (Some(velocity_y), true) => flame_tint(velocity_y, entity.tuning.max_landing_velocity),
+                _ => WHITE,
+            };
+
             draw_texture_ex(
                 &thrust_renderer.lander_texture,
                 thrust_x,
                 thrust_y,
-                WHITE,
+                flame_color,
                 DrawTextureParams {
                     dest_size: Some(thrust_size),
-                    rotation: entity.transform.rotation.to_radians(),
+                    rotation: transform.rotation.to_radians(),
                     flip_x: false,
                     flip_y: true,
                     ..Default::default()
@@ -163,26 +440,334 @@ pub fn render_lander(entity: &Entity, camera: &Camera2D) {
     }
 }
 
+/// Draws an arrow from the lander center along its velocity.
+///
+/// The arrow grows with speed (capped so it stays near the lander) and is
+/// green while the speed is within the safe landing limit, red above it.
+///
+/// # Arguments
+///
+/// * `entity` - Lander entity with transform and landing limits
+/// * `phys` - Physics component providing the current velocity
+/// * `camera` - Camera used for world coordinates (same as `render_lander`)
+pub fn render_velocity_vector(entity: &Entity, phys: &Physics, camera: &Camera2D) {
+    const HEAD_SIZE: f32 = 8.0;
+
+    let transform = entity.render_transform();
+    let center = transform.position + transform.size / 2.0;
+    let Some((tip, color)) = velocity_arrow(center, phys.velocity, entity.tuning.max_landing_velocity) else {
+        return;
+    };
+
+    set_camera(camera);
+    let direction = (tip - center).normalize();
+    let side = vec2(-direction.y, direction.x);
+    draw_line(center.x, center.y, tip.x, tip.y, 2.0, color);
+    draw_triangle(
+        tip + direction * HEAD_SIZE / 2.0,
+        tip - direction * HEAD_SIZE / 2.0 + side * HEAD_SIZE / 2.0,
+        tip - direction * HEAD_SIZE / 2.0 - side * HEAD_SIZE / 2.0,
+        color,
+    );
+}
+
+/// Tip and color of the velocity arrow.
+///
+/// # Arguments
+///
+/// * `center` - Lander center in camera coordinates
+/// * `velocity` - Lander velocity in m/s
+/// * `max_landing_velocity` - Safe landing speed limit
+///
+/// # Returns
+///
+/// `(tip, color)`, or `None` when the lander is practically at rest
+pub fn velocity_arrow(center: Vec2, velocity: Vec2, max_landing_velocity: f32) -> Option<(Vec2, Color)> {
+    const PIXELS_PER_MPS: f32 = 3.0;
+    const MAX_LENGTH: f32 = 120.0;
+    const MIN_SPEED: f32 = 0.1;
+
+    let speed = velocity.length();
+    if !(speed >= MIN_SPEED && speed.is_finite()) {
+        return None;
+    }
+    let length = (speed * PIXELS_PER_MPS).min(MAX_LENGTH);
+    let color = if speed <= max_landing_velocity { GREEN } else { RED };
+    Some((center + velocity / speed * length, color))
+}
+
+/// Thrust flame tint for the current descent rate.
+///
+/// Climbing or sinking slowly gives a cool blue flame, half the safe landing
+/// speed a plain white one, and reaching the limit a hot red one.
+///
+/// # Arguments
+///
+/// * `velocity_y` - Vertical velocity (negative when descending)
+/// * `max_landing_velocity` - Safe landing speed limit
+pub fn flame_tint(velocity_y: f32, max_landing_velocity: f32) -> Color {
+    const COOL: Color = Color::new(0.55, 0.75, 1.0, 1.0);
+    const HOT: Color = Color::new(1.0, 0.35, 0.25, 1.0);
+
+    let danger = ((-velocity_y).max(0.0) / max_landing_velocity).clamp(0.0, 1.0);
+    let mix = |from: Color, to: Color, t: f32| {
+        Color::new(
+            from.r + (to.r - from.r) * t,
+            from.g + (to.g - from.g) * t,
+            from.b + (to.b - from.b) * t,
+            1.0,
+        )
+    };
+    if danger < 0.5 {
+        mix(COOL, WHITE, danger * 2.0)
+    } else {
+        mix(WHITE, HOT, (danger - 0.5) * 2.0)
+    }
+}
+
+/// How far the landing legs are unfolded at a given altitude.
+///
+/// Legs stay stowed above `LEG_DEPLOY_START_ALTITUDE` and are fully down by
+/// `LEG_DEPLOY_FULL_ALTITUDE`, easing in and out between the two.
+///
+/// # Arguments
+///
+/// * `altitude` - Height of the lander bottom above the terrain beneath it
+///
+/// # Returns
+///
+/// Deployment from `0.0` (stowed) to `1.0` (down and locked)
+pub fn leg_deployment(altitude: f32) -> f32 {
+    let t = ((LEG_DEPLOY_START_ALTITUDE - altitude) / (LEG_DEPLOY_START_ALTITUDE - LEG_DEPLOY_FULL_ALTITUDE))
+        .clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Draws the landing legs as struts with foot pads.
+///
+/// Stowed legs are folded up against the descent stage; deployed legs reach
+/// the outer bottom corners of the sprite, where collision puts the feet.
+///
+/// # Arguments
+///
+/// * `transform` - Lander transform (camera coordinates)
+/// * `deployment` - Leg deployment from `leg_deployment`
+fn draw_landing_legs(transform: &Transform, deployment: f32) {
+    const LEG_COLOR: Color = Color::new(0.8, 0.8, 0.75, 1.0);
+    const HIP_HEIGHT: f32 = 0.3; // Strut attachment height, fraction of the lander height
+    const HIP_INSET: f32 = 0.3;  // Strut attachment inset from the side, fraction of the width
+    const PAD_WIDTH: f32 = 0.12; // Foot pad width, fraction of the lander width
+
+    let size = transform.size;
+    let center = transform.position + size * 0.5;
+    let angle = transform.rotation.to_radians();
+    let (sin, cos) = angle.sin_cos();
+    // Lander-local offset from the center to camera coordinates
+    let to_world = |local: Vec2| center + vec2(local.x * cos - local.y * sin, local.x * sin + local.y * cos);
+
+    for side in [-1.0, 1.0] {
+        let hip = vec2(side * size.x * (0.5 - HIP_INSET), size.y * (HIP_HEIGHT - 0.5));
+        let stowed = vec2(side * size.x * 0.35, size.y * (HIP_HEIGHT - 0.5) - size.y * 0.05);
+        let deployed = vec2(side * size.x * 0.5, -size.y * 0.5);
+        let foot = stowed.lerp(deployed, deployment);
+
+        let (hip, foot) = (to_world(hip), to_world(foot));
+        draw_line(hip.x, hip.y, foot.x, foot.y, 1.5, LEG_COLOR);
+
+        let pad = vec2(cos, sin) * size.x * PAD_WIDTH * 0.5 * deployment;
+        draw_line(foot.x - pad.x, foot.y - pad.y, foot.x + pad.x, foot.y + pad.y, 2.0, LEG_COLOR);
+    }
+}
+
+/// Draws flickering vapor plumes from both sides of the lander while fuel is dumped.
+///
+/// # Arguments
+///
+/// * `transform` - Lander transform (camera coordinates)
+fn draw_fuel_vent(transform: &Transform) {
+    const PLUME_COUNT: usize = 4;
+
+    let center = transform.position + transform.size * 0.5;
+    let angle = transform.rotation.to_radians();
+    let side = vec2(angle.cos(), angle.sin()); // Lander's local "right"
+    let flicker = (get_time() * 20.0).sin() as f32 * 0.5 + 0.5;
+
+    for i in 0..PLUME_COUNT {
+        let spread = i as f32 / PLUME_COUNT as f32;
+        let length = transform.size.x * (0.4 + 0.3 * spread + 0.2 * flicker);
+        let color = Color::new(0.85, 0.9, 1.0, 0.6 - 0.12 * i as f32);
+        for direction in [side, -side] {
+            let start = center + direction * transform.size.x * 0.5;
+            let drift = vec2(0.0, -spread * transform.size.y * 0.3);
+            let end = start + direction * length + drift;
+            draw_line(start.x, start.y, end.x, end.y, 1.5, color);
+        }
+    }
+}
+
+/// Where a thrust flame meets the terrain and how far it spreads along it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlameSplash {
+    pub contact: Vec2,     // Point where the flame axis reaches the surface
+    pub flame_length: f32, // Flame length clipped at the surface
+    pub spread: f32,       // Length of flame pushed out along the surface
+    pub tangent: Vec2,     // Unit surface direction at the contact point
+    pub normal: Vec2,      // Unit surface normal (pointing up, out of the ground)
+}
+
+/// Finds where a downward flame meets the terrain.
+///
+/// The flame runs from `nozzle` along `direction`. The distance to the surface
+/// is estimated from the height above the terrain directly under the nozzle,
+/// which is cheap and accurate enough for a short flame.
+///
+/// # Arguments
+///
+/// * `nozzle` - Flame origin in camera coordinates
+/// * `direction` - Unit direction of the flame
+/// * `flame_length` - Unclipped flame length
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `world_width` - Width in pixels the terrain spans
+///
+/// # Returns
+///
+/// The splash geometry, or `None` if the flame does not reach the ground
+pub fn flame_ground_contact(
+    nozzle: Vec2,
+    direction: Vec2,
+    flame_length: f32,
+    terrain: &[f64],
+    world_width: f32,
+) -> Option<FlameSplash> {
+    // Flames pointing sideways or up never reach the ground below
+    if direction.y >= -0.1 || flame_length <= 0.0 {
+        return None;
+    }
+
+    let to_terrain = |x: f32| pixel_to_terrain_x(x, terrain.len(), world_width);
+    let ground = terrain_height_at(terrain, to_terrain(nozzle.x))?;
+    let distance = ((nozzle.y - ground) / -direction.y).max(0.0);
+    if distance >= flame_length {
+        return None;
+    }
+
+    let contact = nozzle + direction * distance;
+    // Rise per terrain point to rise per pixel
+    let slope = terrain_slope_at(terrain, to_terrain(contact.x)) * to_terrain(1.0);
+    let tangent = vec2(1.0, slope).normalize();
+    let normal = vec2(-slope, 1.0).normalize();
+
+    Some(FlameSplash {
+        contact,
+        flame_length: distance,
+        spread: flame_length - distance,
+        tangent,
+        normal,
+    })
+}
+
+/// Draws a flame splashing out both ways along the surface.
+///
+/// # Arguments
+///
+/// * `splash` - Splash geometry from `flame_ground_contact`
+/// * `half_width` - Half the flame width at the nozzle
+fn draw_flame_splash(splash: &FlameSplash, half_width: f32) {
+    const SPLASH_HEIGHT_RATIO: f32 = 0.35; // Splash thickness relative to its reach
+    const SPLASH_COLOR: Color = Color::new(1.0, 0.6, 0.1, 0.7);
+
+    let reach = half_width + splash.spread;
+    let lift = splash.normal * reach * SPLASH_HEIGHT_RATIO;
+
+    for side in [-1.0, 1.0] {
+        let tip = splash.contact + splash.tangent * reach * side;
+        draw_triangle(splash.contact, splash.contact + lift, tip, SPLASH_COLOR);
+    }
+}
+
+/// Renders the assisted-mode approach corridor above the nearest landing zone.
+///
+/// The funnel is tinted green while the lander is inside it at an acceptable
+/// speed for its height, and red otherwise.
+///
+/// # Arguments
+///
+/// * `entity` - Lander entity with terrain and landing zones
+/// * `phys` - Physics component providing the lander velocity
+/// * `camera` - Camera for coordinate transformations
+pub fn render_approach_corridor(entity: &Entity, phys: &Physics, camera: &Camera2D) {
+    const CORRIDOR_ALPHA: f32 = 0.15;
+    const EDGE_ALPHA: f32 = 0.6;
+
+    let lander_center = vec2(
+        entity.transform.position.x + entity.transform.size.x / 2.0,
+        entity.transform.position.y,
+    );
+    let center_point = pixel_to_terrain_x(lander_center.x, entity.terrain.len(), entity.world_size.x);
+    let zone = match target_zone(&entity.landing_zones, center_point) {
+        Some(zone) => zone,
+        None => return,
+    };
+
+    set_camera(camera);
+
+    let corridor = ApproachCorridor::for_zone(zone, &entity.terrain, entity.world_size.x, &entity.tuning, entity.body.gravity());
+    let status = corridor.assess(lander_center, phys.velocity);
+    let tint = if status.on_track() { GREEN } else { RED };
+
+    // Funnel from the zone edges up to the top of the world
+    let top_y = entity.world_size.y;
+    let bottom_half = corridor.half_width_at(corridor.ground_y);
+    let top_half = corridor.half_width_at(top_y);
+    let bottom_left = vec2(corridor.center_x - bottom_half, corridor.ground_y);
+    let bottom_right = vec2(corridor.center_x + bottom_half, corridor.ground_y);
+    let top_left = vec2(corridor.center_x - top_half, top_y);
+    let top_right = vec2(corridor.center_x + top_half, top_y);
+
+    let fill = Color::new(tint.r, tint.g, tint.b, CORRIDOR_ALPHA);
+    draw_triangle(bottom_left, bottom_right, top_right, fill);
+    draw_triangle(bottom_left, top_right, top_left, fill);
+
+    let edge = Color::new(tint.r, tint.g, tint.b, EDGE_ALPHA);
+    draw_line(bottom_left.x, bottom_left.y, top_left.x, top_left.y, 1.5, edge);
+    draw_line(bottom_right.x, bottom_right.y, top_right.x, top_right.y, 1.5, edge);
+}
+
 /// Renders the procedurally generated terrain with color-coded landing zones and scores.
 ///
-/// Terrain is rendered as connected line segments with different colors:
+/// Terrain is rendered as connected line segments colored by the configured
+/// `TerrainTheme` (classic theme colors shown):
 /// - Green: Normal rough terrain
 /// - Red: Hard landing zones (1.0x lander width) - Score: 2.0
 /// - Orange: Medium landing zones (1.25x lander width) - Score: 1.6
 /// - Yellow: Easy landing zones (1.5x lander width) - Score: 1.3
+/// - Gray: Zones already consumed this session (single-use zone mode)
 ///
 /// Scores are displayed above each landing zone.
 ///
+/// When anti-aliasing is enabled, each segment is drawn over a wider,
+/// translucent copy of itself to feather the jagged edges of thin lines.
+///
 /// # Arguments
 ///
 /// * `entity` - Entity containing terrain data and landing zones
 /// * `_camera` - Camera (unused, terrain uses screen coordinates)
-pub fn render_terrain(entity: &Entity, _camera: &Camera2D) {
-    // Draw terrain with 1:1 pixel correspondence - much simpler coordinate system
-    for i in 0..entity.terrain.len() - 1 {
-        let start_x = i as f32;
+/// * `config` - Runtime options controlling line feathering and the color theme
+/// * `session` - Game session; zones already consumed this session are drawn gray
+pub fn render_terrain(entity: &Entity, camera: &Camera2D, config: &GameConfig, session: &GameSession) {
+    // Feather width added around each line and its opacity
+    const FEATHER_WIDTH: f32 = 1.5;
+    const FEATHER_ALPHA: f32 = 0.35;
+
+    // Terrain is in world coordinates, so it lines up with the lander under a follow-cam
+    set_camera(camera);
+
+    // Terrain points are spread evenly over the world width
+    let points = entity.terrain.len();
+    for i in 0..points - 1 {
+        let start_x = terrain_x_to_pixel(i as f32, points, entity.world_size.x);
         let start_y = entity.terrain[i] as f32;
-        let end_x = (i + 1) as f32;
+        let end_x = terrain_x_to_pixel((i + 1) as f32, points, entity.world_size.x);
         let end_y = entity.terrain[i + 1] as f32;
 
         // Check if BOTH endpoints of this segment are within any landing zone
@@ -196,79 +781,212 @@ pub fn render_terrain(entity: &Entity, _camera: &Camera2D) {
 
         // Determine color and width based on landing zone difficulty
         let (line_color, line_width) = if let Some(difficulty) = in_landing_zone {
-            let color = match difficulty {
-                LandingZoneDifficulty::Hard => RED,    // Hardest zones are red (1.0x width)
-                LandingZoneDifficulty::Medium => ORANGE, // Medium zones are orange (1.25x width)
-                LandingZoneDifficulty::Easy => YELLOW,   // Easiest zones are yellow (1.5x width)
-            };
-            (color, 4.0) // Thicker lines for all landing zones
+            // Thicker lines for all landing zones, consumed zones use the theme's muted color
+            (config.theme.zone_color(difficulty, session.is_zone_consumed(difficulty)), 4.0)
         } else {
-            (GREEN, 2.0) // Regular terrain in green with normal width
+            (config.theme.terrain, 2.0) // Regular terrain in the theme color with normal width
         };
 
+        if config.antialiasing {
+            let feather_color = Color::new(line_color.r, line_color.g, line_color.b, FEATHER_ALPHA);
+            draw_line(start_x, start_y, end_x, end_y, line_width + FEATHER_WIDTH, feather_color);
+        }
         draw_line(start_x, start_y, end_x, end_y, line_width, line_color);
     }
 
-    // Render scores above landing zones using the same coordinate system as terrain
-    // (Don't change camera - keep using the same coordinates as terrain rendering)
+    render_fuel_pickups(entity);
+    render_zone_labels(entity, camera, session, &config.theme);
+}
 
-    for zone in &entity.landing_zones {
-        // Calculate the center position of the zone
-        let zone_center_x = (zone.start + zone.end) as f32 / 2.0;
+/// Draws the uncollected fuel canisters as small tanks standing on the terrain.
+///
+/// Expects the terrain camera to be set.
+fn render_fuel_pickups(entity: &Entity) {
+    const CANISTER_COLOR: Color = Color::new(1.0, 0.85, 0.2, 1.0);
+
+    for pickup in &entity.fuel_pickups {
+        let body = pickup.rect();
+        draw_rectangle_lines(body.x, body.y, body.w, body.h * 0.8, 1.5, CANISTER_COLOR);
+        // Filler cap on top and a band around the middle
+        draw_rectangle(body.x + body.w * 0.3, body.y + body.h * 0.8, body.w * 0.4, body.h * 0.2, CANISTER_COLOR);
+        draw_line(body.x, body.y + body.h * 0.4, body.x + body.w, body.y + body.h * 0.4, 1.0, CANISTER_COLOR);
+    }
+}
+
+/// Computes the screen-space anchor for a landing zone's score label.
+///
+/// Terrain heights are camera coordinates (Y increases upward), so the label
+/// sits `offset` units above the terrain at the zone center and is then
+/// flipped into screen coordinates (Y increases downward).
+///
+/// # Arguments
+///
+/// * `zone` - Landing zone to label
+/// * `terrain` - Terrain heights in camera coordinates, one per terrain point
+/// * `world_width` - Width in pixels the terrain spans
+/// * `world_height` - Height of the world in pixels
+/// * `offset` - Distance above the terrain in pixels
+///
+/// # Returns
+///
+/// Screen position of the label's bottom-center point
+pub fn zone_label_anchor(zone: &LandingZone, terrain: &[f64], world_width: f32, world_height: f32, offset: f32) -> Vec2 {
+    let zone_center_x = (zone.start + zone.end) as f32 / 2.0;
+    let center_index = (zone_center_x as usize).min(terrain.len().saturating_sub(1));
+    let terrain_height = terrain.get(center_index).copied().unwrap_or(0.0) as f32;
+
+    vec2(
+        terrain_x_to_pixel(zone_center_x, terrain.len(), world_width),
+        world_height - (terrain_height + offset),
+    )
+}
+
+/// Renders score labels above every landing zone in a single screen-space pass.
+///
+/// Each label is sized by zone difficulty (harder zones get larger text), drawn
+/// in the zone color and backed by a dark rectangle for legibility against the
+/// terrain.
+///
+/// # Arguments
+///
+/// * `entity` - Entity containing terrain data and landing zones
+/// * `camera` - Camera the terrain is drawn with, for placing the labels
+/// * `session` - Game session; consumed zones are labeled in the muted color
+/// * `theme` - Terrain theme supplying the zone colors
+pub fn render_zone_labels(entity: &Entity, camera: &Camera2D, session: &GameSession, theme: &TerrainTheme) {
+    const LABEL_OFFSET: f32 = 25.0; // Pixels above terrain
+    const LABEL_BASE_SIZE: f32 = 14.0;
+    const LABEL_SIZE_PER_POINT: f32 = 2.0; // Extra text size per point of zone score
+    const LABEL_PADDING: f32 = 3.0;
+    const LABEL_BACKGROUND: Color = Color::new(0.0, 0.0, 0.0, 0.6);
+    const LABEL_SYMBOL_GAP: f32 = 2.0; // Space between the symbol box and the score box
 
-        // Find the terrain height at the center of the zone
-        let center_index = (zone_center_x as usize).min(entity.terrain.len() - 1);
-        let terrain_height = entity.terrain[center_index] as f32;
+    if entity.terrain.is_empty() {
+        return;
+    }
 
-        // Position score text above the zone (offset upward from terrain)
-        let score_y = terrain_height - 25.0; // 25 pixels above terrain
+    // Labels are laid out in screen coordinates, so switch camera once up front
+    set_default_camera();
+    let world_height = entity.world_size.y;
+
+    for zone in &entity.landing_zones {
+        // The anchor is laid out for the whole-world camera; map it through the
+        // actual one so labels stay on their zones under the follow-cam and letterboxing
+        let anchor = zone_label_anchor(zone, &entity.terrain, entity.world_size.x, world_height, LABEL_OFFSET);
+        let anchor = camera.world_to_screen(vec2(anchor.x, world_height - anchor.y));
 
-        // Calculate and format the score
         let score = zone.difficulty.score();
         let score_text = format!("{:.1}", score);
+        let text_size = LABEL_BASE_SIZE + score * LABEL_SIZE_PER_POINT;
 
         // Choose color to match zone difficulty
-        let text_color = match zone.difficulty {
-            LandingZoneDifficulty::Hard => RED,
-            LandingZoneDifficulty::Medium => ORANGE,
-            LandingZoneDifficulty::Easy => YELLOW,
-        };
+        let text_color = theme.zone_color(zone.difficulty, session.is_zone_consumed(zone.difficulty));
 
-        // Draw score text centered above the zone
-        let text_size = 18.0;
-        let text_width = measure_text(&score_text, None, text_size as u16, 1.0).width;
-        let text_x = zone_center_x - text_width / 2.0;
+        // Center the text horizontally on the zone, baseline at the anchor
+        let dimensions = measure_text(&score_text, None, text_size as u16, 1.0);
+        let text_x = anchor.x - dimensions.width / 2.0;
+        let text_top = anchor.y - dimensions.offset_y;
 
-        // Convert to screen coordinates for proper text rendering
-        // Score_y is in terrain coordinates (Y increases downward)
-        // Convert to screen coordinates where Y=0 is at the top
-        let screen_y = screen_height() - score_y;
+        draw_rectangle(
+            text_x - LABEL_PADDING,
+            text_top - LABEL_PADDING,
+            dimensions.width + 2.0 * LABEL_PADDING,
+            dimensions.height + 2.0 * LABEL_PADDING,
+            LABEL_BACKGROUND,
+        );
+        macroquad::text::draw_text(&score_text, text_x, anchor.y, text_size, text_color);
 
-        // Use default camera for text rendering to avoid coordinate system issues
-        set_default_camera();
-        macroquad::text::draw_text(&score_text, text_x, screen_y, text_size, text_color);
+        // Colorblind mode: a difficulty shape in its own box left of the score
+        if entity.colorblind {
+            let size = dimensions.height;
+            let box_right = text_x - LABEL_PADDING - LABEL_SYMBOL_GAP;
+            draw_rectangle(
+                box_right - size - 2.0 * LABEL_PADDING,
+                text_top - LABEL_PADDING,
+                size + 2.0 * LABEL_PADDING,
+                size + 2.0 * LABEL_PADDING,
+                LABEL_BACKGROUND,
+            );
+            let center = vec2(box_right - LABEL_PADDING - size / 2.0, text_top + size / 2.0);
+            draw_zone_symbol(zone.difficulty.symbol(), center, size / 2.0, text_color);
+        }
+    }
+}
+
+/// Draws a zone difficulty marker in screen coordinates.
+///
+/// # Arguments
+///
+/// * `symbol` - Shape to draw
+/// * `center` - Shape center in screen pixels
+/// * `radius` - Half the shape's width
+/// * `color` - Fill color
+pub fn draw_zone_symbol(symbol: ZoneSymbol, center: Vec2, radius: f32, color: Color) {
+    match symbol {
+        // Screen Y points down, so the apex is at the smaller Y
+        ZoneSymbol::Triangle => draw_triangle(
+            vec2(center.x, center.y - radius),
+            vec2(center.x - radius, center.y + radius),
+            vec2(center.x + radius, center.y + radius),
+            color,
+        ),
+        ZoneSymbol::Diamond => draw_poly(center.x, center.y, 4, radius, 0.0, color),
+        ZoneSymbol::Circle => draw_circle(center.x, center.y, radius, color),
     }
 }
 
 /// Draws the main game UI including mission status, fuel, velocity, and landing zone info.
 ///
 /// The UI displays:
-/// - Mission timer and status
+/// - Mission timer (starts on first thrust input) and status
 /// - Fuel percentage and spacecraft mass
 /// - Velocity components and total speed
+/// - Inertial navigation (horizontal distance traveled, max altitude)
+/// - Remaining delta-V and, for two-stage craft, the current stage
+/// - Three-light landing indicator (zone, speed, angle)
+/// - Gravity direction arrow when gravity is tilted
+/// - Flashing "THRUST BLOCKED" warning while thrust is wasted against a surface
 /// - Landing zone count and difficulty breakdown
 /// - Thrust status indicator
 ///
+/// The amount shown follows `entity.hud_mode`: everything in `Full`, only
+/// fuel and speed in `Minimal`, and nothing in `Off`.
+///
 /// # Arguments
 ///
 /// * `entity` - Entity containing all game state and UI data
-pub fn draw_text(entity: &Entity) {
+/// * `theme` - Terrain theme, so the zone breakdown matches the terrain colors
+pub fn draw_text(entity: &Entity, theme: &TerrainTheme) {
+    // Seconds thrust must stay blocked before the warning shows
+    const THRUST_BLOCKED_DELAY: f32 = 0.3;
+
     set_default_camera();
     let fonts = &entity.screen_fonts;
     let phys = entity.physics.as_ref().unwrap();
 
-    let time_elapsed_text = format!("TIME {:.1}", entity.time_elapsed);
-    fonts.draw_text("MISSION", 20.0, 0.0, 15.0, Color::from([1.0; 4]));
+    match entity.hud_mode {
+        HudMode::Off => return,
+        HudMode::Minimal => {
+            let fuel_text = if let Some(rocket) = &entity.rocket_physics {
+                format!("FUEL: {:.1}%", rocket.fuel_percentage())
+            } else {
+                "FUEL: N/A".to_string()
+            };
+            let speed_text = format!("SPEED: {}", entity.units.format_speed(phys.velocity.length(), 1));
+            fonts.draw_text(&fuel_text, 20.0, 0.0, 15.0, Color::from([1.0; 4]));
+            fonts.draw_text(&speed_text, 20.0, 20.0, 15.0, Color::from([1.0; 4]));
+            return;
+        }
+        HudMode::Full => {}
+    }
+
+    let time_elapsed_text = format!("TIME {:.1}", entity.mission_time());
+    let mission_text = if entity.daily_challenge {
+        format!("MISSION: {} - DAILY CHALLENGE", entity.body.name().to_uppercase())
+    } else {
+        format!("MISSION: {}", entity.body.name().to_uppercase())
+    };
+    fonts.draw_text(&mission_text, 20.0, 0.0, 15.0, Color::from([1.0; 4]));
     fonts.draw_text(&time_elapsed_text, 20.0, 20.0, 15.0, Color::from([1.0; 4]));
 
     // Display fuel information from rocket physics if available
@@ -278,11 +996,19 @@ pub fn draw_text(entity: &Entity) {
         "FUEL: N/A".to_string()
     };
     fonts.draw_text(&fuel_text, 20.0, 40.0, 15.0, Color::from([1.0; 4]));
+    if let Some(rocket) = &entity.rocket_physics {
+        let rcs_text = format!("RCS: {:.0}%", rocket.rcs_fuel_percentage());
+        let rcs_color = if rocket.has_rcs_fuel() { SKYBLUE } else { GRAY };
+        fonts.draw_text(&rcs_text, 150.0, 40.0, 15.0, rcs_color);
+    }
 
     // Add mass information for realism
     if let Some(rocket) = &entity.rocket_physics {
-        let mass_text = format!("MASS: {:.0}kg", rocket.total_mass());
+        let mass_text = format!("MASS: {}", entity.units.format_mass(rocket.total_mass()));
         fonts.draw_text(&mass_text, 20.0, 60.0, 15.0, Color::from([1.0; 4]));
+        if rocket.is_dumping {
+            fonts.draw_text("VENTING", 150.0, 60.0, 15.0, SKYBLUE);
+        }
     }
     
     // Display landing zones information
@@ -304,26 +1030,26 @@ pub fn draw_text(entity: &Entity) {
         
         let mut y_offset = 100.0;
         if hard_count > 0 {
-            let hard_text = format!("RED: {} hard", hard_count);
-            fonts.draw_text(&hard_text, 20.0, y_offset, 12.0, RED);
+            let hard_text = format!("HARD: {}", hard_count);
+            fonts.draw_text(&hard_text, 20.0, y_offset, 12.0, theme.hard);
             y_offset += 15.0;
         }
         if medium_count > 0 {
-            let medium_text = format!("ORANGE: {} med", medium_count);
-            fonts.draw_text(&medium_text, 20.0, y_offset, 12.0, ORANGE);
+            let medium_text = format!("MEDIUM: {}", medium_count);
+            fonts.draw_text(&medium_text, 20.0, y_offset, 12.0, theme.medium);
             y_offset += 15.0;
         }
         if easy_count > 0 {
-            let easy_text = format!("YELLOW: {} easy", easy_count);
-            fonts.draw_text(&easy_text, 20.0, y_offset, 12.0, YELLOW);
+            let easy_text = format!("EASY: {}", easy_count);
+            fonts.draw_text(&easy_text, 20.0, y_offset, 12.0, theme.easy);
         }
     }
 
     let w = macroquad::window::screen_width();
     let right_text_start = w - 195.0;
-    let altitude_text = format!("ALTITUDE: {:.1}", entity.transform.position.y);
-    let horizontal_speed_text = format!("H-SPEED: {:.1} m/s", phys.velocity.x);
-    let vertical_speed_text = format!("V-SPEED: {:.1} m/s", phys.velocity.y);
+    let altitude_text = format!("ALTITUDE: {}", entity.units.format_distance(entity.altitude(), 1));
+    let horizontal_speed_text = format!("H-SPEED: {}", entity.units.format_speed(phys.velocity.x, 1));
+    let vertical_speed_text = format!("V-SPEED: {}", entity.units.format_speed(phys.velocity.y, 1));
     fonts.draw_text(
         &altitude_text,
         right_text_start,
@@ -346,55 +1072,240 @@ pub fn draw_text(entity: &Entity) {
         Color::from([1.0; 4]),
     );
 
-    // Add delta-V and thrust information for advanced players
-    if let Some(rocket) = &entity.rocket_physics {
-        let total_velocity = phys.velocity.length();
-        let velocity_text = format!("SPEED: {:.1} m/s", total_velocity);
+    // Add delta-V and thrust information for advanced players
+    if let Some(rocket) = &entity.rocket_physics {
+        let total_velocity = phys.velocity.length();
+        let velocity_text = format!("SPEED: {}", entity.units.format_speed(total_velocity, 1));
+        fonts.draw_text(
+            &velocity_text,
+            right_text_start,
+            60.0,
+            15.0,
+            Color::from([1.0; 4]),
+        );
+
+        // Show the throttle setting, lit while the engine is delivering thrust
+        let thrust_percent = (rocket.throttle * 100.0).round() as i32;
+        if rocket.spool_level > 0.0 {
+            let thrust_text = format!("THRUST: {}%", thrust_percent);
+            fonts.draw_text(
+                &thrust_text,
+                right_text_start,
+                80.0,
+                15.0,
+                Color::from([1.0, 1.0, 0.0, 1.0]),
+            ); // Yellow for thrust
+        } else {
+            fonts.draw_text(
+                &format!("THRUST: {}%", thrust_percent),
+                right_text_start,
+                80.0,
+                15.0,
+                Color::from([0.5, 0.5, 0.5, 1.0]),
+            ); // Gray when off
+        }
+    }
+
+    // Inertial navigation readout
+    let distance_text = format!("DISTANCE: {}", entity.units.format_distance(entity.distance_traveled, 0));
+    let max_altitude_text = format!("MAX ALT: {}", entity.units.format_distance(entity.max_altitude, 1));
+    fonts.draw_text(
+        &distance_text,
+        right_text_start,
+        100.0,
+        15.0,
+        Color::from([1.0; 4]),
+    );
+    fonts.draw_text(
+        &max_altitude_text,
+        right_text_start,
+        120.0,
+        15.0,
+        Color::from([1.0; 4]),
+    );
+
+    // Remaining delta-V and staging, both follow the jettisoned mass
+    if let Some(rocket) = &entity.rocket_physics {
+        let delta_v = calculate_delta_v(rocket) as f32;
+        let delta_v_text = format!("DELTA-V: {}", entity.units.format_speed(delta_v, 0));
+        fonts.draw_text(
+            &delta_v_text,
+            right_text_start,
+            140.0,
+            15.0,
+            delta_v_color(delta_v, phys.velocity.y),
+        );
+
+        if rocket.descent_stage.is_some() {
+            let (stage_text, stage_color) = if rocket.stage_jettisoned {
+                ("STAGE: ASCENT", Color::from([0.5, 0.5, 0.5, 1.0]))
+            } else {
+                ("STAGE: DESCENT (J)", Color::from([1.0, 1.0, 0.0, 1.0]))
+            };
+            fonts.draw_text(stage_text, right_text_start, 160.0, 15.0, stage_color);
+        }
+    }
+
+    draw_landing_indicator(entity, phys, right_text_start, 190.0);
+
+    // Flash a warning while fuel is being burned against a surface
+    if entity.thrust_blocked_time >= THRUST_BLOCKED_DELAY && (get_time() * 4.0) as i64 % 2 == 0 {
+        let warning = "THRUST BLOCKED";
+        let width = measure_text(warning, None, 20, 1.0).width;
+        fonts.draw_text(warning, (w - width) / 2.0, screen_height() / 2.0 + 60.0, 20.0, RED);
+    }
+
+    // Tilted-world mode: show which way is down
+    if entity.gravity_angle != 0.0 {
+        let gravity_text = format!("GRAVITY: {:+.0}°", entity.gravity_angle);
+        fonts.draw_text(&gravity_text, right_text_start, 215.0, 15.0, Color::from([1.0; 4]));
+        draw_gravity_arrow(vec2(right_text_start + 150.0, 222.0), entity.gravity_angle);
+    }
+    if entity.gravity_falloff {
+        let gravity_text = format!("G: {:.3} m/s²", entity.gravity_magnitude());
+        fonts.draw_text(&gravity_text, right_text_start, 235.0, 15.0, Color::from([1.0; 4]));
+    }
+
+    // Windy mode: how hard and which way the wind is blowing right now
+    if !entity.wind.is_calm() {
+        let wind = entity.wind.force_at(entity.time_elapsed);
+        let wind_text = format!("WIND: {:.0} N", wind.abs());
+        fonts.draw_text(&wind_text, right_text_start, 255.0, 15.0, Color::from([1.0; 4]));
+        draw_wind_arrow(vec2(right_text_start + 150.0, 262.0), wind / entity.wind.peak_force());
+    }
+}
+
+/// Draws a horizontal arrow showing the wind in screen coordinates.
+///
+/// # Arguments
+///
+/// * `center` - Arrow midpoint in screen coordinates
+/// * `fraction` - Wind force as a fraction of its peak, negative blowing left
+fn draw_wind_arrow(center: Vec2, fraction: f32) {
+    const MAX_ARROW_LENGTH: f32 = 30.0;
+    const HEAD_SIZE: f32 = 7.0;
+
+    let fraction = fraction.clamp(-1.0, 1.0);
+    let direction = vec2(fraction.signum(), 0.0);
+    let length = (MAX_ARROW_LENGTH * fraction.abs()).max(HEAD_SIZE);
+
+    let tail = center - direction * length / 2.0;
+    let tip = center + direction * length / 2.0;
+    draw_line(tail.x, tail.y, tip.x, tip.y, 2.0, SKYBLUE);
+    draw_triangle(
+        tip,
+        tip - direction * HEAD_SIZE + vec2(0.0, HEAD_SIZE / 2.0),
+        tip - direction * HEAD_SIZE - vec2(0.0, HEAD_SIZE / 2.0),
+        SKYBLUE,
+    );
+}
+
+/// Draws an arrow pointing along gravity in screen coordinates.
+///
+/// # Arguments
+///
+/// * `center` - Arrow midpoint in screen coordinates
+/// * `gravity_angle` - Gravity direction in degrees from straight down
+fn draw_gravity_arrow(center: Vec2, gravity_angle: f32) {
+    const ARROW_LENGTH: f32 = 24.0;
+    const HEAD_SIZE: f32 = 7.0;
+
+    // Flip the camera-space direction into screen space (Y down)
+    let camera_direction = gravity_vector(1.0, gravity_angle);
+    let direction = vec2(camera_direction.x, -camera_direction.y);
+    let side = vec2(-direction.y, direction.x);
+
+    let tail = center - direction * ARROW_LENGTH / 2.0;
+    let tip = center + direction * ARROW_LENGTH / 2.0;
+    draw_line(tail.x, tail.y, tip.x, tip.y, 2.0, SKYBLUE);
+    draw_triangle(
+        tip,
+        tip - direction * HEAD_SIZE + side * HEAD_SIZE / 2.0,
+        tip - direction * HEAD_SIZE - side * HEAD_SIZE / 2.0,
+        SKYBLUE,
+    );
+}
+
+/// Draws the three-light landing indicator (zone / speed / angle).
+///
+/// Each light is green while that landing criterion is met and red otherwise,
+/// so players see exactly which requirement they are about to fail.
+///
+/// # Arguments
+///
+/// * `entity` - Lander entity with transform, terrain and landing zones
+/// * `phys` - Physics component providing the current velocity
+/// * `x` - Left edge of the indicator in screen coordinates
+/// * `y` - Vertical center of the lights in screen coordinates
+pub fn draw_landing_indicator(entity: &Entity, phys: &Physics, x: f32, y: f32) {
+    const LIGHT_RADIUS: f32 = 6.0;
+    const LIGHT_SPACING: f32 = 60.0;
+
+    let readiness = assess_landing(
+        &entity.transform,
+        phys.velocity,
+        &entity.terrain,
+        entity.world_size.x,
+        &entity.landing_zones,
+        entity.gravity_angle,
+        entity.gravity_magnitude(),
+        &entity.tuning,
+    );
+    let lights = [
+        ("ZONE", readiness.zone_ok),
+        ("SPEED", readiness.speed_ok),
+        ("ANGLE", readiness.angle_ok),
+    ];
+
+    for (i, (label, ok)) in lights.iter().enumerate() {
+        let light_x = x + LIGHT_RADIUS + i as f32 * LIGHT_SPACING;
+        draw_circle(light_x, y, LIGHT_RADIUS, if *ok { GREEN } else { RED });
+        entity.screen_fonts.draw_text(label, light_x - LIGHT_RADIUS, y + LIGHT_RADIUS + 2.0, 11.0, LIGHTGRAY);
+    }
+}
+
+/// Draws mission-control radio messages centered at the top of the screen.
+///
+/// Messages stack one per line, oldest on top, and fade out as they expire.
+///
+/// # Arguments
+///
+/// * `entity` - Entity holding the radio log and fonts
+pub fn render_radio_log(entity: &Entity) {
+    const RADIO_TOP: f32 = 20.0;
+    const RADIO_LINE_HEIGHT: f32 = 18.0;
+    const RADIO_TEXT_SIZE: u16 = 15;
+
+    set_default_camera();
+    let fonts = &entity.screen_fonts;
+    let center_x = screen_width() / 2.0;
+
+    for (line, (text, alpha)) in entity.radio.visible(get_time()).into_iter().enumerate() {
+        let width = measure_text(text, None, RADIO_TEXT_SIZE, 1.0).width;
         fonts.draw_text(
-            &velocity_text,
-            right_text_start,
-            60.0,
-            15.0,
-            Color::from([1.0; 4]),
+            text,
+            center_x - width / 2.0,
+            RADIO_TOP + line as f32 * RADIO_LINE_HEIGHT,
+            RADIO_TEXT_SIZE as f32,
+            Color::new(0.6, 0.9, 1.0, alpha),
         );
-
-        // Show thrust status
-        if rocket.is_thrusting {
-            let thrust_percent =
-                (rocket.thrust_vector.length() / rocket.max_thrust as f32 * 100.0) as i32;
-            let thrust_text = format!("THRUST: {}%", thrust_percent);
-            fonts.draw_text(
-                &thrust_text,
-                right_text_start,
-                80.0,
-                15.0,
-                Color::from([1.0, 1.0, 0.0, 1.0]),
-            ); // Yellow for thrust
-        } else {
-            fonts.draw_text(
-                "THRUST: 0%",
-                right_text_start,
-                80.0,
-                15.0,
-                Color::from([0.5, 0.5, 0.5, 1.0]),
-            ); // Gray when off
-        }
     }
 }
 
 /// Draws mission result alert box for success or failure scenarios.
 ///
 /// The alert box appears when the mission ends, showing:
-/// - Success: "Mission Success!" with green text
+/// - Success: "Mission Success!" with green text, score and landing grade
 /// - Failure: "Mission Failed!" with red text
 /// - Restart instructions
 ///
 /// # Arguments
 ///
 /// * `entity` - Entity containing mission status
-/// * `session` - Game session for attempt context
-pub fn draw_alert_box(entity: &Entity, session: &GameSession) {
+/// * `session_manager` - Game sessions for attempt context and, in hotseat, whose turn is next
+pub fn draw_alert_box(entity: &Entity, session_manager: &SessionManager) {
     let fonts = &entity.screen_fonts;
+    let session = &session_manager.session;
 
     let screen_width = screen_width();
     let screen_height = screen_height();
@@ -417,8 +1328,12 @@ pub fn draw_alert_box(entity: &Entity, session: &GameSession) {
         fonts.draw_text(success_text, success_text_x, current_y, 28.0, GREEN);
         current_y += 30.0;
         
-        if let Some(attempt) = session.attempts.get(session.current_attempt.saturating_sub(1)) {
-            let score_text = format!("Score: {:.0}", attempt.score);
+        // Practice runs are not recorded, so there is no score to show
+        if let Some(attempt) = session.attempts.get(session.current_attempt.saturating_sub(1)).filter(|_| !entity.practice) {
+            let score_text = match attempt.grade {
+                Some(grade) => format!("Score: {:.0}   Grade: {}", attempt.score, grade.letter()),
+                None => format!("Score: {:.0}", attempt.score),
+            };
             let score_text_width = measure_text(&score_text, None, 18, 1.0).width;
             let score_text_x = box_x + (ALERT_BOX_WIDTH - score_text_width) / 2.0;
             fonts.draw_text(&score_text, score_text_x, current_y, 18.0, WHITE);
@@ -433,8 +1348,12 @@ pub fn draw_alert_box(entity: &Entity, session: &GameSession) {
     }
     
     // Show session progress context - centered
-    let progress_text = if session.session_complete {
+    let progress_text = if entity.practice {
+        "Practice - not scored".to_string()
+    } else if session_manager.is_complete() {
         format!("Session Complete! Total: {}", session.total_score as i32)
+    } else if let Some(label) = session_manager.turn_label() {
+        format!("{} - Attempt {} of {}", label, session.current_attempt, session.max_attempts)
     } else {
         format!("Attempt {} of {}", session.current_attempt, session.max_attempts)
     };
@@ -444,18 +1363,23 @@ pub fn draw_alert_box(entity: &Entity, session: &GameSession) {
     current_y += 25.0;
 
     // Show appropriate restart message based on session state - centered
-    let restart_text = if session.session_complete {
+    let restart_text = if entity.practice {
+        "Press R to Fly Again".to_string()
+    } else if session_manager.is_complete() {
         // All 3 attempts completed - offer new session
-        "Press R for New Session"
+        "Press R for New Session".to_string()
+    } else if let Some(next) = session_manager.next_player().filter(|_| session_manager.is_hotseat()) {
+        // Hotseat - hand the controls to whoever flies next
+        format!("Press R for Player {}", next + 1)
     } else {
         // Still have attempts remaining - offer next attempt
-        "Press R for Next Attempt"
+        "Press R for Next Attempt".to_string()
     };
     
-    let restart_text_width = measure_text(restart_text, None, 16, 1.0).width;
+    let restart_text_width = measure_text(&restart_text, None, 16, 1.0).width;
     let restart_text_x = box_x + (ALERT_BOX_WIDTH - restart_text_width) / 2.0;
     fonts.draw_text(
-        restart_text,
+        &restart_text,
         restart_text_x,
         current_y,
         16.0,
@@ -467,8 +1391,8 @@ pub fn draw_alert_box(entity: &Entity, session: &GameSession) {
 ///
 /// This function renders:
 /// - Lander bounding box with corner markers
-/// - Leg collision zones (bottom 25%, left/right 30%)
-/// - Body collision zone (center 40%)
+/// - Leg collision zones (from the entity's tuned `CollisionShape`)
+/// - Body collision zone (center section above the legs)
 /// - Critical collision edges and margins
 ///
 /// # Arguments
@@ -499,33 +1423,16 @@ pub fn draw_collision_bounding_box(entity: &Entity, camera: &Camera2D) -> () {
         ORANGE,
     ); // Top-right of rocket
 
-    // Collision zones - corrected for inverted Y coordinates
-    const LEG_HEIGHT_RATIO: f32 = 0.25;
-    const LEG_WIDTH_RATIO: f32 = 0.3;
-    let leg_height = lander_height * LEG_HEIGHT_RATIO;
-    let leg_width = lander_width * LEG_WIDTH_RATIO;
-
-    // Bottom 25% of rocket for legs (lander_y is the bottom)
-    let leg_zone_bottom = lander_y;
-    // let leg_zone_top = lander_y + leg_height;
-
-    // Left leg zone (green rectangles) - bottom 25% left edge
-    draw_rectangle_lines(lander_x, leg_zone_bottom, leg_width, leg_height, 2.0, GREEN);
-
-    // Right leg zone (green rectangles) - bottom 25% right edge
-    draw_rectangle_lines(
-        lander_x + lander_width - leg_width,
-        leg_zone_bottom,
-        leg_width,
-        leg_height,
-        2.0,
-        GREEN,
-    );
+    // Collision zones - same shape the collision detection uses
+    let zones = entity.tuning.shape.zones(&entity.transform);
+
+    // Left and right leg zones (green rectangles) - bottom edge corners
+    for leg in [zones.left_leg, zones.right_leg] {
+        draw_rectangle_lines(leg.x, leg.y, leg.w, leg.h, 2.0, GREEN);
+    }
 
-    // Body collision zone (red center area) - full height, center area
-    let body_left = lander_x + leg_width;
-    let body_width = lander_width - (2.0 * leg_width);
-    draw_rectangle_lines(body_left, lander_y, body_width, lander_height, 2.0, RED);
+    // Body collision zone (red center area) - above the legs
+    draw_rectangle_lines(zones.body.x, zones.body.y, zones.body.w, zones.body.h, 2.0, RED);
 
     // Bottom edge line - this is where collision actually happens (at lander_y)
     draw_line(
@@ -551,14 +1458,13 @@ pub fn draw_collision_bounding_box(entity: &Entity, camera: &Camera2D) -> () {
     set_camera(camera);
 
     // Use same terrain index calculation as collision detection
-    let screen_width = macroquad::window::screen_width();
-    let lander_left_x = lander_x;
-    let lander_right_x = lander_x + lander_width;
+    let points = entity.terrain.len();
+    let lander_left_x = pixel_to_terrain_x(lander_x, points, entity.world_size.x);
+    let lander_right_x = pixel_to_terrain_x(lander_x + lander_width, points, entity.world_size.x);
 
     // Convert to terrain array indices (same as collision detection)
-    let _terrain_start_idx = (((lander_left_x + screen_width) / (screen_width * 2.0) * 800.0) as i32).max(0) as usize;
-    let _terrain_end_idx = (((lander_right_x + screen_width) / (screen_width * 2.0) * 800.0) as i32)
-        .min(799) as usize;
+    let _terrain_start_idx = (lander_left_x as i32).max(0) as usize;
+    let _terrain_end_idx = (lander_right_x as i32).min(points as i32 - 1) as usize;
 
     // Debug collision points removed for cleaner display
 
@@ -577,8 +1483,8 @@ pub fn draw_collision_bounding_box(entity: &Entity, camera: &Camera2D) -> () {
 /// * `entity` - Entity containing terrain data for marker positioning
 pub fn debug_render(entity: &Entity) {
     // Debug: Draw center reticle to show screen center
-    let screen_center_x = screen_width() / 2.0;
-    let screen_center_y = screen_height() / 2.0;
+    let screen_center_x = entity.world_size.x / 2.0;
+    let screen_center_y = entity.world_size.y / 2.0;
     // Horizontal line (50 pixels each direction)
     draw_line(
         screen_center_x - 50.0,
@@ -613,9 +1519,9 @@ pub fn debug_render(entity: &Entity) {
     );
     // Right edge marker (10 pixels from right edge toward center)
     draw_line(
-        screen_width() - 10.0,
+        entity.world_size.x - 10.0,
         max_terrain_height,
-        screen_width(),
+        entity.world_size.x,
         max_terrain_height,
         2.0,
         BLUE,
@@ -629,34 +1535,420 @@ pub fn debug_render(entity: &Entity) {
         2.0,
         BLUE,
     );
+
+    // Engine efficiency readout, tunable with [ and ]
+    if let Some(rocket) = &entity.rocket_physics {
+        set_default_camera();
+        let engine_text = format!(
+            "ISP: {:.0} s (v_e {:.0} m/s)  [ / ] to tune",
+            rocket.specific_impulse(),
+            rocket.exhaust_velocity
+        );
+        entity.screen_fonts.draw_text(&engine_text, 20.0, entity.world_size.y - 40.0, 15.0, SKYBLUE);
+    }
+
+    // Start position of this attempt (non-zero only with --random-spawn)
+    set_default_camera();
+    let spawn_text = format!("SPAWN: offset {:+.0} px, drift {:+.1} m/s", entity.spawn_offset, entity.spawn_drift);
+    entity.screen_fonts.draw_text(&spawn_text, 20.0, entity.world_size.y - 60.0, 15.0, SKYBLUE);
+
+    let integrator_text = format!("INTEGRATOR: {}", entity.integrator.name());
+    entity.screen_fonts.draw_text(&integrator_text, 20.0, entity.world_size.y - 80.0, 15.0, SKYBLUE);
+}
+
+/// Blacks out the window outside the letterboxed world.
+///
+/// When the window's aspect ratio differs from the virtual resolution the
+/// cameras still draw beyond the world edges (the starfield, terrain past the
+/// follow-cam clamp); bars over the unused strips keep the world framed.
+///
+/// # Arguments
+///
+/// * `virtual_resolution` - Size of the gameplay world
+pub fn render_letterbox(virtual_resolution: Vec2) {
+    let window = vec2(screen_width(), screen_height());
+    let world = letterbox(virtual_resolution, window);
+
+    set_default_camera();
+    // Left and right bars, then top and bottom; one pair is empty
+    draw_rectangle(0.0, 0.0, world.x, window.y, BLACK);
+    draw_rectangle(world.right(), 0.0, window.x - world.right(), window.y, BLACK);
+    draw_rectangle(0.0, 0.0, window.x, world.y, BLACK);
+    draw_rectangle(0.0, world.bottom(), window.x, window.y - world.bottom(), BLACK);
 }
 
 /// Configures the 2D camera with proper coordinate system transformations.
 ///
 /// The camera setup:
 /// - Inverts Y-axis for standard mathematical coordinates
-/// - Centers on screen with appropriate zoom levels
-/// - Handles coordinate transformations between screen and world space
+/// - Shows the whole virtual resolution, letterboxed into the window
+///   (see `utils::letterbox`), so a resized window scales the world rather
+///   than revealing more or less of it
+///
+/// # Arguments
+///
+/// * `virtual_resolution` - Size of the gameplay world
 ///
 /// # Returns
 ///
 /// Configured `Camera2D` instance ready for rendering
-pub fn configure_camera() -> Camera2D {
-    let screen_width = screen_width();
-    let screen_height = screen_height();
+pub fn configure_camera(virtual_resolution: Vec2) -> Camera2D {
+    let window = vec2(screen_width(), screen_height());
+    let scale = letterbox(virtual_resolution, window).w / virtual_resolution.x;
 
     // Create a Camera2D with the standard x, y axes orientation
     Camera2D {
-        zoom: vec2(2.0 / screen_width, -2.0 / screen_height), // Invert y-axis
-        target: vec2(screen_width / 2.0, screen_height / 2.0),
+        zoom: vec2(2.0 * scale / window.x, -2.0 * scale / window.y), // Invert y-axis
+        target: virtual_resolution / 2.0,
+        ..Default::default()
+    }
+}
+
+/// Configures a camera that follows the lander and zooms in near the surface.
+///
+/// Zoom grows from 1x at `FOLLOW_ZOOM_START_ALTITUDE` to `FOLLOW_MAX_ZOOM` on
+/// the ground. The target is the lander center, clamped so the view never
+/// leaves the virtual world; at 1x that is the same view as
+/// `configure_camera`.
+///
+/// # Arguments
+///
+/// * `entity` - Lander to follow
+pub fn configure_follow_camera(entity: &Entity) -> Camera2D {
+    let window = vec2(screen_width(), screen_height());
+    let scale = letterbox(entity.world_size, window).w / entity.world_size.x;
+    let transform = entity.displayed_transform(get_time());
+    let center = transform.position + transform.size * 0.5;
+    let altitude = transform.position.y - entity.ground_under(transform.position.x, transform.size.x);
+    let zoom = follow_zoom(altitude);
+
+    Camera2D {
+        zoom: vec2(2.0 * zoom * scale / window.x, -2.0 * zoom * scale / window.y), // Invert y-axis
+        target: follow_target(center, zoom, entity.world_size),
         ..Default::default()
     }
 }
 
+/// Follow-cam zoom for an altitude: 1x high up, `FOLLOW_MAX_ZOOM` on the ground.
+pub fn follow_zoom(altitude: f32) -> f32 {
+    let closeness = 1.0 - (altitude / FOLLOW_ZOOM_START_ALTITUDE).clamp(0.0, 1.0);
+    1.0 + (FOLLOW_MAX_ZOOM - 1.0) * closeness
+}
+
+/// Camera target near `center`, kept far enough from the edges that a view
+/// zoomed by `zoom` stays inside the `world`.
+pub fn follow_target(center: Vec2, zoom: f32, world: Vec2) -> Vec2 {
+    let half_view = world / (2.0 * zoom);
+    center.clamp(half_view, world - half_view)
+}
+
+/// Offsets a camera's target by the crash shake.
+///
+/// Only the world view shakes; HUD panels switch to the default camera.
+///
+/// # Arguments
+///
+/// * `camera` - Camera to shake
+/// * `intensity` - Peak jitter in pixels at the moment of impact (0 for none)
+/// * `elapsed` - Seconds since the impact
+pub fn shake_camera(mut camera: Camera2D, intensity: f32, elapsed: f64) -> Camera2D {
+    camera.target += shake_offset(intensity, elapsed);
+    camera
+}
+
+/// Random camera jitter for a crash, decaying linearly to nothing over `SHAKE_DURATION`.
+pub fn shake_offset(intensity: f32, elapsed: f64) -> Vec2 {
+    if intensity <= 0.0 || !(0.0..SHAKE_DURATION).contains(&elapsed) {
+        return Vec2::ZERO;
+    }
+    let amplitude = intensity * (1.0 - (elapsed / SHAKE_DURATION) as f32);
+    vec2(
+        rand::gen_range(-amplitude, amplitude),
+        rand::gen_range(-amplitude, amplitude),
+    )
+}
+
+/// Renders the lifetime statistics screen (toggled with L).
+///
+/// # Arguments
+///
+/// * `entity` - Entity providing the screen fonts
+/// * `lifetime` - Totals across every session played
+pub fn render_lifetime_stats(entity: &Entity, lifetime: &LifetimeStats) {
+    const PANEL_WIDTH: f32 = 300.0;
+    const LINE_HEIGHT: f32 = 20.0;
+
+    set_default_camera();
+    let fonts = &entity.screen_fonts;
+
+    let attempts = lifetime.landings + lifetime.crashes;
+    let favorite = lifetime.favorite_zone().map_or("-", |zone| zone.name());
+    let favorite_body = lifetime.favorite_body().map_or("-", |body| body.name());
+    let lines = [
+        format!("SESSIONS:      {}", lifetime.sessions_played),
+        format!("LANDINGS:      {}", lifetime.landings),
+        format!("CRASHES:       {}", lifetime.crashes),
+        match attempts {
+            0 => "SUCCESS RATE:  -".to_string(),
+            _ => format!("SUCCESS RATE:  {:.0}%", lifetime.landings as f32 * 100.0 / attempts as f32),
+        },
+        format!("BEST SCORE:    {:.0}", lifetime.best_score),
+        format!("FUEL SAVED:    {:.0}%", lifetime.fuel_saved),
+        format!("FAVORITE ZONE: {}", favorite),
+        format!("FAVORITE BODY: {}", favorite_body),
+    ];
+
+    let panel_height = (lines.len() as f32 + 2.0) * LINE_HEIGHT;
+    let x = (screen_width() - PANEL_WIDTH) / 2.0;
+    let y = (screen_height() - panel_height) / 2.0;
+    draw_rectangle(x, y, PANEL_WIDTH, panel_height, Color::new(0.0, 0.0, 0.0, 0.85));
+    draw_rectangle_lines(x, y, PANEL_WIDTH, panel_height, 2.0, GOLD);
+
+    let title = "LIFETIME STATS";
+    let title_width = measure_text(title, None, 18, 1.0).width;
+    fonts.draw_text(title, x + (PANEL_WIDTH - title_width) / 2.0, y + 8.0, 18.0, GOLD);
+    for (i, line) in lines.iter().enumerate() {
+        fonts.draw_text(line, x + 20.0, y + (i as f32 + 1.5) * LINE_HEIGHT, 14.0, WHITE);
+    }
+}
+
+/// Draws the centered "PAUSED" overlay.
+pub fn render_paused(entity: &Entity) {
+    set_default_camera();
+    draw_rectangle(0.0, 0.0, screen_width(), screen_height(), Color::new(0.0, 0.0, 0.0, 0.4));
+
+    let title = "PAUSED";
+    let title_width = measure_text(title, None, 40, 1.0).width;
+    let y = screen_height() / 2.0 - 30.0;
+    entity.screen_fonts.draw_text(title, (screen_width() - title_width) / 2.0, y, 40.0, WHITE);
+
+    let hint = "Press P to resume";
+    let hint_width = measure_text(hint, None, 16, 1.0).width;
+    entity.screen_fonts.draw_text(hint, (screen_width() - hint_width) / 2.0, y + 50.0, 16.0, LIGHTGRAY);
+}
+
+/// Renders the leaderboard of the best completed sessions (toggled with T).
+///
+/// The game is paused while the leaderboard is open.
+///
+/// # Arguments
+///
+/// * `entity` - Entity providing the screen fonts
+/// * `high_scores` - Completed sessions, highest score first
+pub fn render_leaderboard(entity: &Entity, high_scores: &[SessionRecord]) {
+    const PANEL_WIDTH: f32 = 420.0;
+    const LINE_HEIGHT: f32 = 20.0;
+    const ROWS: usize = 10;
+
+    set_default_camera();
+    let fonts = &entity.screen_fonts;
+
+    let rows = high_scores.len().clamp(1, ROWS);
+    let panel_height = (rows as f32 + 3.5) * LINE_HEIGHT;
+    let x = (screen_width() - PANEL_WIDTH) / 2.0;
+    let y = (screen_height() - panel_height) / 2.0;
+    draw_rectangle(x, y, PANEL_WIDTH, panel_height, Color::new(0.0, 0.0, 0.0, 0.85));
+    draw_rectangle_lines(x, y, PANEL_WIDTH, panel_height, 2.0, GOLD);
+
+    let title = "LEADERBOARD - PAUSED";
+    let title_width = measure_text(title, None, 18, 1.0).width;
+    fonts.draw_text(title, x + (PANEL_WIDTH - title_width) / 2.0, y + 8.0, 18.0, GOLD);
+
+    let columns = [x + 20.0, x + 60.0, x + 170.0, x + 260.0];
+    let header_y = y + 1.5 * LINE_HEIGHT;
+    for (column, label) in columns.iter().zip(["#", "DATE", "SCORE", "RATING"]) {
+        fonts.draw_text(label, *column, header_y, 14.0, LIGHTGRAY);
+    }
+
+    if high_scores.is_empty() {
+        fonts.draw_text("No completed sessions yet", columns[1], header_y + LINE_HEIGHT, 14.0, WHITE);
+        return;
+    }
+    for (rank, record) in high_scores.iter().take(ROWS).enumerate() {
+        let row_y = header_y + (rank as f32 + 1.0) * LINE_HEIGHT;
+        let color = if rank == 0 { GOLD } else { WHITE };
+        fonts.draw_text(&format!("{}", rank + 1), columns[0], row_y, 14.0, color);
+        fonts.draw_text(&record.date, columns[1], row_y, 14.0, color);
+        fonts.draw_text(&format!("{:.0}", record.total_score), columns[2], row_y, 14.0, color);
+        fonts.draw_text(&record.rating, columns[3], row_y, 14.0, color);
+    }
+}
+
+/// Draws vertical fuel and RCS propellant gauges in the bottom-right corner.
+///
+/// Each bar fills in proportion to its tank and shares the green / yellow /
+/// red thresholds of `fuel_bar_color`.
+///
+/// # Arguments
+///
+/// * `entity` - Entity with the rocket engine and fonts
+pub fn render_fuel_gauge(entity: &Entity) {
+    const BAR_WIDTH: f32 = 12.0;
+    const BAR_HEIGHT: f32 = 100.0;
+    const BAR_SPACING: f32 = 24.0;
+    const MARGIN: f32 = 20.0;
+
+    let rocket = match &entity.rocket_physics {
+        Some(rocket) => rocket,
+        None => return,
+    };
+
+    set_default_camera();
+    let gauges = [("F", rocket.fuel_percentage()), ("R", rocket.rcs_fuel_percentage())];
+    let bottom = screen_height() - MARGIN - 16.0; // Room for the letter below each bar
+    let first_x = screen_width() - MARGIN - BAR_WIDTH - BAR_SPACING * (gauges.len() - 1) as f32;
+
+    for (i, (label, percent)) in gauges.iter().enumerate() {
+        let x = first_x + i as f32 * BAR_SPACING;
+        let fill = BAR_HEIGHT * (percent / 100.0).clamp(0.0, 1.0);
+        draw_rectangle(x, bottom - BAR_HEIGHT, BAR_WIDTH, BAR_HEIGHT, Color::new(0.3, 0.3, 0.3, 0.8));
+        draw_rectangle(x, bottom - fill, BAR_WIDTH, fill, fuel_bar_color(*percent));
+        draw_rectangle_lines(x, bottom - BAR_HEIGHT, BAR_WIDTH, BAR_HEIGHT, 1.0, GRAY);
+        entity.screen_fonts.draw_text(label, x + 2.0, bottom + 2.0, 12.0, WHITE);
+    }
+}
+
+/// Draws a scaled-down view of the whole terrain in the bottom-left corner.
+///
+/// Landing zones are marked in their difficulty colors and the lander is a
+/// dot, so the player can find the zones while the follow camera is zoomed
+/// in. Drawn in screen coordinates.
+///
+/// # Arguments
+///
+/// * `entity` - Lander entity with terrain, landing zones and transform
+/// * `session` - Current session, for greying out consumed zones
+/// * `theme` - Terrain and zone colors
+pub fn render_minimap(entity: &Entity, session: &GameSession, theme: &TerrainTheme) {
+    const MAP_WIDTH: f32 = 160.0;
+    const MAP_HEIGHT: f32 = 60.0;
+    const MARGIN: f32 = 20.0;
+
+    if entity.terrain.is_empty() {
+        return;
+    }
+
+    set_default_camera();
+    let left = MARGIN;
+    let bottom = screen_height() - MARGIN;
+    let scale = vec2(MAP_WIDTH / entity.terrain.len() as f32, MAP_HEIGHT / entity.world_size.y);
+    // Camera coordinates (Y up) to a point on the map (Y down)
+    let to_map = |x: f32, y: f32| vec2(left + x * scale.x, bottom - y.clamp(0.0, entity.world_size.y) * scale.y);
+
+    draw_rectangle(left, bottom - MAP_HEIGHT, MAP_WIDTH, MAP_HEIGHT, Color::new(0.0, 0.0, 0.0, 0.6));
+
+    let heights = minimap_heights(&entity.terrain, MAP_WIDTH as usize);
+    let step = entity.terrain.len() as f32 / heights.len() as f32;
+    for (i, pair) in heights.windows(2).enumerate() {
+        let start = to_map(i as f32 * step, pair[0]);
+        let end = to_map((i + 1) as f32 * step, pair[1]);
+        draw_line(start.x, start.y, end.x, end.y, 1.0, theme.terrain);
+    }
+
+    for zone in &entity.landing_zones {
+        let height = entity.terrain.get(zone.start).copied().unwrap_or(0.0) as f32;
+        let start = to_map(zone.start as f32, height);
+        let end = to_map((zone.end + 1) as f32, height);
+        let color = theme.zone_color(zone.difficulty, session.is_zone_consumed(zone.difficulty));
+        draw_line(start.x, start.y, end.x, end.y, 3.0, color);
+    }
+
+    let transform = entity.displayed_transform(get_time());
+    let center = transform.position + transform.size * 0.5;
+    let dot = to_map(pixel_to_terrain_x(center.x, entity.terrain.len(), entity.world_size.x), center.y);
+    draw_circle(dot.x, dot.y, 2.5, WHITE);
+
+    draw_rectangle_lines(left, bottom - MAP_HEIGHT, MAP_WIDTH, MAP_HEIGHT, 1.0, GRAY);
+}
+
+/// Samples the terrain down to `width` evenly spaced heights for the minimap.
+///
+/// Terrain no wider than `width` is returned as is.
+pub fn minimap_heights(terrain: &[f64], width: usize) -> Vec<f32> {
+    if terrain.len() <= width {
+        return terrain.iter().map(|height| *height as f32).collect();
+    }
+    (0..width)
+        .map(|i| terrain[i * terrain.len() / width] as f32)
+        .collect()
+}
+
+/// Draws a compact fuel gauge centered under an attempt icon.
+///
+/// # Arguments
+///
+/// * `center_x` - Horizontal center of the bar
+/// * `top` - Top edge of the bar
+/// * `fuel_percent` - Fuel remaining (0-100)
+fn draw_fuel_bar(center_x: f32, top: f32, fuel_percent: f32) {
+    const BAR_WIDTH: f32 = 24.0;
+    const BAR_HEIGHT: f32 = 3.0;
+
+    let left = center_x - BAR_WIDTH / 2.0;
+    let fill = BAR_WIDTH * (fuel_percent / 100.0).clamp(0.0, 1.0);
+    draw_rectangle(left, top, BAR_WIDTH, BAR_HEIGHT, Color::new(0.3, 0.3, 0.3, 0.8));
+    draw_rectangle(left, top, fill, BAR_HEIGHT, fuel_bar_color(fuel_percent));
+}
+
+/// Delta-V readout color: red once the remaining budget can't cancel the
+/// current descent rate, white otherwise.
+///
+/// # Arguments
+///
+/// * `delta_v` - Remaining delta-V in m/s
+/// * `vertical_velocity` - Vertical velocity in m/s (negative is descending)
+pub fn delta_v_color(delta_v: f32, vertical_velocity: f32) -> Color {
+    if delta_v < -vertical_velocity {
+        RED
+    } else {
+        WHITE
+    }
+}
+
+/// Gauge color for a fuel level: green above half, yellow above a quarter, red below.
+pub fn fuel_bar_color(fuel_percent: f32) -> Color {
+    if fuel_percent > 50.0 {
+        GREEN
+    } else if fuel_percent >= 25.0 {
+        YELLOW
+    } else {
+        RED
+    }
+}
+
+/// Draws a single attempt indicator circle.
+///
+/// Gray is a future attempt, yellow (ringed) the current one, green a success
+/// and red a failure. Shared by the session status bar and the result card.
+///
+/// # Arguments
+///
+/// * `x`, `y` - Circle center
+/// * `size` - Circle radius
+/// * `result` - Attempt outcome
+/// * `is_current` - Whether this is the attempt being flown
+pub fn draw_attempt_icon(x: f32, y: f32, size: f32, result: &AttemptResult, is_current: bool) {
+    let (circle_color, highlight) = match result {
+        AttemptResult::Success => (GREEN, false),
+        AttemptResult::Failure => (RED, false),
+        AttemptResult::InProgress if is_current => (YELLOW, true), // Current attempt
+        AttemptResult::InProgress => (GRAY, false),                // Future attempt
+    };
+
+    // Draw background circle
+    draw_circle(x, y, size, circle_color);
+
+    // Highlight current attempt with a ring
+    if highlight {
+        draw_circle_lines(x, y, size + 2.0, 2.0, WHITE);
+    }
+}
+
 /// Renders the game session status bar showing attempt indicators and scoring information.
 ///
 /// The status bar displays:
 /// - Visual indicators for each of the 3 attempts (gray = future, yellow = current, green = success, red = failure)
+/// - Score and landing grade under each successful attempt
 /// - Current attempt number and total attempts
 /// - Total session score
 /// - Performance summary when session is complete
@@ -665,7 +1957,9 @@ pub fn configure_camera() -> Camera2D {
 ///
 /// * `entity` - Entity containing fonts for text rendering
 /// * `session` - Game session state to display
-pub fn render_session_status(entity: &Entity, session: &GameSession) {
+/// * `best_score` - Highest session score on record, shown beside the total
+/// * `turn_label` - Whose turn it is, in a hotseat game
+pub fn render_session_status(entity: &Entity, session: &GameSession, best_score: Option<f32>, turn_label: Option<String>) {
     set_default_camera();
     let fonts = &entity.screen_fonts;
     
@@ -682,27 +1976,12 @@ pub fn render_session_status(entity: &Entity, session: &GameSession) {
         let x = start_x + (i as f32 * icon_spacing);
         let y = start_y;
         
-        // Determine color and appearance based on attempt status
-        let (circle_color, text_color, is_current) = match attempt.result {
-            AttemptResult::Success => (GREEN, GREEN, false),
-            AttemptResult::Failure => (RED, RED, false),
-            AttemptResult::InProgress => {
-                if i == session.current_attempt {
-                    (YELLOW, YELLOW, true) // Current attempt
-                } else {
-                    (GRAY, GRAY, false)   // Future attempt
-                }
-            }
-        };
-        
-        // Draw background circle
-        draw_circle(x, y, icon_size, circle_color);
-        
         // No text needed - the colored circles are clear enough indicators
-        
-        // Highlight current attempt with a ring
-        if is_current {
-            draw_circle_lines(x, y, icon_size + 2.0, 2.0, WHITE);
+        draw_attempt_icon(x, y, icon_size, &attempt.result, i == session.current_attempt);
+
+        // Fuel left at the end of each finished attempt, just under the icon
+        if attempt.result != AttemptResult::InProgress {
+            draw_fuel_bar(x, y + icon_size + 5.0, attempt.fuel_remaining);
         }
         
         // Show score below successful attempts - positioned better to avoid overlap
@@ -713,21 +1992,38 @@ pub fn render_session_status(entity: &Entity, session: &GameSession) {
             let score_text_x = x - score_text_width / 2.0;
             fonts.draw_text(&score_text, score_text_x, y + 35.0, 12.0, WHITE);
         }
+
+        // Landing grade below the score
+        if let Some(grade) = attempt.grade {
+            let grade_text_width = measure_text(grade.letter(), None, 12, 1.0).width;
+            fonts.draw_text(grade.letter(), x - grade_text_width / 2.0, y + 48.0, 12.0, GOLD);
+        }
     }
     
     // Draw session information - centered below the icons with more space
     let info_y = start_y + 60.0; // Position below the icons and scores
     
     // Current attempt indicator - centered
-    let attempt_text = format!("ATTEMPT: {}/{}", 
-        (session.current_attempt + 1).min(session.max_attempts), 
-        session.max_attempts);
+    let attempt_text = if entity.practice {
+        "PRACTICE".to_string()
+    } else {
+        let attempt = format!("ATTEMPT: {}/{}",
+            (session.current_attempt + 1).min(session.max_attempts),
+            session.max_attempts);
+        match turn_label {
+            Some(label) => format!("{} - {}", label, attempt),
+            None => attempt,
+        }
+    };
     let attempt_text_width = measure_text(&attempt_text, None, 16, 1.0).width;
     let attempt_text_x = (screen_width - attempt_text_width) / 2.0;
     fonts.draw_text(&attempt_text, attempt_text_x, info_y, 16.0, WHITE);
     
-    // Total score - centered below attempt indicator
-    let score_text = format!("TOTAL SCORE: {:.0}", session.total_score);
+    // Total score - centered below attempt indicator, with the best session on record beside it
+    let score_text = match best_score {
+        Some(best) => format!("TOTAL SCORE: {:.0}   BEST: {:.0}", session.total_score, best),
+        None => format!("TOTAL SCORE: {:.0}", session.total_score),
+    };
     let score_text_width = measure_text(&score_text, None, 16, 1.0).width;
     let score_text_x = (screen_width - score_text_width) / 2.0;
     fonts.draw_text(&score_text, score_text_x, info_y + 20.0, 16.0, WHITE);
@@ -768,3 +2064,278 @@ pub fn render_session_status(entity: &Entity, session: &GameSession) {
         }
     }
 }
+
+/// Renders the full-screen summary of a completed session.
+///
+/// Tabulates every attempt's result, zone, fuel, time, grade and score with
+/// the best-scoring landing highlighted, followed by the session total,
+/// performance rating, the best landing on each zone difficulty for the
+/// current body, the daily challenge's date, seed and best when one is being
+/// played, and how to start a new session.
+///
+/// # Arguments
+///
+/// * `entity` - Entity providing the screen fonts, current body and terrain seed
+/// * `session` - Completed game session to summarize
+/// * `high_scores` - Saved records, for the best landings and daily best
+/// * `daily` - Date of the daily challenge being played, if any
+pub fn render_session_summary(entity: &Entity, session: &GameSession, high_scores: &HighScores, daily: Option<&str>) {
+    const PANEL_WIDTH: f32 = 540.0;
+    const LINE_HEIGHT: f32 = 24.0;
+
+    set_default_camera();
+    let fonts = &entity.screen_fonts;
+    draw_rectangle(0.0, 0.0, screen_width(), screen_height(), Color::new(0.0, 0.0, 0.0, 0.9));
+
+    let best_landings: Vec<String> = [LandingZoneDifficulty::Hard, LandingZoneDifficulty::Medium, LandingZoneDifficulty::Easy]
+        .iter()
+        .map(|zone| {
+            let score = high_scores.best_landing(*zone, entity.body).map_or("-".to_string(), |record| format!("{:.0}", record.score));
+            format!("{} {}", zone.name().to_uppercase(), score)
+        })
+        .collect();
+
+    let mut lines = vec![
+        (format!("TOTAL SCORE: {:.0}", session.total_score), 20.0, WHITE),
+        (format!("RATING: {}", session.performance_rating()), 20.0, GOLD),
+        (
+            format!("SUCCESSES: {} / FAILURES: {}   AVG FUEL: {:.1}%",
+                session.success_count(),
+                session.failure_count(),
+                session.average_fuel_efficiency()),
+            14.0,
+            WHITE,
+        ),
+        (format!("BEST ON {}: {}", entity.body.name().to_uppercase(), best_landings.join("   ")), 14.0, GOLD),
+    ];
+    if let Some(date) = daily {
+        let best = high_scores.daily_best_on(date).map_or("-".to_string(), |score| format!("{:.0}", score));
+        lines.push((format!("DAILY CHALLENGE {} (SEED {}): BEST {}", date, entity.layout_seed, best), 14.0, GOLD));
+    }
+    lines.push(("Press R for New Session".to_string(), 16.0, LIGHTGRAY));
+
+    let panel_height = (session.attempts.len() + lines.len() + 4) as f32 * LINE_HEIGHT;
+    let x = (screen_width() - PANEL_WIDTH) / 2.0;
+    let y = (screen_height() - panel_height) / 2.0;
+    draw_rectangle_lines(x, y, PANEL_WIDTH, panel_height, 2.0, GOLD);
+
+    let title = "SESSION COMPLETE";
+    let title_width = measure_text(title, None, 28, 1.0).width;
+    fonts.draw_text(title, x + (PANEL_WIDTH - title_width) / 2.0, y + 10.0, 28.0, GOLD);
+
+    let columns = [x + 20.0, x + 55.0, x + 145.0, x + 230.0, x + 310.0, x + 390.0, x + 460.0];
+    let header_y = y + 2.5 * LINE_HEIGHT;
+    for (column, label) in columns.iter().zip(["#", "RESULT", "ZONE", "FUEL", "TIME", "GRADE", "SCORE"]) {
+        fonts.draw_text(label, *column, header_y, 14.0, LIGHTGRAY);
+    }
+
+    let best = session.best_attempt();
+    for (i, attempt) in session.attempts.iter().enumerate() {
+        let row_y = header_y + (i as f32 + 1.0) * LINE_HEIGHT;
+        let is_best = matches!(best, Some(best) if std::ptr::eq(best, attempt));
+        if is_best {
+            draw_rectangle(x + 10.0, row_y - 4.0, PANEL_WIDTH - 20.0, LINE_HEIGHT - 2.0, Color::new(1.0, 0.84, 0.0, 0.2));
+        }
+
+        let color = if is_best { GOLD } else { WHITE };
+        let result_color = match attempt.result {
+            AttemptResult::Success => GREEN,
+            AttemptResult::Failure => RED,
+            AttemptResult::InProgress => GRAY,
+        };
+        fonts.draw_text(&format!("{}", i + 1), columns[0], row_y, 16.0, color);
+        fonts.draw_text(attempt.result.name(), columns[1], row_y, 16.0, result_color);
+        fonts.draw_text(attempt.landing_zone.as_ref().map_or("-", |zone| zone.name()), columns[2], row_y, 16.0, color);
+        fonts.draw_text(&format!("{:.1}%", attempt.fuel_remaining), columns[3], row_y, 16.0, color);
+        fonts.draw_text(&format!("{:.1}s", attempt.time_taken), columns[4], row_y, 16.0, color);
+        fonts.draw_text(attempt.grade.map_or("-", |grade| grade.letter()), columns[5], row_y, 16.0, color);
+        fonts.draw_text(&format!("{:.0}", attempt.score), columns[6], row_y, 16.0, color);
+    }
+
+    let footer_y = header_y + (session.attempts.len() as f32 + 1.5) * LINE_HEIGHT;
+    for (i, (text, size, color)) in lines.iter().enumerate() {
+        let text_width = measure_text(text, None, *size as u16, 1.0).width;
+        fonts.draw_text(text, x + (PANEL_WIDTH - text_width) / 2.0, footer_y + i as f32 * LINE_HEIGHT, *size, *color);
+    }
+}
+
+/// Draws every hotseat player's total above the session summary, leader in gold.
+///
+/// # Arguments
+///
+/// * `entity` - Entity providing the screen fonts
+/// * `session_manager` - Sessions of all the players
+pub fn render_hotseat_standings(entity: &Entity, session_manager: &SessionManager) {
+    let totals: Vec<f32> = (0..session_manager.player_count())
+        .map(|player| session_manager.session_of(player).total_score)
+        .collect();
+    let best = totals.iter().copied().fold(f32::MIN, f32::max);
+
+    let spacing = 180.0;
+    let start_x = (screen_width() - spacing * (totals.len() as f32 - 1.0)) / 2.0;
+    for (player, total) in totals.iter().enumerate() {
+        let text = format!("PLAYER {}: {:.0}", player + 1, total);
+        let text_width = measure_text(&text, None, 20, 1.0).width;
+        let color = if *total == best { GOLD } else { WHITE };
+        entity.screen_fonts.draw_text(&text, start_x + player as f32 * spacing - text_width / 2.0, 30.0, 20.0, color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flame_tint() {
+        let cool = flame_tint(0.0, 10.0);
+        assert!(cool.b > cool.r, "Hovering burns blue");
+        assert_eq!(flame_tint(5.0, 10.0), cool, "Climbing counts as safe");
+        assert_eq!(flame_tint(-5.0, 10.0), WHITE);
+        let hot = flame_tint(-10.0, 10.0);
+        assert!(hot.r > hot.b, "At the landing limit the flame runs red");
+        assert_eq!(flame_tint(-30.0, 10.0), hot);
+    }
+
+    #[test]
+    fn test_velocity_arrow() {
+        let center = vec2(100.0, 200.0);
+        assert!(velocity_arrow(center, Vec2::ZERO, 10.0).is_none(), "No arrow at rest");
+
+        let (tip, color) = velocity_arrow(center, vec2(0.0, -5.0), 10.0).unwrap();
+        assert_eq!(tip, vec2(100.0, 185.0), "Points down, 3 px per m/s");
+        assert_eq!(color, GREEN);
+
+        let (tip, color) = velocity_arrow(center, vec2(100.0, 0.0), 10.0).unwrap();
+        assert_eq!(tip, vec2(220.0, 200.0), "Length is capped");
+        assert_eq!(color, RED);
+    }
+
+    #[test]
+    fn test_delta_v_color() {
+        assert_eq!(delta_v_color(100.0, -20.0), WHITE);
+        assert_eq!(delta_v_color(10.0, -20.0), RED, "Not enough to stop the descent");
+        assert_eq!(delta_v_color(0.0, 5.0), WHITE, "Climbing needs no braking");
+    }
+
+    #[test]
+    fn test_minimap_heights() {
+        let terrain: Vec<f64> = (0..800).map(f64::from).collect();
+        let heights = minimap_heights(&terrain, 160);
+        assert_eq!(heights.len(), 160);
+        assert_eq!(heights[..3], [0.0, 5.0, 10.0], "Every fifth point");
+        assert_eq!(heights[159], 795.0);
+
+        assert_eq!(minimap_heights(&[3.0, 4.0], 160), vec![3.0, 4.0], "Narrow terrain is kept whole");
+        assert!(minimap_heights(&[], 160).is_empty());
+    }
+
+    #[test]
+    fn test_fuel_bar_color() {
+        assert_eq!(fuel_bar_color(80.0), GREEN);
+        assert_eq!(fuel_bar_color(50.0), YELLOW);
+        assert_eq!(fuel_bar_color(25.0), YELLOW, "Same red line as the live fuel readout");
+        assert_eq!(fuel_bar_color(24.9), RED);
+        assert_eq!(fuel_bar_color(0.0), RED);
+    }
+
+    #[test]
+    fn test_leg_deployment() {
+        assert_eq!(leg_deployment(200.0), 0.0, "Stowed while high up");
+        assert_eq!(leg_deployment(LEG_DEPLOY_START_ALTITUDE), 0.0);
+        assert_eq!(leg_deployment(LEG_DEPLOY_FULL_ALTITUDE), 1.0);
+        assert_eq!(leg_deployment(0.0), 1.0, "Down and locked on the ground");
+
+        // Eases through the halfway point and only ever extends on the way down
+        let mid = (LEG_DEPLOY_START_ALTITUDE + LEG_DEPLOY_FULL_ALTITUDE) / 2.0;
+        assert!((leg_deployment(mid) - 0.5).abs() < 1e-6);
+        let mut previous = 0.0;
+        for altitude in (0..=100).rev() {
+            let deployment = leg_deployment(altitude as f32);
+            assert!(deployment >= previous);
+            previous = deployment;
+        }
+    }
+
+    #[test]
+    fn test_flame_ground_contact() {
+        // Ground rising at 45° under the lander
+        let terrain: Vec<f64> = (0..200).map(|x| x as f64).collect();
+        let down = vec2(0.0, -1.0);
+
+        // Nozzle 10 above the surface, 24-long flame: clipped at the ground
+        let splash = flame_ground_contact(vec2(100.0, 110.0), down, 24.0, &terrain, 200.0).unwrap();
+        assert_eq!(splash.contact, vec2(100.0, 100.0));
+        assert_eq!(splash.flame_length, 10.0);
+        assert_eq!(splash.spread, 14.0);
+        assert!((splash.tangent - vec2(1.0, 1.0).normalize()).length() < 1e-5);
+        assert!((splash.normal - vec2(-1.0, 1.0).normalize()).length() < 1e-5);
+
+        // Too high to reach, or thrusting sideways
+        assert!(flame_ground_contact(vec2(100.0, 200.0), down, 24.0, &terrain, 200.0).is_none());
+        assert!(flame_ground_contact(vec2(100.0, 110.0), vec2(1.0, 0.0), 24.0, &terrain, 200.0).is_none());
+    }
+
+    #[test]
+    fn test_zone_label_anchor_above_zone_center() {
+        // Terrain rises linearly so each zone center has a distinct height
+        let terrain: Vec<f64> = (0..800).map(|i| 50.0 + i as f64 * 0.1).collect();
+        let screen_height = 600.0;
+
+        let zones = [
+            LandingZone { start: 100, end: 131, difficulty: LandingZoneDifficulty::Hard, width_points: 32, motion: None },
+            LandingZone { start: 300, end: 339, difficulty: LandingZoneDifficulty::Medium, width_points: 40, motion: None },
+            LandingZone { start: 500, end: 547, difficulty: LandingZoneDifficulty::Easy, width_points: 48, motion: None },
+        ];
+
+        for zone in &zones {
+            let anchor = zone_label_anchor(zone, &terrain, 800.0, screen_height, 25.0);
+
+            // Horizontally centered on the zone regardless of its width
+            assert_eq!(anchor.x, (zone.start + zone.end) as f32 / 2.0);
+            assert!(anchor.x > zone.start as f32 && anchor.x < zone.end as f32);
+
+            // Terrain in screen coordinates; the label must sit above it (smaller Y)
+            let terrain_screen_y = screen_height - terrain[anchor.x as usize] as f32;
+            assert!((terrain_screen_y - anchor.y - 25.0).abs() < 0.001,
+                    "Label should be 25px above terrain, got {}", terrain_screen_y - anchor.y);
+        }
+    }
+
+    #[test]
+    fn test_zone_label_anchor_clamps_to_terrain() {
+        // A zone reaching past the end of the terrain must not index out of bounds
+        let terrain = vec![80.0; 100];
+        let zone = LandingZone { start: 90, end: 140, difficulty: LandingZoneDifficulty::Easy, width_points: 50, motion: None };
+
+        let anchor = zone_label_anchor(&zone, &terrain, 100.0, 600.0, 25.0);
+        assert_eq!(anchor.y, 600.0 - 105.0);
+    }
+
+    #[test]
+    fn test_shake_offset_decays() {
+        assert_eq!(shake_offset(0.0, 0.1), Vec2::ZERO, "No shake without an impact");
+        assert_eq!(shake_offset(10.0, SHAKE_DURATION), Vec2::ZERO, "Shake is over");
+
+        for _ in 0..50 {
+            let early = shake_offset(10.0, 0.0);
+            assert!(early.x.abs() <= 10.0 && early.y.abs() <= 10.0);
+            let late = shake_offset(10.0, SHAKE_DURATION * 0.9);
+            assert!(late.x.abs() <= 1.0 + 1e-4 && late.y.abs() <= 1.0 + 1e-4, "Jitter decays: {:?}", late);
+        }
+    }
+
+    #[test]
+    fn test_follow_camera_zoom_and_target() {
+        assert_eq!(follow_zoom(FOLLOW_ZOOM_START_ALTITUDE * 2.0), 1.0);
+        assert_eq!(follow_zoom(0.0), FOLLOW_MAX_ZOOM);
+        let halfway = follow_zoom(FOLLOW_ZOOM_START_ALTITUDE / 2.0);
+        assert!(halfway > 1.0 && halfway < FOLLOW_MAX_ZOOM);
+
+        let screen = vec2(800.0, 600.0);
+        // Unzoomed, the view is pinned to the whole screen like the static camera
+        assert_eq!(follow_target(vec2(100.0, 500.0), 1.0, screen), vec2(400.0, 300.0));
+        // Zoomed 2x, the lander is centered unless that would show past an edge
+        assert_eq!(follow_target(vec2(300.0, 250.0), 2.0, screen), vec2(300.0, 250.0));
+        assert_eq!(follow_target(vec2(50.0, 580.0), 2.0, screen), vec2(200.0, 450.0));
+    }
+}
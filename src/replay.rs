@@ -0,0 +1,210 @@
+//! Attempt recording for instant replay.
+//!
+//! The main loop offers a `ReplayFrame` every frame while the lander flies;
+//! the recorder keeps one per fixed physics step, so the length of attempt it
+//! holds doesn't depend on the frame rate. Once the attempt is over the frames can be played back at their original
+//! pace, drawn instead of the wreck or the landed craft. The terrain is
+//! snapshotted when the attempt starts so the replay shows the ground as it
+//! was before any crater.
+//!
+//! The best successful attempt of a session is kept as a ghost: its frames are
+//! looked up by simulation time (`ghost_frame`) so later attempts fly beside it.
+
+use std::collections::VecDeque;
+
+use macroquad::math::Vec2;
+
+use crate::sim::FIXED_DT;
+
+/// Longest attempt (s) a recording holds before the oldest frames are dropped
+const MAX_RECORDED_SECONDS: f32 = 900.0;
+/// Frames kept per attempt: one per fixed step for `MAX_RECORDED_SECONDS`
+const DEFAULT_CAPACITY: usize = (MAX_RECORDED_SECONDS / FIXED_DT) as usize;
+/// Seconds the final frame is held before the replay loops
+const END_HOLD: f32 = 1.5;
+
+/// Lander state captured for one frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReplayFrame {
+    pub time: f32,       // Seconds since the attempt started (simulation time)
+    pub position: Vec2,  // Lander bottom-left corner in camera coordinates
+    pub rotation: f32,   // Degrees, 0° upright
+    pub thrust: f32,     // Flame level (0.0-1.0), 0 when out of fuel
+    pub velocity_y: f32, // Vertical velocity (m/s), for the flame tint
+}
+
+/// Frames of the current attempt and the terrain it was flown over
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    frames: VecDeque<ReplayFrame>,
+    capacity: usize,
+    terrain: Vec<f64>,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+}
+
+impl Recorder {
+    /// Creates a recorder that keeps at most `capacity` frames, dropping the oldest first.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            frames: VecDeque::with_capacity(capacity.min(DEFAULT_CAPACITY)),
+            capacity: capacity.max(1),
+            terrain: Vec::new(),
+        }
+    }
+
+    /// Discards the previous recording and snapshots the terrain for a new attempt.
+    pub fn start(&mut self, terrain: &[f64]) {
+        self.frames.clear();
+        self.terrain = terrain.to_vec();
+    }
+
+    /// Appends a frame, evicting the oldest one when full.
+    ///
+    /// Simulation time only moves in fixed steps, so a frame no later than the
+    /// last one recorded repeats that step and is skipped.
+    pub fn record(&mut self, frame: ReplayFrame) {
+        if self.frames.back().is_some_and(|last| frame.time <= last.time) {
+            return;
+        }
+        if self.frames.len() == self.capacity {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(frame);
+    }
+
+    /// Number of frames recorded
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Whether nothing has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Copy of the recorded frames, oldest first
+    pub fn frames(&self) -> Vec<ReplayFrame> {
+        self.frames.iter().copied().collect()
+    }
+
+    /// Exchanges the snapshotted terrain with `terrain`.
+    ///
+    /// Called once to show the original ground during playback and again to
+    /// put the current ground back.
+    pub fn swap_terrain(&mut self, terrain: &mut Vec<f64>) {
+        std::mem::swap(&mut self.terrain, terrain);
+    }
+
+    /// Frame to show `elapsed` seconds into a looping playback.
+    ///
+    /// Playback runs at the recorded pace, holds the last frame for a moment
+    /// and then starts over.
+    pub fn frame_at(&self, elapsed: f32) -> Option<ReplayFrame> {
+        let first = self.frames.front()?;
+        let last = self.frames.back()?;
+        let length = last.time - first.time + END_HOLD;
+        let time = first.time + elapsed.max(0.0) % length;
+
+        // Latest frame at or before `time`
+        let index = self.frames.partition_point(|frame| frame.time <= time);
+        self.frames.get(index.saturating_sub(1)).copied()
+    }
+}
+
+/// Ghost frame to show `time` seconds into an attempt.
+///
+/// Unlike a replay the ghost does not loop: it waits at the first frame and
+/// stays at the last one once its attempt is over.
+///
+/// # Arguments
+///
+/// * `frames` - Frames of the recorded attempt, oldest first
+/// * `time` - Simulation time of the attempt being flown
+pub fn ghost_frame(frames: &[ReplayFrame], time: f32) -> Option<ReplayFrame> {
+    let index = frames.partition_point(|frame| frame.time <= time);
+    frames.get(index.saturating_sub(1)).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(time: f32) -> ReplayFrame {
+        ReplayFrame {
+            time,
+            position: Vec2::new(time, 100.0 - time),
+            rotation: 0.0,
+            thrust: 0.5,
+            velocity_y: -1.0,
+        }
+    }
+
+    #[test]
+    fn test_replay_follows_recorded_pace_and_loops() {
+        let mut recorder = Recorder::with_capacity(100);
+        assert_eq!(recorder.frame_at(0.0), None, "Nothing to replay yet");
+
+        recorder.start(&[1.0, 2.0, 3.0]);
+        for i in 0..=20 {
+            recorder.record(frame(i as f32 * 0.1));
+        }
+
+        assert_eq!(recorder.frame_at(0.0), Some(frame(0.0)));
+        assert_eq!(recorder.frame_at(1.05).map(|f| f.time), Some(1.0));
+        assert_eq!(recorder.frame_at(2.5).map(|f| f.time), Some(2.0), "Last frame is held");
+        let length = 2.0 + END_HOLD;
+        assert_eq!(recorder.frame_at(length + 0.55).map(|f| f.time), Some(0.5), "Playback loops");
+
+        // The terrain snapshot comes back unchanged after a round trip
+        let mut terrain = vec![9.0; 3];
+        recorder.swap_terrain(&mut terrain);
+        assert_eq!(terrain, vec![1.0, 2.0, 3.0]);
+        recorder.swap_terrain(&mut terrain);
+        assert_eq!(terrain, vec![9.0; 3]);
+
+        // A new attempt starts from nothing
+        recorder.start(&terrain);
+        assert!(recorder.is_empty());
+    }
+
+    #[test]
+    fn test_ghost_follows_simulation_time() {
+        let mut recorder = Recorder::default();
+        assert_eq!(ghost_frame(&recorder.frames(), 1.0), None);
+
+        for i in 1..=10 {
+            recorder.record(frame(i as f32 * 0.5));
+        }
+        let frames = recorder.frames();
+        assert_eq!(ghost_frame(&frames, 0.0), Some(frame(0.5)), "Waits at the start");
+        assert_eq!(ghost_frame(&frames, 2.2).map(|f| f.time), Some(2.0));
+        assert_eq!(ghost_frame(&frames, 60.0), Some(frame(5.0)), "Stays where it ended");
+    }
+
+    #[test]
+    fn test_recorder_drops_oldest_when_full() {
+        let mut recorder = Recorder::with_capacity(3);
+        for i in 0..5 {
+            recorder.record(frame(i as f32));
+        }
+        assert_eq!(recorder.len(), 3);
+        assert_eq!(recorder.frame_at(0.0).map(|f| f.time), Some(2.0));
+    }
+
+    #[test]
+    fn test_recorder_keeps_one_frame_per_step() {
+        let mut recorder = Recorder::default();
+        // A fast display renders several frames per physics step
+        for step in 0..4 {
+            for _ in 0..3 {
+                recorder.record(frame(step as f32 * FIXED_DT));
+            }
+        }
+        assert_eq!(recorder.len(), 4);
+    }
+}
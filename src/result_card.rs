@@ -0,0 +1,87 @@
+//! Shareable result card for a completed session.
+//!
+//! The card is drawn off-screen into a `RenderTarget` and exported as a PNG in
+//! the working directory, so it looks the same whatever the window size.
+
+use macroquad::prelude::*;
+use macroquad_text::Fonts;
+
+use crate::rendering::draw_attempt_icon;
+use crate::session::{AttemptResult, GameSession};
+
+const CARD_WIDTH: f32 = 420.0;
+const CARD_HEIGHT: f32 = 260.0;
+const CARD_BACKGROUND: Color = Color::new(0.05, 0.06, 0.1, 1.0);
+
+/// Renders the result card for a session and saves it as a PNG.
+///
+/// Must be called before the frame's scene is drawn (the game calls it from
+/// `handle_input`): it draws with its own camera and leaves the default camera
+/// set, which the frame's camera setup then replaces.
+///
+/// # Arguments
+///
+/// * `session` - Completed game session to summarize
+/// * `fonts` - Fonts used for the card text
+///
+/// # Returns
+///
+/// The path of the saved PNG
+pub fn save_result_card(session: &GameSession, fonts: &Fonts) -> String {
+    let target = render_target(CARD_WIDTH as u32, CARD_HEIGHT as u32);
+    target.texture.set_filter(FilterMode::Linear);
+
+    // Y grows downward in card space; with a positive Y zoom the rows come
+    // out of the texture top-first, which is the order PNG expects
+    set_camera(&Camera2D {
+        zoom: vec2(2.0 / CARD_WIDTH, 2.0 / CARD_HEIGHT),
+        target: vec2(CARD_WIDTH / 2.0, CARD_HEIGHT / 2.0),
+        render_target: Some(target.clone()),
+        ..Default::default()
+    });
+    draw_card(session, fonts);
+
+    // Switching camera flushes the card's draw calls into the render target
+    set_default_camera();
+
+    let path = format!("lunar_lander_result_{}.png", macroquad::miniquad::date::now() as u64);
+    target.texture.get_texture_data().export_png(&path);
+    info!("Saved result card to {}", path);
+    path
+}
+
+/// Lays out the card in card space (origin top-left, Y down).
+fn draw_card(session: &GameSession, fonts: &Fonts) {
+    clear_background(CARD_BACKGROUND);
+    draw_rectangle_lines(4.0, 4.0, CARD_WIDTH - 8.0, CARD_HEIGHT - 8.0, 2.0, GRAY);
+
+    fonts.draw_text("LUNAR LANDER - MISSION REPORT", 20.0, 16.0, 18.0, WHITE);
+
+    let score_text = format!("TOTAL SCORE: {:.0}", session.total_score);
+    fonts.draw_text(&score_text, 20.0, 50.0, 16.0, WHITE);
+    let rating_text = format!("RATING: {}", session.performance_rating());
+    fonts.draw_text(&rating_text, 20.0, 72.0, 16.0, GOLD);
+    let landed_text = format!("LANDED: {}/{}", session.success_count(), session.max_attempts);
+    fonts.draw_text(&landed_text, 240.0, 50.0, 16.0, WHITE);
+
+    // One row per attempt: icon, outcome and details
+    for (i, attempt) in session.attempts.iter().enumerate() {
+        let row_y = 120.0 + i as f32 * 40.0;
+        draw_attempt_icon(36.0, row_y, 11.0, &attempt.result, false);
+
+        let detail = match attempt.result {
+            AttemptResult::Success => format!(
+                "{} / {}  score {:.0}  fuel {:.0}%  {:.1}s",
+                attempt.landing_zone.as_ref().map(|zone| zone.name()).unwrap_or("-"),
+                attempt.grade.map(|grade| grade.letter()).unwrap_or("-"),
+                attempt.score,
+                attempt.fuel_remaining,
+                attempt.time_taken
+            ),
+            AttemptResult::Failure => format!("Lost  fuel {:.0}%  {:.1}s", attempt.fuel_remaining, attempt.time_taken),
+            AttemptResult::InProgress => "Not flown".to_string(),
+        };
+        fonts.draw_text(&format!("ATTEMPT {}", i + 1), 60.0, row_y - 10.0, 14.0, LIGHTGRAY);
+        fonts.draw_text(&detail, 160.0, row_y - 10.0, 14.0, WHITE);
+    }
+}
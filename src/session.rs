@@ -5,10 +5,33 @@
 //! - Comprehensive scoring system based on zone difficulty and fuel efficiency
 //! - Session state management and progression
 //! - Performance analysis and session summaries
+//! - High score list of completed sessions, the best landing per zone
+//!   difficulty and body, and today's daily challenge best, kept in `SCORES_FILE`
 
-use macroquad::logging::info;
+use std::fs;
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use macroquad::logging::{info, warn};
+use macroquad::math::Vec2;
+use serde::{Deserialize, Serialize};
+
+use crate::body::CelestialBody;
+use crate::replay::ReplayFrame;
+use crate::stats::LifetimeStats;
 use crate::surface::LandingZoneDifficulty;
 
+/// Largest pinpoint bonus, as a fraction of the score, for a dead-center touchdown
+const MAX_PINPOINT_BONUS: f32 = 0.5;
+/// Landings at or under this time (seconds) earn the full time bonus
+const FAST_LANDING_TIME: f32 = 30.0;
+/// Time (seconds) at which the time bonus has tapered to 1.0
+const PAR_LANDING_TIME: f32 = 90.0;
+/// Largest time bonus, for a landing at or under `FAST_LANDING_TIME`
+const MAX_TIME_BONUS: f32 = 1.3;
+/// Smallest time multiplier, reached one par time past `PAR_LANDING_TIME`
+const MIN_TIME_BONUS: f32 = 0.9;
+
 /// Represents the result of a single landing attempt
 #[derive(Debug, Clone, PartialEq)]
 pub enum AttemptResult {
@@ -17,6 +40,68 @@ pub enum AttemptResult {
     InProgress,
 }
 
+impl AttemptResult {
+    /// Name used in exported statistics
+    pub fn name(&self) -> &'static str {
+        match self {
+            AttemptResult::Success => "Success",
+            AttemptResult::Failure => "Failure",
+            AttemptResult::InProgress => "In progress",
+        }
+    }
+}
+
+/// Letter grade for how comfortably a successful landing met the criteria
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LandingGrade {
+    A,
+    B,
+    C,
+    D,
+    F,
+}
+
+impl LandingGrade {
+    /// Grades a landing from its margins against the landing limits.
+    ///
+    /// Each margin runs from 0.0 (right at the limit) to 1.0 (ideal): dead
+    /// center of the zone, zero touchdown speed, perfectly level. The grade
+    /// follows the tightest of the three, so one near-miss caps the grade.
+    ///
+    /// # Grade Thresholds
+    ///
+    /// - **A**: every margin at least 0.8
+    /// - **B**: at least 0.6
+    /// - **C**: at least 0.4
+    /// - **D**: at least 0.2
+    /// - **F**: scraped in at the limit of at least one criterion
+    pub fn from_margins(centering: f32, speed: f32, angle: f32) -> Self {
+        let margin = centering.min(speed).min(angle);
+        if margin >= 0.8 {
+            LandingGrade::A
+        } else if margin >= 0.6 {
+            LandingGrade::B
+        } else if margin >= 0.4 {
+            LandingGrade::C
+        } else if margin >= 0.2 {
+            LandingGrade::D
+        } else {
+            LandingGrade::F
+        }
+    }
+
+    /// Returns the grade letter for display
+    pub fn letter(&self) -> &'static str {
+        match self {
+            LandingGrade::A => "A",
+            LandingGrade::B => "B",
+            LandingGrade::C => "C",
+            LandingGrade::D => "D",
+            LandingGrade::F => "F",
+        }
+    }
+}
+
 /// Details of a single landing attempt within a game session
 #[derive(Debug, Clone)]
 pub struct LandingAttempt {
@@ -25,6 +110,7 @@ pub struct LandingAttempt {
     pub fuel_remaining: f32,  // Percentage (0-100)
     pub landing_zone: Option<LandingZoneDifficulty>,
     pub time_taken: f32,      // Time in seconds
+    pub grade: Option<LandingGrade>, // Landing quality, successful attempts only
 }
 
 impl LandingAttempt {
@@ -36,6 +122,7 @@ impl LandingAttempt {
             fuel_remaining: 0.0,
             landing_zone: None,
             time_taken: 0.0,
+            grade: None,
         }
     }
 
@@ -43,11 +130,12 @@ impl LandingAttempt {
     pub fn new_completed(
         result: AttemptResult,
         zone_difficulty: Option<LandingZoneDifficulty>,
+        edge_distances: Option<(f32, f32)>,
         fuel_remaining_percent: f32,
         time_taken: f32,
     ) -> Self {
         let score = if result == AttemptResult::Success && zone_difficulty.is_some() {
-            Self::calculate_score(zone_difficulty.unwrap(), fuel_remaining_percent, time_taken)
+            Self::calculate_score(zone_difficulty.unwrap(), edge_distances, fuel_remaining_percent, time_taken)
         } else {
             0.0
         };
@@ -58,6 +146,7 @@ impl LandingAttempt {
             fuel_remaining: fuel_remaining_percent,
             landing_zone: zone_difficulty,
             time_taken,
+            grade: None,
         }
     }
 
@@ -65,18 +154,23 @@ impl LandingAttempt {
     ///
     /// # Scoring Formula
     /// 
-    /// `Score = Base_Points × Zone_Multiplier × Fuel_Bonus × Time_Bonus`
+    /// `Score = Base_Points × Zone_Multiplier × Pinpoint_Bonus × Fuel_Bonus × Time_Bonus`
     ///
     /// - **Base Points**: 1000
     /// - **Zone Multiplier**: 2.0 (Hard), 1.6 (Medium), 1.3 (Easy)
+    /// - **Pinpoint Bonus**: up to 1.5 for a centered touchdown (see `pinpoint_bonus`),
+    ///   1.0 when `edge_distances` is `None`
     /// - **Fuel Bonus**: 1.0 + (fuel_remaining / 100) - rewards fuel conservation
-    /// - **Time Bonus**: 1.2 if completed under 60 seconds, 1.0 otherwise
+    /// - **Time Bonus**: 1.3 at 30 seconds or less, tapering to 1.0 at 90 seconds
+    ///   and down to 0.9 at 180 seconds or more (see `time_bonus`)
     ///
     /// # Examples
-    /// - Hard zone + 50% fuel + fast = 1000 × 2.0 × 1.5 × 1.2 = 3600 points
-    /// - Easy zone + 80% fuel + slow = 1000 × 1.3 × 1.8 × 1.0 = 2340 points
+    /// - Hard zone + 50% fuel + 30 s = 1000 × 2.0 × 1.5 × 1.3 = 3900 points
+    /// - Easy zone + 80% fuel + 90 s = 1000 × 1.3 × 1.8 × 1.0 = 2340 points
+    /// - The same landings dead center score 5850 and 3510 points
     pub fn calculate_score(
         zone_difficulty: LandingZoneDifficulty,
+        edge_distances: Option<(f32, f32)>,
         fuel_remaining_percent: f32,
         time_taken: f32,
     ) -> f32 {
@@ -84,15 +178,59 @@ impl LandingAttempt {
         
         // Zone difficulty multiplier (from existing scoring system)
         let zone_multiplier = zone_difficulty.score(); // 2.0, 1.6, 1.3
+
+        // Pinpoint bonus: 1.0 at a zone edge up to 1.5 dead center
+        let pinpoint_bonus = edge_distances.map_or(1.0, |(left, right)| Self::pinpoint_bonus(left, right));
         
         // Fuel efficiency bonus: 1.0 to 2.0 based on fuel remaining
         // More fuel remaining = higher bonus
         let fuel_bonus = 1.0 + (fuel_remaining_percent / 100.0);
         
-        // Time bonus: reward fast completion (under 60 seconds)
-        let time_bonus = if time_taken < 60.0 { 1.2 } else { 1.0 };
+        // Time bonus: 1.3 for fast landings down to 0.9 for slow ones
+        let time_bonus = Self::time_bonus(time_taken);
         
-        base_points * zone_multiplier * fuel_bonus * time_bonus
+        base_points * zone_multiplier * pinpoint_bonus * fuel_bonus * time_bonus
+    }
+
+    /// Score multiplier for how well a touchdown was centered in its zone.
+    ///
+    /// # Arguments
+    ///
+    /// * `distance_from_left` - Slack between the lander and the zone's left edge (terrain points)
+    /// * `distance_from_right` - Slack between the lander and the zone's right edge (terrain points)
+    ///
+    /// # Returns
+    ///
+    /// `1.5` for equal distances, tapering linearly to `1.0` with the lander at an edge;
+    /// a zone exactly as wide as the lander counts as centered
+    pub fn pinpoint_bonus(distance_from_left: f32, distance_from_right: f32) -> f32 {
+        let slack = distance_from_left + distance_from_right;
+        let centering = if slack > 0.0 {
+            1.0 - (distance_from_left - distance_from_right).abs() / slack
+        } else {
+            1.0
+        };
+        1.0 + MAX_PINPOINT_BONUS * centering.clamp(0.0, 1.0)
+    }
+
+    /// Score multiplier for how quickly a landing was completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - Time taken for the attempt in seconds
+    ///
+    /// # Returns
+    ///
+    /// `1.3` up to 30 seconds, falling linearly to `1.0` at 90 seconds, then more
+    /// gently to a floor of `0.9` from 180 seconds
+    pub fn time_bonus(time: f32) -> f32 {
+        if time <= PAR_LANDING_TIME {
+            let t = ((time - FAST_LANDING_TIME) / (PAR_LANDING_TIME - FAST_LANDING_TIME)).clamp(0.0, 1.0);
+            MAX_TIME_BONUS * (1.0 - t) + t
+        } else {
+            let t = ((time - PAR_LANDING_TIME) / PAR_LANDING_TIME).min(1.0);
+            (1.0 - t) + MIN_TIME_BONUS * t
+        }
     }
 }
 
@@ -104,6 +242,14 @@ pub struct GameSession {
     pub attempts: Vec<LandingAttempt>, // Results of each attempt
     pub total_score: f32,              // Cumulative score across all attempts
     pub session_complete: bool,        // True after all attempts finished
+    pub attempt_sites: Vec<(Vec2, AttemptResult)>, // Touchdown/crash sites of prior attempts
+    pub consumed_zones: Vec<LandingZoneDifficulty>, // Zone difficulties already landed on (single-use mode)
+}
+
+impl Default for GameSession {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl GameSession {
@@ -119,6 +265,8 @@ impl GameSession {
             ],
             total_score: 0.0,
             session_complete: false,
+            attempt_sites: Vec::new(),
+            consumed_zones: Vec::new(),
         }
     }
 
@@ -158,6 +306,11 @@ impl GameSession {
         }
     }
 
+    /// Checks if a zone difficulty has already been used up this session
+    pub fn is_zone_consumed(&self, difficulty: LandingZoneDifficulty) -> bool {
+        self.consumed_zones.contains(&difficulty)
+    }
+
     /// Gets a performance rating based on session results
     pub fn performance_rating(&self) -> &'static str {
         let success_count = self.success_count();
@@ -174,11 +327,212 @@ impl GameSession {
             _ => "UNKNOWN"
         }
     }
+
+    /// Formats the completed attempts as CSV, one row per attempt.
+    ///
+    /// The header row is always present. Attempts without a landing zone
+    /// leave the zone column empty.
+    pub fn export_csv(&self) -> String {
+        let mut csv = String::from(SESSION_CSV_HEADER);
+        csv.push('\n');
+        for attempt in self.attempts.iter().filter(|attempt| attempt.result != AttemptResult::InProgress) {
+            csv.push_str(&format!(
+                "{},{:.0},{:.1},{},{:.2}\n",
+                attempt.result.name(),
+                attempt.score,
+                attempt.fuel_remaining,
+                attempt.landing_zone.as_ref().map_or("", |zone| zone.name()),
+                attempt.time_taken
+            ));
+        }
+        csv
+    }
+}
+
+/// File the high score list is kept in (JSON, working directory)
+pub const SCORES_FILE: &str = "scores.json";
+/// CSV header matching the columns of `GameSession::export_csv`
+const SESSION_CSV_HEADER: &str = "result,score,fuel_remaining,landing_zone,time_taken";
+/// Completed sessions kept in the high score list
+const MAX_HIGH_SCORES: usize = 10;
+
+/// A completed session as stored in the high score list
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionRecord {
+    pub date: String,     // Completion date, YYYY-MM-DD (UTC)
+    pub total_score: f32, // Session total score
+    pub successes: usize, // Successful landings out of the session's attempts
+    pub rating: String,   // Performance rating at completion
+}
+
+impl SessionRecord {
+    /// Summarizes a completed session.
+    pub fn from_session(session: &GameSession, date: String) -> Self {
+        Self {
+            date,
+            total_score: session.total_score,
+            successes: session.success_count(),
+            rating: session.performance_rating().to_string(),
+        }
+    }
+}
+
+/// Best single landing on one zone difficulty and body
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BestLanding {
+    pub zone: LandingZoneDifficulty, // Zone difficulty landed on
+    pub body: CelestialBody,         // Body the landing was flown on
+    pub score: f32,                  // Attempt score
+    pub date: String,                // Date it was set, YYYY-MM-DD (UTC)
+}
+
+/// Best session score on one day's daily challenge
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyBest {
+    pub date: String,     // Challenge date, YYYY-MM-DD (UTC)
+    pub total_score: f32, // Best session total flown on it
+}
+
+/// Everything kept in the high score file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HighScores {
+    pub sessions: Vec<SessionRecord>,   // Best completed sessions, highest score first
+    pub best_landings: Vec<BestLanding>, // Best landing per (zone difficulty, body) pair
+    pub daily_best: Option<DailyBest>,  // Best daily challenge session of the latest day played
+}
+
+impl HighScores {
+    /// Record for landing on `zone` on `body`, if one has been set
+    pub fn best_landing(&self, zone: LandingZoneDifficulty, body: CelestialBody) -> Option<&BestLanding> {
+        self.best_landings.iter().find(|record| record.zone == zone && record.body == body)
+    }
+
+    /// Keeps a landing if it beats the record for its zone difficulty and body.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the landing set a new record
+    pub fn record_landing(&mut self, zone: LandingZoneDifficulty, body: CelestialBody, score: f32, date: String) -> bool {
+        let landing = BestLanding { zone, body, score, date };
+        match self.best_landings.iter_mut().find(|record| record.zone == zone && record.body == body) {
+            Some(record) if record.score >= score => false,
+            Some(record) => {
+                *record = landing;
+                true
+            }
+            None => {
+                self.best_landings.push(landing);
+                true
+            }
+        }
+    }
+
+    /// Best daily challenge session for `date`, if one has been completed
+    pub fn daily_best_on(&self, date: &str) -> Option<f32> {
+        self.daily_best.as_ref().filter(|best| best.date == date).map(|best| best.total_score)
+    }
+
+    /// Keeps a daily challenge session if it beats `date`'s best; a new day replaces the old record.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the session set a new daily best
+    pub fn record_daily(&mut self, date: &str, total_score: f32) -> bool {
+        if self.daily_best_on(date).is_some_and(|best| best >= total_score) {
+            return false;
+        }
+        self.daily_best = Some(DailyBest { date: date.to_string(), total_score });
+        true
+    }
+}
+
+/// Layouts the high score file has had
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ScoreFile {
+    Flat(Vec<SessionRecord>), // Before best landings were kept: the session list alone
+    Current(HighScores),
+}
+
+/// Today's UTC calendar date (YYYY-MM-DD).
+pub fn today() -> String {
+    let unix_seconds = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs());
+    civil_date(unix_seconds)
+}
+
+/// Terrain seed of the daily challenge for `date`.
+///
+/// FNV-1a over the date string, so every build and platform derives the same
+/// layout for the same day.
+pub fn daily_seed(date: &str) -> u64 {
+    date.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// Formats a Unix timestamp as a UTC calendar date (YYYY-MM-DD).
+fn civil_date(unix_seconds: u64) -> String {
+    // Days since 1970-01-01 shifted to an era starting in March, 0000
+    let days = (unix_seconds / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153; // 0 = March
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Reads the high scores, starting empty if the file is missing or unreadable.
+///
+/// A file from before best landings were kept holds just the session list;
+/// it loads with no best landings and is rewritten in the current layout on
+/// the next save.
+fn read_high_scores(path: &str) -> HighScores {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return HighScores::default(),
+        Err(err) => {
+            warn!("Could not read {}: {} - starting with no high scores", path, err);
+            return HighScores::default();
+        }
+    };
+    match serde_json::from_str(&text) {
+        Ok(ScoreFile::Flat(sessions)) => HighScores { sessions, ..HighScores::default() },
+        Ok(ScoreFile::Current(high_scores)) => high_scores,
+        Err(err) => {
+            warn!("Ignoring corrupt high score file {}: {}", path, err);
+            HighScores::default()
+        }
+    }
+}
+
+/// Writes the high scores as pretty-printed JSON.
+fn write_high_scores(path: &str, high_scores: &HighScores) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(high_scores).map_err(io::Error::other)?;
+    fs::write(path, json)
 }
 
 /// Manages game session state and progression
 pub struct SessionManager {
-    pub session: GameSession,
+    pub session: GameSession,   // Session of the player at the controls
+    pub active_player: usize,   // Index of the player at the controls (always 0 in single-player)
+    pub waiting_sessions: Vec<GameSession>, // Hotseat: the other players' sessions, next in turn first
+    pub single_use_zones: bool, // Each zone difficulty scores only once per session
+    pub practice: bool,         // Practice mode: attempts are neither scored nor counted
+    pub body: CelestialBody,    // Body the active player is flying on, for the lifetime stats
+    pub lifetime: LifetimeStats, // Totals across every session played
+    pub high_scores: HighScores, // Best completed sessions, best landings and daily best
+    pub daily: Option<String>,   // Date of the daily challenge being played, if any
+    pub best_replay: Option<Vec<ReplayFrame>>, // Flight of the best landing by any player, shown as a ghost
+    pub best_replay_seed: u64,  // Terrain seed the best landing was flown over
+}
+
+impl Default for SessionManager {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SessionManager {
@@ -186,34 +540,190 @@ impl SessionManager {
     pub fn new() -> Self {
         Self {
             session: GameSession::new(),
+            active_player: 0,
+            waiting_sessions: Vec::new(),
+            single_use_zones: false,
+            practice: false,
+            body: CelestialBody::default(),
+            lifetime: LifetimeStats::default(),
+            high_scores: HighScores::default(),
+            daily: None,
+            best_replay: None,
+            best_replay_seed: 0,
+        }
+    }
+
+    /// Sets how many players take turns, each with a session of their own.
+    ///
+    /// Starts every player on a fresh session with player 1 at the controls;
+    /// one player (the default) is the ordinary single-player game.
+    ///
+    /// # Arguments
+    ///
+    /// * `players` - Number of players, at least 1
+    pub fn set_player_count(&mut self, players: usize) {
+        self.waiting_sessions = (1..players.max(1)).map(|_| GameSession::new()).collect();
+        self.session = GameSession::new();
+        self.active_player = 0;
+    }
+
+    /// Number of players taking turns
+    pub fn player_count(&self) -> usize {
+        self.waiting_sessions.len() + 1
+    }
+
+    /// Whether several players are taking turns at the controls
+    pub fn is_hotseat(&self) -> bool {
+        !self.waiting_sessions.is_empty()
+    }
+
+    /// Session of a given player
+    ///
+    /// # Arguments
+    /// * `player` - Player index, 0-based
+    pub fn session_of(&self, player: usize) -> &GameSession {
+        if player == self.active_player {
+            &self.session
+        } else {
+            // Waiting sessions run in turn order starting after the active player
+            let count = self.player_count();
+            &self.waiting_sessions[(player + count - self.active_player - 1) % count]
         }
     }
 
+    /// Label naming whose turn it is, for hotseat games only
+    pub fn turn_label(&self) -> Option<String> {
+        self.is_hotseat().then(|| format!("PLAYER {}", self.active_player + 1))
+    }
+
+    /// Player who flies next: the first one after the active player, in turn
+    /// order, with attempts left (the active player again once the rest are done)
+    pub fn next_player(&self) -> Option<usize> {
+        let count = self.player_count();
+        (1..=count)
+            .map(|step| (self.active_player + step) % count)
+            .find(|&player| !self.session_of(player).session_complete)
+    }
+
+    /// Hands the controls to `next_player`, if there is one.
+    ///
+    /// # Returns
+    ///
+    /// `true` when a different player is now at the controls
+    pub fn pass_turn(&mut self) -> bool {
+        let Some(next) = self.next_player() else {
+            return false;
+        };
+        let previous = self.active_player;
+        while self.active_player != next {
+            let incoming = self.waiting_sessions.remove(0);
+            let outgoing = std::mem::replace(&mut self.session, incoming);
+            self.waiting_sessions.push(outgoing);
+            self.active_player = (self.active_player + 1) % self.player_count();
+        }
+        self.active_player != previous
+    }
+
+    /// Whether every player's session is over
+    pub fn is_complete(&self) -> bool {
+        self.session.session_complete && self.waiting_sessions.iter().all(|session| session.session_complete)
+    }
+
+    /// Loads the high scores from `SCORES_FILE`.
+    ///
+    /// A missing or corrupt file gives no records.
+    pub fn load_high_scores() -> HighScores {
+        read_high_scores(SCORES_FILE)
+    }
+
+    /// Writes the high scores, including the session just completed, to `SCORES_FILE`.
+    pub fn save_session(&self) -> io::Result<()> {
+        write_high_scores(SCORES_FILE, &self.high_scores)
+    }
+
+    /// Writes the current session's attempts to a CSV file (see `GameSession::export_csv`).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Destination file, overwritten if it exists
+    pub fn save_csv(&self, path: &str) -> io::Result<()> {
+        fs::write(path, self.session.export_csv())
+    }
+
+    /// Highest session score on record, if any session has been completed
+    pub fn best_score(&self) -> Option<f32> {
+        self.high_scores.sessions.first().map(|record| record.total_score)
+    }
+
+    /// Adds a completed session to the high score list, keeping the best `MAX_HIGH_SCORES`.
+    fn record_high_score(&mut self, record: SessionRecord) {
+        let sessions = &mut self.high_scores.sessions;
+        sessions.push(record);
+        sessions.sort_by(|a, b| b.total_score.total_cmp(&a.total_score));
+        sessions.truncate(MAX_HIGH_SCORES);
+    }
+
     /// Completes the current attempt and updates session state
     ///
     /// # Arguments
     /// * `result` - Whether the attempt succeeded or failed
     /// * `fuel_remaining` - Fuel remaining percentage (0-100)
     /// * `zone` - Landing zone difficulty if successful
+    /// * `edge_distances` - Slack to the zone's left and right edges, for the pinpoint bonus
     /// * `time` - Time taken for the attempt in seconds
+    ///
+    /// In single-use zone mode, a successful landing consumes its zone difficulty;
+    /// landing again on a consumed difficulty still counts as a success but scores nothing.
+    /// In practice mode the attempt is only logged; the session is left untouched.
     pub fn complete_attempt(
         &mut self,
         result: AttemptResult,
         fuel_remaining: f32,
         zone: Option<LandingZoneDifficulty>,
+        edge_distances: Option<(f32, f32)>,
         time: f32,
     ) {
+        if self.practice {
+            info!("Practice run: {:?}, {:.1}% fuel, {:.1}s", result, fuel_remaining, time);
+            return;
+        }
+
         // Create completed attempt with calculated score
-        let attempt = LandingAttempt::new_completed(result, zone, fuel_remaining, time);
+        let mut attempt = LandingAttempt::new_completed(result, zone, edge_distances, fuel_remaining, time);
+
+        if self.single_use_zones && attempt.result == AttemptResult::Success {
+            if let Some(difficulty) = zone {
+                if self.session.is_zone_consumed(difficulty) {
+                    info!("{} zone already used this session - no score", difficulty.name());
+                    attempt.score = 0.0;
+                } else {
+                    self.session.consumed_zones.push(difficulty);
+                }
+            }
+        }
         
         // Update session state
         self.session.attempts[self.session.current_attempt] = attempt.clone();
         self.session.total_score += attempt.score;
         self.session.current_attempt += 1;
         
+        self.lifetime.record_attempt(&attempt, self.body);
+        if let (Some(difficulty), true) = (zone, attempt.score > 0.0) {
+            if self.high_scores.record_landing(difficulty, self.body, attempt.score, today()) {
+                info!("New best {} landing on {}: {:.0}", difficulty.name(), self.body.name(), attempt.score);
+            }
+        }
+
         // Check if session is complete
         if self.session.current_attempt >= self.session.max_attempts {
             self.session.session_complete = true;
+            self.lifetime.sessions_played += 1;
+            self.record_high_score(SessionRecord::from_session(&self.session, today()));
+            if let Some(date) = &self.daily {
+                if self.high_scores.record_daily(date, self.session.total_score) {
+                    info!("New daily challenge best for {}: {:.0}", date, self.session.total_score);
+                }
+            }
         }
 
         // Debug output for attempt completion
@@ -226,14 +736,73 @@ impl SessionManager {
         );
     }
 
+    /// Records where an attempt ended so it can be marked on the terrain
+    ///
+    /// # Arguments
+    /// * `position` - Bottom-center of the lander in camera coordinates
+    /// * `result` - Outcome of the attempt at that site
+    pub fn record_attempt_site(&mut self, position: Vec2, result: AttemptResult) {
+        if self.practice {
+            return;
+        }
+        self.session.attempt_sites.push((position, result));
+    }
+
+    /// Records the landing grade of the attempt that just completed
+    ///
+    /// # Arguments
+    /// * `grade` - Quality grade of the successful landing
+    pub fn record_landing_grade(&mut self, grade: LandingGrade) {
+        if let Some(attempt) = self.session.current_attempt.checked_sub(1).and_then(|i| self.session.attempts.get_mut(i)) {
+            info!("Landing grade: {}", grade.letter());
+            attempt.grade = Some(grade);
+        }
+    }
+
+    /// Keeps the flight of the attempt that just completed if it is the best landing so far.
+    ///
+    /// In a hotseat game the ghost is shared, so the landing has to beat every
+    /// player's best, not just the active player's.
+    ///
+    /// # Arguments
+    /// * `frames` - Recorded frames of the attempt
+    /// * `terrain_seed` - Seed of the terrain it was flown over; the ghost only shows over the same terrain
+    pub fn record_best_replay(&mut self, frames: Vec<ReplayFrame>, terrain_seed: u64) {
+        let Some(latest) = self.session.current_attempt.checked_sub(1).and_then(|i| self.session.attempts.get(i)) else {
+            return;
+        };
+        let best_score = std::iter::once(&self.session)
+            .chain(&self.waiting_sessions)
+            .filter_map(GameSession::best_attempt)
+            .map(|best| best.score)
+            .fold(f32::MIN, f32::max);
+        let is_best = latest.result == AttemptResult::Success && best_score <= latest.score;
+        if is_best && !frames.is_empty() {
+            self.best_replay = Some(frames);
+            self.best_replay_seed = terrain_seed;
+        }
+    }
+
+    /// Flight of the best landing so far, if it was flown over the terrain with `terrain_seed`
+    pub fn ghost_for(&self, terrain_seed: u64) -> Option<&[ReplayFrame]> {
+        self.best_replay.as_deref().filter(|_| self.best_replay_seed == terrain_seed)
+    }
+
     /// Checks if there are more attempts available in this session
+    ///
+    /// In a hotseat game this covers every player's session, not just the active one.
     pub fn can_start_next_attempt(&self) -> bool {
-        !self.session.session_complete && self.session.current_attempt < self.session.max_attempts
+        std::iter::once(&self.session)
+            .chain(&self.waiting_sessions)
+            .any(|session| !session.session_complete && session.current_attempt < session.max_attempts)
     }
 
     /// Resets to a new session (start over with 3 fresh attempts)
+    ///
+    /// Every hotseat player starts over too, with player 1 back at the controls.
     pub fn reset_session(&mut self) {
-        self.session = GameSession::new();
+        self.set_player_count(self.player_count());
+        self.best_replay = None;
         info!("New game session started");
     }
 
@@ -257,20 +826,57 @@ mod tests {
         // Test hard zone with high fuel efficiency
         let score = LandingAttempt::calculate_score(
             LandingZoneDifficulty::Hard,
+            None,
             80.0, // 80% fuel remaining
-            45.0  // Under 60 seconds
+            45.0  // A quarter of the way from 30 to 90 seconds
         );
-        // Expected: 1000 * 2.0 * 1.8 * 1.2 = 4320
-        assert_eq!(score, 4320.0);
+        // Expected: 1000 * 2.0 * 1.8 * 1.225 = 4410
+        assert!((score - 4410.0).abs() < 0.1);
 
         // Test easy zone with low fuel efficiency
         let score = LandingAttempt::calculate_score(
             LandingZoneDifficulty::Easy,
+            None,
             20.0, // 20% fuel remaining
-            75.0  // Over 60 seconds
+            75.0  // Three quarters of the way from 30 to 90 seconds
         );
-        // Expected: 1000 * 1.333... * 1.2 * 1.0 ≈ 1600
-        assert!((score - 1600.0).abs() < 10.0);
+        // Expected: 1000 * 1.333... * 1.2 * 1.075 = 1720
+        assert!((score - 1720.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_time_bonus() {
+        assert_eq!(LandingAttempt::time_bonus(0.0), 1.3);
+        assert_eq!(LandingAttempt::time_bonus(30.0), 1.3);
+        assert_eq!(LandingAttempt::time_bonus(90.0), 1.0);
+        assert_eq!(LandingAttempt::time_bonus(180.0), 0.9);
+        assert_eq!(LandingAttempt::time_bonus(600.0), 0.9, "Slow landings bottom out");
+
+        // No cliff anywhere: the bonus only ever falls as time goes on
+        let bonuses: Vec<f32> = (0..=400).map(|step| LandingAttempt::time_bonus(step as f32 * 0.5)).collect();
+        assert!(bonuses.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert!(LandingAttempt::time_bonus(59.0) - LandingAttempt::time_bonus(61.0) < 0.02);
+    }
+
+    #[test]
+    fn test_pinpoint_bonus() {
+        assert_eq!(LandingAttempt::pinpoint_bonus(20.0, 20.0), 1.5);
+        assert_eq!(LandingAttempt::pinpoint_bonus(0.0, 40.0), 1.0, "Touching the left edge");
+        assert_eq!(LandingAttempt::pinpoint_bonus(30.0, 10.0), 1.25);
+        assert_eq!(LandingAttempt::pinpoint_bonus(0.0, 0.0), 1.5, "Zone exactly as wide as the lander");
+
+        // Centered beats the edge for an otherwise identical landing
+        let centered = LandingAttempt::calculate_score(LandingZoneDifficulty::Hard, Some((20.0, 20.0)), 50.0, 30.0);
+        let edge = LandingAttempt::calculate_score(LandingZoneDifficulty::Hard, Some((0.0, 40.0)), 50.0, 30.0);
+        let unknown = LandingAttempt::calculate_score(LandingZoneDifficulty::Hard, None, 50.0, 30.0);
+        assert!((centered - 5850.0).abs() < 0.1);
+        assert!((edge - 3900.0).abs() < 0.1);
+        assert_eq!(unknown, edge);
+
+        let mut manager = SessionManager::new();
+        manager.complete_attempt(AttemptResult::Success, 50.0, Some(LandingZoneDifficulty::Hard), Some((20.0, 20.0)), 30.0);
+        manager.complete_attempt(AttemptResult::Success, 50.0, Some(LandingZoneDifficulty::Hard), Some((0.0, 40.0)), 30.0);
+        assert!(manager.session.attempts[0].score > manager.session.attempts[1].score);
     }
 
     #[test]
@@ -286,6 +892,7 @@ mod tests {
             AttemptResult::Success,
             70.0,
             Some(LandingZoneDifficulty::Hard),
+            None,
             50.0
         );
         assert_eq!(manager.session.current_attempt, 1);
@@ -293,11 +900,12 @@ mod tests {
         assert!(manager.session.total_score > 0.0);
         
         // Complete remaining attempts
-        manager.complete_attempt(AttemptResult::Failure, 30.0, None, 120.0);
+        manager.complete_attempt(AttemptResult::Failure, 30.0, None, None, 120.0);
         manager.complete_attempt(
             AttemptResult::Success,
             90.0,
             Some(LandingZoneDifficulty::Medium),
+            None,
             40.0
         );
         
@@ -307,6 +915,169 @@ mod tests {
         assert_eq!(manager.session.failure_count(), 1);
     }
 
+    #[test]
+    fn test_hotseat_alternation() {
+        let mut manager = SessionManager::new();
+        assert!(!manager.is_hotseat());
+        assert_eq!(manager.turn_label(), None);
+        assert!(!manager.pass_turn(), "Single-player keeps the controls");
+
+        manager.set_player_count(2);
+        assert_eq!(manager.turn_label().as_deref(), Some("PLAYER 1"));
+
+        // Players alternate, each scoring into their own session
+        for round in 0..3 {
+            manager.complete_attempt(AttemptResult::Success, 50.0, Some(LandingZoneDifficulty::Hard), None, 30.0);
+            assert!(manager.pass_turn());
+            assert_eq!(manager.active_player, 1);
+            manager.complete_attempt(AttemptResult::Failure, 20.0, None, None, 30.0);
+            if round < 2 {
+                assert!(!manager.is_complete());
+                assert!(manager.pass_turn());
+                assert_eq!(manager.active_player, 0);
+            }
+        }
+        assert!(manager.is_complete());
+        assert!(!manager.pass_turn());
+        assert!(!manager.can_start_next_attempt());
+        assert_eq!(manager.session_of(0).success_count(), 3);
+        assert_eq!(manager.session_of(1).failure_count(), 3);
+        assert_eq!(manager.session_of(1).total_score, 0.0);
+        assert_eq!(manager.high_scores.sessions.len(), 2, "One record per player");
+
+        manager.reset_session();
+        assert_eq!((manager.active_player, manager.player_count()), (0, 2));
+        assert_eq!(manager.session_of(1).current_attempt, 0);
+    }
+
+    #[test]
+    fn test_hotseat_skips_finished_players() {
+        let mut manager = SessionManager::new();
+        manager.set_player_count(2);
+        manager.session.session_complete = true;
+        assert!(manager.pass_turn());
+        assert_eq!(manager.active_player, 1);
+
+        // Player 1 is done, so player 2 keeps flying
+        assert!(!manager.pass_turn());
+        assert_eq!(manager.active_player, 1);
+        assert!(manager.session_of(0).session_complete);
+        assert!(manager.can_start_next_attempt());
+    }
+
+    #[test]
+    fn test_best_replay_kept_for_best_landing() {
+        let flight = |x: f32| vec![ReplayFrame { time: 0.0, position: Vec2::new(x, 100.0), rotation: 0.0, thrust: 0.0, velocity_y: 0.0 }];
+        let mut manager = SessionManager::new();
+
+        manager.complete_attempt(AttemptResult::Success, 50.0, Some(LandingZoneDifficulty::Easy), None, 30.0);
+        manager.record_best_replay(flight(1.0), 7);
+        assert_eq!(manager.ghost_for(7).map(|frames| frames[0].position.x), Some(1.0));
+        assert_eq!(manager.ghost_for(8), None, "Different terrain, no ghost");
+
+        // A crash or a lower score leaves the ghost alone; a better landing replaces it
+        manager.complete_attempt(AttemptResult::Failure, 50.0, None, None, 30.0);
+        manager.record_best_replay(flight(2.0), 7);
+        assert_eq!(manager.ghost_for(7).map(|frames| frames[0].position.x), Some(1.0));
+        manager.complete_attempt(AttemptResult::Success, 50.0, Some(LandingZoneDifficulty::Hard), None, 30.0);
+        manager.record_best_replay(flight(3.0), 9);
+        assert_eq!(manager.ghost_for(9).map(|frames| frames[0].position.x), Some(3.0));
+
+        manager.reset_session();
+        assert_eq!(manager.ghost_for(9), None);
+    }
+
+    #[test]
+    fn test_hotseat_ghost_is_best_across_players() {
+        let flight = |x: f32| vec![ReplayFrame { time: 0.0, position: Vec2::new(x, 100.0), rotation: 0.0, thrust: 0.0, velocity_y: 0.0 }];
+        let mut manager = SessionManager::new();
+        manager.set_player_count(2);
+
+        manager.complete_attempt(AttemptResult::Success, 50.0, Some(LandingZoneDifficulty::Hard), None, 30.0);
+        manager.record_best_replay(flight(1.0), 7);
+        assert!(manager.pass_turn());
+
+        // Player 2's first landing is their own best, but worse than player 1's
+        manager.complete_attempt(AttemptResult::Success, 50.0, Some(LandingZoneDifficulty::Easy), None, 30.0);
+        manager.record_best_replay(flight(2.0), 7);
+        assert_eq!(manager.ghost_for(7).map(|frames| frames[0].position.x), Some(1.0));
+    }
+
+    #[test]
+    fn test_attempt_sites_cleared_on_reset() {
+        let mut manager = SessionManager::new();
+
+        manager.record_attempt_site(Vec2::new(120.0, 80.0), AttemptResult::Failure);
+        manager.record_attempt_site(Vec2::new(400.0, 60.0), AttemptResult::Success);
+        assert_eq!(manager.session.attempt_sites.len(), 2);
+        assert_eq!(manager.session.attempt_sites[0].1, AttemptResult::Failure);
+
+        manager.reset_session();
+        assert!(manager.session.attempt_sites.is_empty(), "New session should start without wreckage");
+    }
+
+    #[test]
+    fn test_single_use_zones() {
+        let mut manager = SessionManager::new();
+        manager.single_use_zones = true;
+
+        manager.complete_attempt(AttemptResult::Success, 50.0, Some(LandingZoneDifficulty::Hard), None, 30.0);
+        let first_score = manager.session.attempts[0].score;
+        assert!(first_score > 0.0);
+        assert!(manager.session.is_zone_consumed(LandingZoneDifficulty::Hard));
+
+        // Landing on the same difficulty again scores nothing
+        manager.complete_attempt(AttemptResult::Success, 50.0, Some(LandingZoneDifficulty::Hard), None, 30.0);
+        assert_eq!(manager.session.attempts[1].score, 0.0);
+        assert_eq!(manager.session.attempts[1].result, AttemptResult::Success);
+
+        // A different difficulty still scores
+        manager.complete_attempt(AttemptResult::Success, 50.0, Some(LandingZoneDifficulty::Easy), None, 30.0);
+        assert!(manager.session.attempts[2].score > 0.0);
+
+        manager.reset_session();
+        assert!(manager.session.consumed_zones.is_empty());
+
+        // Lifetime totals outlive the session
+        assert_eq!(manager.lifetime.landings, 3);
+        assert_eq!(manager.lifetime.sessions_played, 1);
+    }
+
+    #[test]
+    fn test_practice_attempts_not_counted() {
+        let mut manager = SessionManager::new();
+        manager.practice = true;
+
+        for _ in 0..5 {
+            manager.complete_attempt(AttemptResult::Failure, 20.0, None, None, 30.0);
+            manager.record_attempt_site(Vec2::new(100.0, 50.0), AttemptResult::Failure);
+        }
+        manager.complete_attempt(AttemptResult::Success, 60.0, Some(LandingZoneDifficulty::Easy), None, 40.0);
+
+        assert_eq!(manager.session.current_attempt, 0);
+        assert_eq!(manager.session.total_score, 0.0);
+        assert!(manager.session.attempt_sites.is_empty());
+        assert_eq!(manager.lifetime, LifetimeStats::default(), "Practice stays out of the lifetime stats");
+        assert!(manager.can_start_next_attempt(), "Practice never runs out of attempts");
+    }
+
+    #[test]
+    fn test_landing_grade_thresholds() {
+        assert_eq!(LandingGrade::from_margins(1.0, 0.9, 0.85), LandingGrade::A);
+        assert_eq!(LandingGrade::from_margins(0.8, 0.8, 0.8), LandingGrade::A);
+        assert_eq!(LandingGrade::from_margins(1.0, 0.79, 1.0), LandingGrade::B);
+        assert_eq!(LandingGrade::from_margins(0.5, 1.0, 1.0), LandingGrade::C);
+        assert_eq!(LandingGrade::from_margins(1.0, 1.0, 0.2), LandingGrade::D);
+        assert_eq!(LandingGrade::from_margins(0.0, 1.0, 1.0), LandingGrade::F, "Touching a zone edge");
+
+        // The grade lands on the attempt that just finished
+        let mut manager = SessionManager::new();
+        manager.complete_attempt(AttemptResult::Success, 50.0, Some(LandingZoneDifficulty::Easy), None, 30.0);
+        manager.record_landing_grade(LandingGrade::B);
+        assert_eq!(manager.session.attempts[0].grade, Some(LandingGrade::B));
+        assert_eq!(manager.session.attempts[1].grade, None);
+    }
+
     #[test]
     fn test_performance_rating() {
         let mut session = GameSession::new();
@@ -316,18 +1087,21 @@ mod tests {
             LandingAttempt::new_completed(
                 AttemptResult::Success,
                 Some(LandingZoneDifficulty::Hard),
+                None,
                 80.0,
                 45.0
             ),
             LandingAttempt::new_completed(
                 AttemptResult::Success,
                 Some(LandingZoneDifficulty::Medium),
+                None,
                 75.0,
                 55.0
             ),
             LandingAttempt::new_completed(
                 AttemptResult::Success,
                 Some(LandingZoneDifficulty::Easy),
+                None,
                 85.0,
                 40.0
             ),
@@ -337,4 +1111,106 @@ mod tests {
         assert_eq!(session.success_count(), 3);
         assert!((session.average_fuel_efficiency() - 80.0).abs() < 1.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_export_csv() {
+        let mut manager = SessionManager::new();
+        assert_eq!(manager.session.export_csv(), format!("{}\n", SESSION_CSV_HEADER), "Header even with no attempts");
+
+        manager.complete_attempt(AttemptResult::Failure, 30.0, None, None, 42.5);
+        manager.complete_attempt(AttemptResult::Success, 80.0, Some(LandingZoneDifficulty::Hard), None, 45.0);
+        let csv = manager.session.export_csv();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(rows.len(), 3, "In-progress attempts are left out");
+        assert_eq!(rows[1], "Failure,0,30.0,,42.50");
+        assert_eq!(rows[2], "Success,4410,80.0,Hard,45.00");
+    }
+
+    #[test]
+    fn test_civil_date() {
+        assert_eq!(civil_date(0), "1970-01-01");
+        assert_eq!(civil_date(951_782_400), "2000-02-29");
+        assert_eq!(civil_date(1_700_000_000), "2023-11-14");
+    }
+
+    #[test]
+    fn test_high_scores() {
+        let mut manager = SessionManager::new();
+        assert_eq!(manager.best_score(), None);
+
+        for score in [1000.0, 3000.0, 2000.0] {
+            let mut session = GameSession::new();
+            session.total_score = score;
+            manager.record_high_score(SessionRecord::from_session(&session, "2024-01-01".to_string()));
+        }
+        assert_eq!(manager.best_score(), Some(3000.0));
+        let scores: Vec<f32> = manager.high_scores.sessions.iter().map(|record| record.total_score).collect();
+        assert_eq!(scores, vec![3000.0, 2000.0, 1000.0], "Highest first");
+
+        // A completed session lands in the list on its own
+        let mut manager = SessionManager::new();
+        for _ in 0..3 {
+            manager.complete_attempt(AttemptResult::Success, 50.0, Some(LandingZoneDifficulty::Easy), None, 30.0);
+        }
+        assert_eq!(manager.high_scores.sessions.len(), 1);
+        assert_eq!(manager.high_scores.sessions[0].successes, 3);
+        assert_eq!(manager.high_scores.sessions[0].rating, "EXPERT");
+
+        // Round trip through a file; missing and corrupt files start empty
+        let path = std::env::temp_dir().join(format!("lunar_lander_scores_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        assert_eq!(read_high_scores(path), HighScores::default());
+        write_high_scores(path, &manager.high_scores).unwrap();
+        assert_eq!(read_high_scores(path), manager.high_scores);
+        fs::write(path, "{ not json").unwrap();
+        assert_eq!(read_high_scores(path), HighScores::default());
+
+        // A file from before best landings were kept still loads its sessions
+        fs::write(path, serde_json::to_string(&manager.high_scores.sessions).unwrap()).unwrap();
+        let migrated = read_high_scores(path);
+        assert_eq!(migrated.sessions, manager.high_scores.sessions);
+        assert!(migrated.best_landings.is_empty());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_daily_challenge() {
+        assert_eq!(daily_seed("2026-10-15"), daily_seed("2026-10-15"));
+        assert_ne!(daily_seed("2026-10-15"), daily_seed("2026-10-16"));
+
+        let mut manager = SessionManager::new();
+        manager.daily = Some("2026-10-15".to_string());
+        for _ in 0..3 {
+            manager.complete_attempt(AttemptResult::Success, 50.0, Some(LandingZoneDifficulty::Easy), None, 30.0);
+        }
+        let best = manager.high_scores.daily_best_on("2026-10-15").unwrap();
+        assert_eq!(best, manager.session.total_score);
+        assert_eq!(manager.high_scores.daily_best_on("2026-10-14"), None);
+
+        // A weaker run keeps the record; the next day starts over
+        assert!(!manager.high_scores.record_daily("2026-10-15", best - 1.0));
+        assert!(manager.high_scores.record_daily("2026-10-16", 1.0));
+        assert_eq!(manager.high_scores.daily_best_on("2026-10-15"), None);
+        assert_eq!(manager.high_scores.daily_best_on("2026-10-16"), Some(1.0));
+    }
+
+    #[test]
+    fn test_best_landing_per_zone_and_body() {
+        let mut manager = SessionManager::new();
+        let easy = LandingZoneDifficulty::Easy;
+        manager.complete_attempt(AttemptResult::Success, 50.0, Some(easy), None, 30.0);
+        let moon_best = manager.high_scores.best_landing(easy, CelestialBody::Moon).unwrap().score;
+        assert!(moon_best > 0.0);
+
+        // A weaker landing leaves the record alone; other bodies and zones keep their own
+        manager.complete_attempt(AttemptResult::Success, 10.0, Some(easy), None, 30.0);
+        assert_eq!(manager.high_scores.best_landing(easy, CelestialBody::Moon).unwrap().score, moon_best);
+        assert!(manager.high_scores.best_landing(easy, CelestialBody::Mars).is_none());
+        assert!(manager.high_scores.best_landing(LandingZoneDifficulty::Hard, CelestialBody::Moon).is_none());
+
+        manager.body = CelestialBody::Mars;
+        manager.complete_attempt(AttemptResult::Success, 90.0, Some(easy), None, 30.0);
+        assert!(manager.high_scores.best_landing(easy, CelestialBody::Mars).unwrap().score > moon_best);
+        assert_eq!(manager.high_scores.best_landing(easy, CelestialBody::Moon).unwrap().score, moon_best);
+    }
+}
@@ -0,0 +1,822 @@
+//! Headless lander simulation for embedding and benchmarking.
+//!
+//! `LanderSim` drives the same physics integration and collision detection as
+//! the game loop, but without reading any `macroquad` window, input or audio
+//! state. It can be stepped thousands of times from a benchmark or used as the
+//! core of a reinforcement-learning environment. The game loop's own
+//! per-entity step, `simulate_step`, lives here too, so the physics of a full
+//! `Entity` can be tested without a window.
+//!
+//! # Stepping contract
+//!
+//! - Each `step` applies the input, integrates forces over `dt`, then checks for contact
+//! - Close to the terrain the step is split into sub-steps that stop at the surface
+//! - The first contact ends the run: the lander is stopped and the outcome is latched
+//! - Once finished, `step` no longer advances the simulation and returns the latched outcome
+//!
+//! # Determinism
+//!
+//! Given the same terrain, landing zones, lander size and world size, and the
+//! same sequence of `(input, dt)` pairs, the simulation produces identical
+//! state. Nothing in `step` reads the clock, the RNG or the window.
+
+use macroquad::prelude::*;
+
+use crate::collision::{detect_collision, CollisionType};
+use crate::entity::{Entity, Transform};
+use crate::guidance::thrust_blocked;
+use crate::input::ROTATION_TORQUE;
+use crate::physics::{gravity_vector, Integrator, Physics, RocketEngine};
+use crate::surface::{peak_height_under, LandingZone, LandingZoneDifficulty};
+use crate::telemetry::TelemetrySample;
+use crate::tuning::LandingTuning;
+use crate::utils::pixel_to_terrain_x;
+use crate::ACCEL_GRAV_Y;
+
+/// Altitude above the terrain within which a step is split into sub-steps
+const SUBSTEP_ALTITUDE: f32 = 20.0;
+/// Number of sub-steps a step is split into near the terrain
+const SUBSTEPS: u32 = 10;
+
+/// Length of one fixed physics step in seconds (60 Hz)
+pub const FIXED_DT: f32 = 1.0 / 60.0;
+/// Most fixed steps run for a single frame. Time beyond that (a dragged or
+/// restored window reports the whole time it was away) is dropped instead of
+/// caught up, so one slow frame can't make the next ones slower still
+const MAX_CATCH_UP_STEPS: u32 = 6;
+
+/// Converts variable frame times into a whole number of fixed physics steps.
+///
+/// Frame time accumulates and is paid out in `FIXED_DT` steps; the remainder
+/// carries over to the next frame. Anything advanced by these steps, the
+/// mission timer included, is independent of the frame rate. A frame that
+/// is not fed to the clock (a dead lander, or a pause) advances nothing.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FixedStepClock {
+    accumulator: f32,
+}
+
+impl FixedStepClock {
+    /// Adds a frame's worth of time and returns how many fixed steps to run.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_time` - Wall-clock duration of the frame in seconds
+    pub fn advance(&mut self, frame_time: f32) -> u32 {
+        if frame_time.is_finite() && frame_time > 0.0 {
+            self.accumulator += frame_time;
+        }
+        let steps = ((self.accumulator / FIXED_DT) as u32).min(MAX_CATCH_UP_STEPS);
+        self.accumulator -= steps as f32 * FIXED_DT;
+        if self.accumulator >= FIXED_DT {
+            // Over the catch-up limit: keep only the partial step
+            self.accumulator = self.accumulator.rem_euclid(FIXED_DT);
+        }
+        steps
+    }
+
+    /// How far the time carried over is into the next step, from `0.0` to just under `1.0`.
+    ///
+    /// Rendering uses it to draw between the last two physics states.
+    pub fn alpha(&self) -> f32 {
+        (self.accumulator / FIXED_DT).clamp(0.0, 1.0)
+    }
+
+    /// Drops any partial step, e.g. when a new attempt starts.
+    pub fn reset(&mut self) {
+        self.accumulator = 0.0;
+    }
+}
+
+/// Control inputs applied for a single simulation step
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SimInput {
+    pub thrust: bool,
+    pub rotate_left: bool,
+    pub rotate_right: bool,
+    pub dump_fuel: bool,
+}
+
+/// Self-contained lander simulation decoupled from the game loop
+pub struct LanderSim {
+    transform: Transform,
+    tuning: LandingTuning,
+    physics: Physics,
+    rocket: RocketEngine,
+    terrain: Vec<f64>,
+    landing_zones: Vec<LandingZone>,
+    world_size: Vec2,
+    vertical_wrap: bool,
+    time_elapsed: f32,
+    outcome: Option<(CollisionType, Option<LandingZoneDifficulty>)>,
+    impact_velocity: Option<Vec2>,
+}
+
+/// Advances a lander body by one time step.
+///
+/// Applies gravity, wind, drag, rocket thrust and any RCS firing, integrates velocity,
+/// position and spin, and wraps the position around the world bounds. A non-finite position result
+/// is discarded and the previous position kept.
+///
+/// Horizontal wrapping always applies. Vertical wrapping is optional; without
+/// it the top of the world is a ceiling: the lander is clamped so its top
+/// stays on screen and any upward velocity is cancelled. Flying into the
+/// ceiling is not a failure. This is the per-entity body of
+/// the game's physics system, shared with `LanderSim`.
+///
+/// # Arguments
+///
+/// * `transform` - Lander transform, position updated in place
+/// * `physics` - Physics component accumulating forces and velocity
+/// * `rocket` - Rocket engine generating thrust, if present
+/// * `gravity` - Gravitational acceleration vector in m/s² (see `gravity_vector`)
+/// * `wind` - Wind force in newtons (see `Wind`)
+/// * `dt` - Time step in seconds
+/// * `world_size` - Width and height used for screen wrapping
+/// * `vertical_wrap` - Wrap from the top of the world to the bottom instead of capping altitude
+#[allow(clippy::too_many_arguments)]
+pub fn advance_lander(
+    transform: &mut Transform,
+    physics: &mut Physics,
+    rocket: Option<&mut RocketEngine>,
+    gravity: Vec2,
+    wind: Vec2,
+    dt: f32,
+    world_size: Vec2,
+    vertical_wrap: bool,
+) {
+    // Reset forces for this frame
+    physics.reset_forces();
+
+    // Apply gravity force
+    let gravity_force = gravity * physics.mass as f32;
+    physics.add_force(gravity_force);
+
+    // Apply wind, which pushes equally hard whatever the lander weighs
+    physics.add_force(wind);
+
+    // Apply atmospheric drag (none in vacuum)
+    physics.add_force(physics.drag_force());
+
+    // Generate thrust force if rocket engine present
+    if let Some(rocket) = rocket {
+        // Update physics mass and inertia based on current rocket mass
+        physics.mass = rocket.total_mass();
+        physics.moment_of_inertia = rocket.moment_of_inertia();
+
+        let thrust_force = rocket.generate_thrust(dt);
+        physics.add_force(thrust_force);
+
+        // Lateral RCS jets translate without rotating the lander
+        let rcs_force = rocket.fire_rcs(rocket.rcs_direction, transform.rotation, dt);
+        physics.add_force(rcs_force);
+
+        // Venting lightens the craft from the next step on
+        if rocket.is_dumping {
+            rocket.dump_fuel(dt);
+        }
+    }
+
+    // Integrate forces into motion, and the commanded torque into the spin
+    let previous_position = transform.position;
+    physics.integrate(&mut transform.position, dt);
+    physics.integrate_rotation(&mut transform.rotation, dt);
+
+    // Wrap around screen horizontally (maintain lunar lander behavior)
+    transform.position.x = transform.position.x.rem_euclid(world_size.x);
+    if vertical_wrap {
+        transform.position.y = transform.position.y.rem_euclid(world_size.y);
+    } else {
+        // Ceiling: cap altitude so the lander stays on screen
+        let ceiling = world_size.y - transform.size.y;
+        if transform.position.y > ceiling {
+            transform.position.y = ceiling;
+            physics.velocity.y = physics.velocity.y.min(0.0);
+        }
+    }
+
+    // A bad dt or world size must not make the lander vanish
+    if !transform.position.is_finite() {
+        warn!("Non-finite position {:?}, keeping {:?}", transform.position, previous_position);
+        transform.position = previous_position;
+    }
+}
+
+/// Advances a lander by one time step, sub-stepping close to the terrain.
+///
+/// Far from the ground this is a single `advance_lander` step. When the
+/// lander is within `SUBSTEP_ALTITUDE` of the terrain, or would fall into that
+/// band during the step, the step is split into `SUBSTEPS` smaller ones and
+/// stops at the first sub-step where a leg or the body actually touches (as
+/// judged by `detect_collision`); ground that is merely close, such as a rock
+/// between the legs, doesn't cut the step short. The velocity left in
+/// `physics` is then the velocity at the moment of contact rather than after
+/// a full step of overshoot, which keeps the `MAX_LANDING_VELOCITY` check fair
+/// at low frame rates.
+///
+/// # Arguments
+///
+/// * `transform` - Lander transform, position updated in place
+/// * `physics` - Physics component accumulating forces and velocity
+/// * `rocket` - Rocket engine generating thrust, if present
+/// * `gravity` - Gravitational acceleration vector in m/s² (see `gravity_vector`)
+/// * `wind` - Wind force in newtons (see `Wind`)
+/// * `dt` - Time step in seconds
+/// * `world_size` - Width and height used for screen wrapping; the terrain spans the width
+/// * `vertical_wrap` - Wrap from the top of the world to the bottom instead of capping altitude
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `tuning` - Leg and body layout and contact margin deciding what counts as contact
+#[allow(clippy::too_many_arguments)]
+pub fn advance_lander_to_contact(
+    transform: &mut Transform,
+    physics: &mut Physics,
+    mut rocket: Option<&mut RocketEngine>,
+    gravity: Vec2,
+    wind: Vec2,
+    dt: f32,
+    world_size: Vec2,
+    vertical_wrap: bool,
+    terrain: &[f64],
+    tuning: &LandingTuning,
+) {
+    let altitude_at = |transform: &Transform| {
+        let x = pixel_to_terrain_x(transform.position.x, terrain.len(), world_size.x);
+        let width = pixel_to_terrain_x(transform.size.x, terrain.len(), world_size.x);
+        transform.position.y - peak_height_under(terrain, x, width)
+    };
+
+    let descent_this_step = (-physics.velocity.y * dt).max(0.0);
+    if terrain.is_empty() || altitude_at(transform) - descent_this_step > SUBSTEP_ALTITUDE {
+        advance_lander(transform, physics, rocket, gravity, wind, dt, world_size, vertical_wrap);
+        return;
+    }
+
+    let sub_dt = dt / SUBSTEPS as f32;
+    for _ in 0..SUBSTEPS {
+        advance_lander(transform, physics, rocket.as_deref_mut(), gravity, wind, sub_dt, world_size, vertical_wrap);
+        if detect_collision(transform, tuning, None, terrain, world_size.x, &[]).0 != CollisionType::None {
+            break;
+        }
+    }
+}
+
+/// Advances a game lander entity by one physics step.
+///
+/// This is the game loop's physics system for a single entity: it moves the
+/// lander (see `advance_lander_to_contact`), then updates the navigation
+/// totals, the blocked-thrust timer, the mission timer and the telemetry
+/// recording. Like the rest of this module it reads no frame time, window
+/// size or input, so tests can drive an `Entity` without a window; the game
+/// feeds it the real frame time through the entity's `FixedStepClock`.
+///
+/// # Arguments
+///
+/// * `entity` - Lander entity, updated in place (nothing happens without a physics component)
+/// * `dt` - Time step in seconds
+/// * `gravity` - Gravitational acceleration vector in m/s² (see `gravity_vector`)
+pub fn simulate_step(entity: &mut Entity, dt: f32, gravity: Vec2) {
+    let wind = vec2(entity.wind.force_at(entity.time_elapsed), 0.0);
+    let Some(physics) = &mut entity.physics else {
+        return;
+    };
+
+    // Rendering draws between this state and the one after the step
+    entity.previous_transform = entity.transform.clone();
+    advance_lander_to_contact(
+        &mut entity.transform,
+        physics,
+        entity.rocket_physics.as_mut(),
+        gravity,
+        wind,
+        dt,
+        entity.world_size,
+        entity.vertical_wrap,
+        &entity.terrain,
+        &entity.tuning,
+    );
+
+    // Inertial navigation: integrate horizontal distance
+    entity.distance_traveled += (physics.velocity.x * dt).abs();
+
+    // Ground under the lander after the step, for peak altitude, the blocked-thrust check and telemetry
+    let ground = peak_height_under(
+        &entity.terrain,
+        pixel_to_terrain_x(entity.transform.position.x, entity.terrain.len(), entity.world_size.x),
+        pixel_to_terrain_x(entity.transform.size.x, entity.terrain.len(), entity.world_size.x),
+    );
+    entity.max_altitude = entity.max_altitude.max(entity.transform.position.y - ground);
+
+    // Track thrust wasted against the ground or ceiling (fuel still burns)
+    let blocked = entity.rocket_physics.as_ref().map_or(false, |rocket| {
+        let ceiling_gap = if entity.vertical_wrap {
+            f32::INFINITY
+        } else {
+            entity.world_size.y - (entity.transform.position.y + entity.transform.size.y)
+        };
+        thrust_blocked(
+            rocket.thrust_direction,
+            rocket.spool_level,
+            physics.velocity,
+            entity.transform.position.y - ground,
+            ceiling_gap,
+        )
+    });
+    entity.thrust_blocked_time = if blocked { entity.thrust_blocked_time + dt } else { 0.0 };
+
+    // Mission timer follows the simulation clock, one fixed step at a time
+    entity.time_elapsed += dt;
+
+    // Flight recording (only when enabled with --telemetry)
+    if let Some(telemetry) = &mut entity.telemetry {
+        let (thrust, fuel) = match &entity.rocket_physics {
+            Some(rocket) => (rocket.spool_level * 100.0, rocket.fuel_percentage()),
+            None => (0.0, 0.0),
+        };
+        telemetry.record(TelemetrySample {
+            time: entity.time_elapsed,
+            altitude: entity.transform.position.y - ground,
+            velocity_x: physics.velocity.x,
+            velocity_y: physics.velocity.y,
+            thrust,
+            fuel,
+        });
+    }
+}
+
+impl LanderSim {
+    /// Creates a simulation with a fully fueled Apollo LM above the given terrain.
+    ///
+    /// The lander starts at the same spot as in the game: centered horizontally,
+    /// 50 units above the middle of the world.
+    ///
+    /// # Arguments
+    ///
+    /// * `terrain` - Terrain heights, spread evenly across the world width
+    /// * `landing_zones` - Landing zones laid out on the terrain
+    /// * `lander_size` - Lander width and height in pixels
+    /// * `world_size` - World width and height (the game uses the screen size)
+    pub fn new(
+        terrain: Vec<f64>,
+        landing_zones: Vec<LandingZone>,
+        lander_size: Vec2,
+        world_size: Vec2,
+    ) -> Self {
+        let rocket = RocketEngine::new_apollo_lm();
+        let physics = Physics::new(rocket.total_mass());
+
+        // Same spawn point as the game: world (0, 50) mapped to camera coordinates
+        let start_center = vec2(world_size.x / 2.0, world_size.y / 2.0 - 50.0);
+        let transform = Transform {
+            size: lander_size,
+            position: start_center - lander_size / 2.0,
+            rotation: 0.0,
+        };
+
+        Self {
+            transform,
+            tuning: LandingTuning::default(),
+            physics,
+            rocket,
+            terrain,
+            landing_zones,
+            world_size,
+            vertical_wrap: false,
+            time_elapsed: 0.0,
+            outcome: None,
+            impact_velocity: None,
+        }
+    }
+
+    /// Selects the numerical integrator (semi-implicit Euler by default).
+    pub fn with_integrator(mut self, integrator: Integrator) -> Self {
+        self.physics.integrator = integrator;
+        self
+    }
+
+    /// Sets the landing limits and leg geometry (today's constants by default).
+    pub fn with_tuning(mut self, tuning: LandingTuning) -> Self {
+        self.tuning = tuning;
+        self
+    }
+
+    /// Enables wrapping from the top of the world to the bottom (off by default).
+    pub fn with_vertical_wrap(mut self, vertical_wrap: bool) -> Self {
+        self.vertical_wrap = vertical_wrap;
+        self
+    }
+
+    /// Advances the simulation by one step.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Controls held during this step
+    /// * `dt` - Time step in seconds
+    ///
+    /// # Returns
+    ///
+    /// * `Some(collision)` - The lander touched the terrain (on this or an earlier step)
+    /// * `None` - The lander is still flying
+    pub fn step(&mut self, input: SimInput, dt: f32) -> Option<CollisionType> {
+        if let Some((collision, _)) = self.outcome {
+            return Some(collision);
+        }
+
+        let torque = match (input.rotate_left, input.rotate_right) {
+            (true, false) => ROTATION_TORQUE,
+            (false, true) => -ROTATION_TORQUE,
+            _ => 0.0,
+        };
+        self.physics.apply_torque(torque);
+
+        if input.thrust && self.rocket.has_fuel() {
+            self.rocket.fire(self.transform.rotation);
+        } else {
+            self.rocket.stop_thrust();
+        }
+        self.rocket.is_dumping = input.dump_fuel;
+
+        advance_lander_to_contact(
+            &mut self.transform,
+            &mut self.physics,
+            Some(&mut self.rocket),
+            gravity_vector(ACCEL_GRAV_Y, 0.0),
+            Vec2::ZERO,
+            dt,
+            self.world_size,
+            self.vertical_wrap,
+            &self.terrain,
+            &self.tuning,
+        );
+        self.time_elapsed += dt;
+
+        let (collision, zone) = detect_collision(
+            &self.transform,
+            &self.tuning,
+            Some(self.physics.velocity),
+            &self.terrain,
+            self.world_size.x,
+            &self.landing_zones,
+        );
+
+        match collision {
+            CollisionType::None => None,
+            hit => {
+                // Contact ends the run, same as stop_lander in the game loop
+                self.impact_velocity = Some(self.physics.velocity);
+                self.physics.velocity = Vec2::ZERO;
+                self.physics.forces = Vec2::ZERO;
+                self.physics.angular_velocity = 0.0;
+                self.rocket.shutdown();
+                self.outcome = Some((hit, zone));
+                Some(hit)
+            }
+        }
+    }
+
+    /// Lander position (bottom-left corner, camera coordinates)
+    pub fn position(&self) -> Vec2 {
+        self.transform.position
+    }
+
+    /// Lander rotation in degrees (0° = upright)
+    pub fn rotation(&self) -> f32 {
+        self.transform.rotation
+    }
+
+    /// Lander velocity in m/s
+    pub fn velocity(&self) -> Vec2 {
+        self.physics.velocity
+    }
+
+    /// Lander spin in degrees per second, positive counterclockwise
+    pub fn angular_velocity(&self) -> f32 {
+        self.physics.angular_velocity
+    }
+
+    /// Velocity at the moment of contact, once the run is finished
+    pub fn impact_velocity(&self) -> Option<Vec2> {
+        self.impact_velocity
+    }
+
+    /// Remaining fuel as a percentage (0-100)
+    pub fn fuel_percentage(&self) -> f32 {
+        self.rocket.fuel_percentage()
+    }
+
+    /// Simulated time since the start of the run in seconds
+    pub fn time_elapsed(&self) -> f32 {
+        self.time_elapsed
+    }
+
+    /// Whether the lander has touched the terrain
+    pub fn is_finished(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    /// Collision outcome and landing zone difficulty, once the run is finished
+    pub fn outcome(&self) -> Option<(CollisionType, Option<LandingZoneDifficulty>)> {
+        self.outcome
+    }
+
+    /// Read-only access to the rocket engine
+    pub fn rocket(&self) -> &RocketEngine {
+        &self.rocket
+    }
+
+    /// Terrain heights the simulation runs against
+    pub fn terrain(&self) -> &[f64] {
+        &self.terrain
+    }
+
+    /// Landing zones laid out on the terrain
+    pub fn landing_zones(&self) -> &[LandingZone] {
+        &self.landing_zones
+    }
+
+    /// Lander width and height
+    pub fn lander_size(&self) -> Vec2 {
+        self.transform.size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collision::COLLISION_MARGIN;
+
+    const DT: f32 = 1.0 / 60.0;
+
+    fn flat_sim() -> LanderSim {
+        // Flat ground at camera Y = 60 with a single zone spanning the whole world
+        let terrain = vec![60.0; 800];
+        let zones = vec![LandingZone {
+            start: 0,
+            end: 799,
+            difficulty: LandingZoneDifficulty::Easy,
+            width_points: 800,
+            motion: None,
+        }];
+        LanderSim::new(terrain, zones, vec2(32.0, 32.0), vec2(800.0, 600.0))
+    }
+
+    #[test]
+    fn test_free_fall_ends_in_hard_landing() {
+        let mut sim = flat_sim();
+
+        let mut result = None;
+        for _ in 0..5000 {
+            result = sim.step(SimInput::default(), DT);
+            if result.is_some() {
+                break;
+            }
+        }
+
+        // ~174 units of free fall reaches ~24 m/s, well above the safe landing speed
+        assert_eq!(result, Some(CollisionType::LegCollision));
+        assert_eq!(sim.outcome(), Some((CollisionType::LegCollision, Some(LandingZoneDifficulty::Easy))));
+        assert_eq!(sim.fuel_percentage(), 100.0, "No fuel should burn without thrust");
+
+        // Finished runs are latched and no longer advance
+        let position = sim.position();
+        let time = sim.time_elapsed();
+        assert_eq!(sim.step(SimInput { thrust: true, ..Default::default() }, DT), Some(CollisionType::LegCollision));
+        assert_eq!(sim.position(), position);
+        assert_eq!(sim.time_elapsed(), time);
+    }
+
+    #[test]
+    fn test_impact_velocity_matches_contact_moment() {
+        // Verlet is exact under constant gravity, so any error left is from
+        // where the stepping stops relative to the surface
+        let mut sim = flat_sim().with_integrator(Integrator::VelocityVerlet);
+        let start_y = sim.position().y;
+
+        // Coarse 10 fps steps: a full step covers several units near the ground
+        for _ in 0..1000 {
+            if sim.step(SimInput::default(), 0.1).is_some() {
+                break;
+            }
+        }
+
+        // Legs touch at the collision margin above the flat ground at Y = 60
+        let fall = start_y - (60.0 + COLLISION_MARGIN);
+        let expected = (2.0 * ACCEL_GRAV_Y * fall).sqrt();
+        let impact = sim.impact_velocity().expect("Lander should have touched down");
+        assert!(
+            (impact.length() - expected).abs() < 0.05,
+            "Impact {:.3} m/s, expected {:.3} m/s",
+            impact.length(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_rock_between_legs_keeps_the_full_step() {
+        // A rock under the gap between the legs, well short of the body
+        let mut terrain = vec![60.0; 800];
+        terrain[398..=402].fill(66.0);
+        let mut transform = Transform {
+            size: vec2(32.0, 32.0),
+            position: vec2(384.0, 69.02),
+            rotation: 0.0,
+        };
+        let mut physics = Physics::new(15000.0).with_integrator(Integrator::VelocityVerlet);
+        physics.velocity = vec2(0.0, -5.0);
+
+        // Within the contact margin of the rock, but nothing touches yet
+        let dt = 0.1;
+        advance_lander_to_contact(
+            &mut transform,
+            &mut physics,
+            None,
+            gravity_vector(ACCEL_GRAV_Y, 0.0),
+            Vec2::ZERO,
+            dt,
+            vec2(800.0, 600.0),
+            false,
+            &terrain,
+            &LandingTuning::default(),
+        );
+
+        let expected = 69.02 - 5.0 * dt - 0.5 * ACCEL_GRAV_Y * dt * dt;
+        assert!(
+            (transform.position.y - expected).abs() < 1e-3,
+            "Lander at {:.4}, expected the whole step down to {:.4}",
+            transform.position.y,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_fixed_step_clock() {
+        let mut clock = FixedStepClock::default();
+
+        // A 40 ms frame is two 60 Hz steps with the remainder carried over
+        assert_eq!(clock.advance(0.040), 2);
+        assert!((clock.alpha() - 0.4).abs() < 1e-3, "Render blend is 40% into the next step");
+        assert_eq!(clock.advance(0.012), 1, "Carried time completes a step");
+
+        // Bad frame times never step, long stalls are capped and not caught up later
+        assert_eq!(clock.advance(f32::NAN), 0);
+        assert_eq!(clock.advance(-1.0), 0);
+        clock.reset();
+        assert_eq!(clock.advance(10.0), MAX_CATCH_UP_STEPS);
+        assert_eq!(clock.advance(0.0), 0);
+        assert!(clock.alpha() < 1.0);
+
+        // The same total time yields the same step count regardless of frame pacing
+        let mut fast = FixedStepClock::default();
+        let mut slow = FixedStepClock::default();
+        let fast_steps: u32 = (0..120).map(|_| fast.advance(1.0 / 120.0)).sum();
+        let slow_steps: u32 = (0..30).map(|_| slow.advance(1.0 / 30.0)).sum();
+        assert!((fast_steps as i32 - 60).abs() <= 1 && (slow_steps as i32 - 60).abs() <= 1);
+    }
+
+    #[test]
+    fn test_simulate_step_full_thrust_reaches_rocket_equation_velocity() {
+        let mut entity = Entity::new();
+        entity.transform = Transform {
+            size: vec2(32.0, 32.0),
+            position: vec2(384.0, 100.0),
+            rotation: 0.0,
+        };
+        let rocket = entity.rocket_physics.as_mut().unwrap();
+        rocket.spool_time_constant = 0.0;
+        rocket.fire(0.0);
+        let start_mass = rocket.total_mass();
+
+        // Two seconds of full thrust straight up, no terrain below
+        let gravity = gravity_vector(ACCEL_GRAV_Y, 0.0);
+        for _ in 0..120 {
+            simulate_step(&mut entity, FIXED_DT, gravity);
+        }
+
+        // Δv = v_e·ln(m0/m1) − g·t
+        let rocket = entity.rocket_physics.as_ref().unwrap();
+        let expected = (rocket.exhaust_velocity * (start_mass / rocket.total_mass()).ln()) as f32 - ACCEL_GRAV_Y * 2.0;
+        let velocity = entity.physics.as_ref().unwrap().velocity;
+        assert!((velocity.y - expected).abs() < 0.01, "Velocity {:.4} m/s, expected {:.4} m/s", velocity.y, expected);
+        assert!(velocity.x.abs() < 1e-3, "Upright thrust has no sideways component");
+        assert!((entity.time_elapsed - 2.0).abs() < 1e-4);
+        assert!(entity.max_altitude > 100.0);
+    }
+
+    #[test]
+    fn test_max_altitude_is_height_above_terrain() {
+        let mut entity = Entity::new();
+        entity.terrain = vec![60.0; 1000];
+        entity.transform = Transform {
+            size: vec2(32.0, 32.0),
+            position: vec2(384.0, 100.0),
+            rotation: 0.0,
+        };
+
+        simulate_step(&mut entity, FIXED_DT, gravity_vector(ACCEL_GRAV_Y, 0.0));
+        assert!((entity.max_altitude - 40.0).abs() < 0.1, "Max altitude {}", entity.max_altitude);
+        assert!((entity.altitude() - entity.max_altitude).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_mission_timer_counts_fixed_steps() {
+        let mut sim = flat_sim();
+        for _ in 0..90 {
+            sim.step(SimInput::default(), FIXED_DT);
+        }
+        assert!((sim.time_elapsed() - 1.5).abs() < 1e-4, "Elapsed {}", sim.time_elapsed());
+    }
+
+    #[test]
+    fn test_thrust_burns_fuel_and_climbs() {
+        let mut sim = flat_sim();
+        let start = sim.position();
+
+        for _ in 0..60 {
+            sim.step(SimInput { thrust: true, ..Default::default() }, DT);
+        }
+
+        assert!(sim.fuel_percentage() < 100.0, "Thrusting should consume fuel");
+        assert!(sim.velocity().y > 0.0, "Thrust-to-weight > 1 should climb");
+        assert!(sim.position().y > start.y);
+        assert!(!sim.is_finished());
+    }
+
+    #[test]
+    fn test_advance_lander_rejects_non_finite_position() {
+        let mut transform = Transform {
+            size: vec2(32.0, 32.0),
+            position: vec2(100.0, 200.0),
+            rotation: 0.0,
+        };
+        let mut physics = Physics::new(23200.0);
+
+        // A zero-width world makes rem_euclid produce NaN
+        advance_lander(&mut transform, &mut physics, None, gravity_vector(ACCEL_GRAV_Y, 0.0), Vec2::ZERO, 1.0 / 60.0, vec2(0.0, 600.0), false);
+        assert_eq!(transform.position, vec2(100.0, 200.0));
+        assert!(physics.velocity.is_finite());
+    }
+
+    #[test]
+    fn test_wind_pushes_lighter_landers_harder() {
+        let drift = |mass: f64| {
+            let mut transform = Transform {
+                size: vec2(32.0, 32.0),
+                position: vec2(100.0, 200.0),
+                rotation: 0.0,
+            };
+            let mut physics = Physics::new(mass);
+            for _ in 0..60 {
+                advance_lander(&mut transform, &mut physics, None, Vec2::ZERO, vec2(-2000.0, 0.0), DT, vec2(800.0, 600.0), false);
+            }
+            physics.velocity.x
+        };
+
+        assert!((drift(20000.0) + 0.1).abs() < 1e-4, "2000 N on 20 t for 1 s is 0.1 m/s");
+        assert!(drift(10000.0) < drift(20000.0));
+    }
+
+    #[test]
+    fn test_ceiling_caps_altitude_without_vertical_wrap() {
+        // ~20 s of full thrust climbs well past the top of a 600-unit world
+        let mut sim = flat_sim();
+        for _ in 0..1200 {
+            sim.step(SimInput { thrust: true, ..Default::default() }, DT);
+        }
+        assert_eq!(sim.position().y, 600.0 - 32.0, "Lander should be held at the ceiling");
+        assert!(sim.velocity().y <= 0.0);
+        assert!(!sim.is_finished(), "Hitting the ceiling is not a failure");
+
+        // With vertical wrap the lander comes round to the bottom instead
+        let mut sim = flat_sim().with_vertical_wrap(true);
+        let mut wrapped = false;
+        for _ in 0..1200 {
+            let before = sim.position().y;
+            sim.step(SimInput { thrust: true, ..Default::default() }, DT);
+            wrapped |= sim.position().y < before - 300.0;
+            if sim.is_finished() {
+                break;
+            }
+        }
+        assert!(wrapped);
+    }
+
+    #[test]
+    fn test_identical_inputs_are_deterministic() {
+        let mut a = flat_sim();
+        let mut b = flat_sim();
+
+        for i in 0..600 {
+            let input = SimInput {
+                thrust: i % 3 == 0,
+                rotate_left: i % 7 == 0,
+                rotate_right: i % 11 == 0,
+                dump_fuel: i % 13 == 0,
+            };
+            assert_eq!(a.step(input, DT), b.step(input, DT));
+        }
+
+        assert_eq!(a.position(), b.position());
+        assert_eq!(a.velocity(), b.velocity());
+        assert_eq!(a.rotation(), b.rotation());
+        assert_eq!(a.rocket().fuel_mass, b.rocket().fuel_mass);
+    }
+}
@@ -0,0 +1,103 @@
+//! Procedural starfield drawn behind the terrain and lander.
+//!
+//! Stars are generated once from a fixed seed, so the sky looks the same on
+//! every run. Positions are fractions of the screen, which keeps the field
+//! filling the window after a resize. The field drifts slightly against the
+//! lander's motion for a hint of parallax.
+
+use macroquad::prelude::*;
+use ::rand::rngs::StdRng;
+use ::rand::{Rng, SeedableRng};
+
+use crate::utils::pixel_to_terrain_x;
+
+/// Seed for the star layout
+const STARFIELD_SEED: u64 = 1969;
+/// Number of stars in the sky
+const STAR_COUNT: usize = 150;
+/// Fraction of the lander's offset from the screen center the stars shift by
+const PARALLAX: f32 = 0.02;
+
+/// A single star
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Star {
+    position: Vec2,  // Fraction of the screen size (0.0-1.0), top-left origin
+    radius: f32,     // Pixels
+    brightness: f32, // Gray level (0.0-1.0)
+}
+
+/// Fixed set of background stars
+#[derive(Debug, Clone)]
+pub struct Starfield {
+    stars: Vec<Star>,
+}
+
+impl Default for Starfield {
+    fn default() -> Self {
+        Self::generate(STAR_COUNT, STARFIELD_SEED)
+    }
+}
+
+impl Starfield {
+    /// Generates `count` stars from `seed`; the same seed gives the same sky.
+    pub fn generate(count: usize, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let stars = (0..count)
+            .map(|_| Star {
+                position: vec2(rng.gen_range(0.0..1.0), rng.gen_range(0.0..1.0)),
+                radius: if rng.gen_bool(0.1) { 1.5 } else { 1.0 },
+                brightness: rng.gen_range(0.35..1.0),
+            })
+            .collect();
+        Self { stars }
+    }
+
+    /// Draws the stars in screen coordinates, above the terrain line only.
+    ///
+    /// Call before anything else is drawn so the stars stay behind the terrain,
+    /// lander and HUD.
+    ///
+    /// # Arguments
+    ///
+    /// * `lander_center` - Lander center in camera coordinates, for the parallax shift
+    /// * `camera` - Camera the terrain is drawn with
+    /// * `terrain` - Terrain heights in camera coordinates, one per terrain point
+    /// * `world_width` - Width in pixels the terrain spans
+    pub fn draw(&self, lander_center: Vec2, camera: &Camera2D, terrain: &[f64], world_width: f32) {
+        let screen = vec2(screen_width(), screen_height());
+        // Camera Y points up and screen Y down, so the vertical shift flips
+        let shift = (lander_center - screen / 2.0) * vec2(-PARALLAX, PARALLAX);
+
+        set_default_camera();
+        for star in &self.stars {
+            let position = (star.position * screen + shift).rem_euclid(screen);
+
+            // Stars don't shine through the ground
+            let world = camera.screen_to_world(position);
+            let ground = terrain
+                .get(pixel_to_terrain_x(world.x, terrain.len(), world_width).max(0.0) as usize)
+                .map_or(0.0, |height| *height as f32);
+            if world.y < ground {
+                continue;
+            }
+
+            let gray = star.brightness;
+            draw_circle(position.x, position.y, star.radius, Color::new(gray, gray, gray, 1.0));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starfield_is_seeded() {
+        let sky = Starfield::generate(50, 7);
+        assert_eq!(sky.stars, Starfield::generate(50, 7).stars);
+        assert_ne!(sky.stars, Starfield::generate(50, 8).stars);
+        assert!(sky.stars.iter().all(|star| {
+            (0.0..1.0).contains(&star.position.x) && (0.0..1.0).contains(&star.position.y)
+        }));
+    }
+}
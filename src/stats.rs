@@ -0,0 +1,146 @@
+//! Lifetime statistics across every session played.
+//!
+//! The totals live in a JSON file next to the game (`STATS_FILE`), read at
+//! startup and rewritten after every attempt. A missing or corrupt file
+//! starts the totals at zero.
+
+use std::fs;
+use std::io;
+
+use macroquad::logging::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::body::CelestialBody;
+use crate::session::{AttemptResult, LandingAttempt};
+use crate::surface::LandingZoneDifficulty;
+
+/// File the lifetime statistics are kept in (JSON, next to `scores.json`)
+pub const STATS_FILE: &str = "lifetime_stats.json";
+
+/// Zone difficulties in the order their landing counts are stored
+const ZONE_ORDER: [LandingZoneDifficulty; 3] = [
+    LandingZoneDifficulty::Hard,
+    LandingZoneDifficulty::Medium,
+    LandingZoneDifficulty::Easy,
+];
+
+/// Totals over all sessions ever played
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LifetimeStats {
+    pub sessions_played: u32, // Sessions played through all their attempts
+    pub landings: u32,        // Successful attempts
+    pub crashes: u32,         // Failed attempts
+    pub best_score: f32,      // Highest single-attempt score
+    pub fuel_saved: f32,      // Sum of fuel percentages left at touchdown
+    pub zone_landings: [u32; 3], // Landings per zone difficulty (Hard, Medium, Easy)
+    pub body_landings: [u32; 4], // Landings per body, in `CelestialBody::ALL` order
+}
+
+impl LifetimeStats {
+    /// Reads the statistics file, starting from zero if it is missing or corrupt.
+    pub fn load(path: &str) -> Self {
+        match fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).unwrap_or_else(|err| {
+                warn!("Ignoring corrupt lifetime stats {}: {} - starting at zero", path, err);
+                Self::default()
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(err) => {
+                warn!("Could not read {}: {} - starting lifetime stats at zero", path, err);
+                Self::default()
+            }
+        }
+    }
+
+    /// Writes the statistics file.
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json)
+    }
+
+    /// Adds a finished attempt to the totals.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - The completed attempt
+    /// * `body` - Body the attempt was flown on
+    pub fn record_attempt(&mut self, attempt: &LandingAttempt, body: CelestialBody) {
+        match attempt.result {
+            AttemptResult::Success => {
+                self.landings += 1;
+                if let Some(slot) = CelestialBody::ALL.iter().position(|&landed_on| landed_on == body) {
+                    self.body_landings[slot] += 1;
+                }
+                self.fuel_saved += attempt.fuel_remaining;
+                self.best_score = self.best_score.max(attempt.score);
+                if let Some(zone) = attempt.landing_zone {
+                    if let Some(slot) = ZONE_ORDER.iter().position(|&difficulty| difficulty == zone) {
+                        self.zone_landings[slot] += 1;
+                    }
+                }
+            }
+            AttemptResult::Failure => self.crashes += 1,
+            AttemptResult::InProgress => {}
+        }
+    }
+
+    /// Zone difficulty landed on most often (the harder one on a tie).
+    pub fn favorite_zone(&self) -> Option<LandingZoneDifficulty> {
+        let (slot, &count) = self
+            .zone_landings
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, &count)| count)?;
+        (count > 0).then_some(ZONE_ORDER[slot])
+    }
+
+    /// Body landed on most often (the earlier one in `CelestialBody::ALL` on a tie).
+    pub fn favorite_body(&self) -> Option<CelestialBody> {
+        let (slot, &count) = self
+            .body_landings
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, &count)| count)?;
+        (count > 0).then_some(CelestialBody::ALL[slot])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lifetime_stats_round_trip() {
+        let path = std::env::temp_dir().join(format!("lunar_lander_stats_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut stats = LifetimeStats::load(path);
+        assert_eq!(stats, LifetimeStats::default(), "First run starts at zero");
+        assert_eq!(stats.favorite_zone(), None);
+        assert_eq!(stats.favorite_body(), None);
+
+        stats.record_attempt(&LandingAttempt::new_completed(AttemptResult::Success, Some(LandingZoneDifficulty::Medium), None, 40.0, 30.0), CelestialBody::Mars);
+        stats.record_attempt(&LandingAttempt::new_completed(AttemptResult::Success, Some(LandingZoneDifficulty::Hard), None, 60.0, 30.0), CelestialBody::Mars);
+        stats.record_attempt(&LandingAttempt::new_completed(AttemptResult::Failure, None, None, 10.0, 20.0), CelestialBody::Moon);
+        stats.sessions_played += 1;
+
+        assert_eq!((stats.landings, stats.crashes), (2, 1));
+        assert_eq!(stats.fuel_saved, 100.0);
+        assert_eq!(stats.best_score, 3840.0, "Hard zone, 60% fuel, under a minute");
+        assert_eq!(stats.favorite_zone(), Some(LandingZoneDifficulty::Hard), "Ties go to the harder zone");
+        assert_eq!(stats.favorite_body(), Some(CelestialBody::Mars), "Crashes don't count toward a favorite");
+
+        stats.save(path).unwrap();
+        assert_eq!(LifetimeStats::load(path), stats);
+
+        // Fields missing from the file start at zero; a corrupt file starts over
+        fs::write(path, r#"{ "landings": 5 }"#).unwrap();
+        let partial = LifetimeStats::load(path);
+        assert_eq!((partial.landings, partial.crashes), (5, 0));
+        fs::write(path, "landings = 5").unwrap();
+        assert_eq!(LifetimeStats::load(path), LifetimeStats::default());
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -7,11 +7,15 @@
 
 extern crate noise;
 
-use macroquad::logging::debug;
-use noise::{NoiseFn, Perlin};
-use rand::Rng;
+use std::ops::RangeInclusive;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+use macroquad::logging::{debug, warn};
+use noise::{NoiseFn, Perlin, Seedable};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum LandingZoneDifficulty {
     Hard,   // 1.0x lander width
     Medium, // 1.25x lander width  
@@ -34,6 +38,64 @@ impl LandingZoneDifficulty {
         }
     }
     
+    /// Returns how uneven the surface of this zone is.
+    ///
+    /// The value is the height of the bumps as a fraction of the terrain
+    /// height range. Harder zones are rougher, tilting the ground under the
+    /// legs by a few degrees; Easy zones stay perfectly flat.
+    ///
+    /// # Returns
+    ///
+    /// * `0.03` - Hard difficulty
+    /// * `0.015` - Medium difficulty
+    /// * `0.0` - Easy difficulty
+    pub fn roughness(&self) -> f64 {
+        match self {
+            LandingZoneDifficulty::Hard => 0.03,
+            LandingZoneDifficulty::Medium => 0.015,
+            LandingZoneDifficulty::Easy => 0.0,
+        }
+    }
+
+    /// Returns the fraction of the tuned landing limits this zone accepts.
+    ///
+    /// # Returns
+    ///
+    /// * `0.6` - Hard difficulty (strictest)
+    /// * `0.8` - Medium difficulty
+    /// * `1.0` - Easy difficulty (the full tuned limits)
+    pub fn landing_limit_multiplier(&self) -> f32 {
+        match self {
+            LandingZoneDifficulty::Hard => 0.6,
+            LandingZoneDifficulty::Medium => 0.8,
+            LandingZoneDifficulty::Easy => 1.0,
+        }
+    }
+
+    /// Returns the fastest safe touchdown on this zone, in m/s.
+    ///
+    /// With the default 10 m/s limit this is 6 m/s on Hard, 8 m/s on Medium
+    /// and 10 m/s on Easy zones.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_landing_velocity` - Tuned landing speed limit (see `LandingTuning`)
+    pub fn max_velocity(&self, max_landing_velocity: f32) -> f32 {
+        max_landing_velocity * self.landing_limit_multiplier()
+    }
+
+    /// Returns the largest safe tilt from the surface normal on this zone, in degrees.
+    ///
+    /// With the default 15° limit this is 9° on Hard, 12° on Medium and 15°
+    /// on Easy zones.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_landing_angle_degrees` - Tuned landing angle limit (see `LandingTuning`)
+    pub fn max_angle(&self, max_landing_angle_degrees: f32) -> f32 {
+        max_landing_angle_degrees * self.landing_limit_multiplier()
+    }
+
     /// Returns the human-readable name of this difficulty level.
     ///
     /// # Returns
@@ -62,6 +124,29 @@ impl LandingZoneDifficulty {
     pub fn score(&self) -> f32 {
         2.0 / self.width_multiplier()
     }
+
+    /// Returns the marker shape drawn beside this zone in colorblind mode.
+    ///
+    /// # Returns
+    ///
+    /// * `ZoneSymbol::Triangle` - Hard difficulty (a warning sign)
+    /// * `ZoneSymbol::Diamond` - Medium difficulty
+    /// * `ZoneSymbol::Circle` - Easy difficulty
+    pub fn symbol(&self) -> ZoneSymbol {
+        match self {
+            LandingZoneDifficulty::Hard => ZoneSymbol::Triangle,
+            LandingZoneDifficulty::Medium => ZoneSymbol::Diamond,
+            LandingZoneDifficulty::Easy => ZoneSymbol::Circle,
+        }
+    }
+}
+
+/// Marker shape telling zone difficulties apart without relying on color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZoneSymbol {
+    Triangle,
+    Diamond,
+    Circle,
 }
 
 #[derive(Debug, Clone)]
@@ -70,14 +155,165 @@ pub struct LandingZone {
     pub end: usize,
     pub difficulty: LandingZoneDifficulty,
     pub width_points: usize,
+    pub motion: Option<ZoneMotion>, // Set for a pad that slides back and forth; None for a fixed zone
+}
+
+/// Horizontal oscillation of a moving landing pad.
+///
+/// The pad carries its own flat surface along with it. It remembers the
+/// terrain heights it is currently sitting on and puts them back as it slides
+/// off a column, so the ground behind it (craters included) reappears as it
+/// was. The ground where the zone was generated was already flattened by the
+/// terrain generator, so that spot stays flat after the pad leaves it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneMotion {
+    pub velocity: f32,      // Terrain points per second; the sign is the current direction
+    pub min_start: usize,   // Leftmost start position
+    pub max_start: usize,   // Rightmost start position
+    position: f32,          // Exact start position; `start` is this rounded down
+    height: f64,            // Height of the pad surface
+    covered: Vec<f64>,      // Terrain heights under the pad, restored as it moves off them
+}
+
+impl LandingZone {
+    /// Sets the zone sliding between two start positions.
+    ///
+    /// The pad takes the height of the zone's first point and is flattened
+    /// into the terrain straight away, smoothing out any roughness.
+    ///
+    /// # Arguments
+    ///
+    /// * `terrain` - Terrain heights the zone was laid out on
+    /// * `speed` - Pad speed in terrain points per second
+    /// * `min_start` - Leftmost start position the pad reaches
+    /// * `max_start` - Rightmost start position the pad reaches
+    pub fn set_moving(&mut self, terrain: &mut [f64], speed: f32, min_start: usize, max_start: usize) {
+        let last_start = terrain.len().saturating_sub(self.width_points);
+        let max_start = max_start.min(last_start);
+        let min_start = min_start.min(max_start);
+        let start = self.start.clamp(min_start, max_start);
+        let height = terrain.get(self.start).copied().unwrap_or_default();
+
+        let end = (start + self.width_points).min(terrain.len());
+        let covered = terrain[start..end].to_vec();
+        terrain[start..end].fill(height);
+
+        self.start = start;
+        self.end = start + self.width_points - 1;
+        self.motion = Some(ZoneMotion {
+            velocity: speed,
+            min_start,
+            max_start,
+            position: start as f32,
+            height,
+            covered,
+        });
+    }
+}
+
+/// Start positions a moving pad can slide between.
+///
+/// The track reaches up to `reach` points either side of the zone's current
+/// start, stopping `ZONE_EDGE_MARGIN` points from the terrain ends and
+/// `MOVING_PAD_CLEARANCE` points from any other zone.
+///
+/// # Arguments
+///
+/// * `landing_zones` - Zones laid out on the terrain
+/// * `index` - Index of the zone that will move
+/// * `num_points` - Number of terrain points
+/// * `reach` - Largest distance from the current start, in terrain points
+///
+/// # Returns
+///
+/// `(min_start, max_start)`, both equal to the current start if there is no room
+pub fn moving_pad_track(landing_zones: &[LandingZone], index: usize, num_points: usize, reach: usize) -> (usize, usize) {
+    let zone = &landing_zones[index];
+    let mut min_start = zone.start.saturating_sub(reach).max(ZONE_EDGE_MARGIN);
+    let mut max_start = (zone.start + reach).min(num_points.saturating_sub(zone.width_points + ZONE_EDGE_MARGIN));
+
+    for (i, other) in landing_zones.iter().enumerate() {
+        if i == index {
+            continue;
+        }
+        if other.end < zone.start {
+            min_start = min_start.max(other.end + MOVING_PAD_CLEARANCE + 1);
+        } else if other.start > zone.end {
+            max_start = max_start.min(other.start.saturating_sub(MOVING_PAD_CLEARANCE + zone.width_points));
+        }
+    }
+
+    (min_start.min(zone.start), max_start.max(zone.start))
+}
+
+/// Advances every moving pad and carries its flat surface along.
+///
+/// A pad reverses when it reaches either end of its track. Fixed zones are
+/// left alone. Only the columns the pad leaves or enters change height, so
+/// call this once per frame while the lander flies.
+///
+/// # Arguments
+///
+/// * `terrain` - Terrain heights the zones sit on
+/// * `landing_zones` - Zones to move
+/// * `dt` - Time step in seconds
+pub fn update_zones(terrain: &mut [f64], landing_zones: &mut [LandingZone], dt: f32) {
+    for zone in landing_zones {
+        let Some(motion) = zone.motion.as_mut() else {
+            continue;
+        };
+
+        // Bounce off the ends of the track
+        motion.position += motion.velocity * dt;
+        if motion.position <= motion.min_start as f32 {
+            motion.position = motion.min_start as f32;
+            motion.velocity = motion.velocity.abs();
+        } else if motion.position >= motion.max_start as f32 {
+            motion.position = motion.max_start as f32;
+            motion.velocity = -motion.velocity.abs();
+        }
+
+        let start = motion.position as usize;
+        if start == zone.start {
+            continue;
+        }
+
+        // Put back the ground the pad was on, then pick up the ground it moves onto
+        let end = (zone.start + motion.covered.len()).min(terrain.len());
+        terrain[zone.start..end].copy_from_slice(&motion.covered[..end - zone.start]);
+        let end = (start + zone.width_points).min(terrain.len());
+        motion.covered = terrain[start..end].to_vec();
+        terrain[start..end].fill(motion.height);
+
+        zone.start = start;
+        zone.end = start + zone.width_points - 1;
+    }
 }
 
-/// Generates procedural terrain with 1-3 randomly placed landing zones of varying difficulty.
+/// Number of points in a generated terrain, whatever the window width.
+///
+/// Points are spread evenly across the world, so terrain detail and all
+/// point-based distances (zone widths, spacing) stay the same when the
+/// window is resized; see `utils::terrain_x_to_pixel` for the mapping.
+pub const TERRAIN_POINTS: usize = 1000;
+/// Default range for the number of landing zones on a terrain
+pub const DEFAULT_ZONE_COUNT: RangeInclusive<usize> = 1..=3;
+/// Minimum points between neighbouring landing zones
+const ZONE_SPACING: usize = 150;
+/// Points kept clear of zones at each end of the terrain
+const ZONE_EDGE_MARGIN: usize = 100;
+/// Points a moving pad keeps clear of the other zones
+const MOVING_PAD_CLEARANCE: usize = 50;
+
+/// Generates procedural terrain with randomly placed landing zones of varying difficulty.
 ///
 /// This function creates realistic lunar terrain using multi-octave Perlin noise while
 /// integrating flat landing zones. The zones are strategically placed to avoid overlaps
 /// and provide a good gameplay experience.
 ///
+/// The seed drives both the zone placement and the noise, so the same seed
+/// and settings always produce the same terrain and zones.
+///
 /// # Arguments
 ///
 /// * `num_points` - Number of terrain points to generate
@@ -87,6 +323,8 @@ pub struct LandingZone {
 /// * `octaves` - Number of noise octaves for terrain detail
 /// * `persistence` - Persistence factor for noise octaves
 /// * `lander_width_points` - Width of the lander in terrain points
+/// * `zone_count_range` - How many landing zones to place (see `place_landing_zones`)
+/// * `seed` - Seed for the zone layout and the terrain noise
 ///
 /// # Returns
 ///
@@ -94,21 +332,15 @@ pub struct LandingZone {
 /// * `Vec<f64>` - Generated terrain heights
 /// * `Vec<LandingZone>` - Landing zones with positions and difficulty levels
 ///
-/// # Zone Generation
-///
-/// - Generates 1-3 landing zones randomly
-/// - Each zone has a random difficulty (Hard/Medium/Easy)
-/// - Zones are spaced at least 150 points apart
-/// - Fallback to single zone if spacing constraints can't be met
-///
 /// # Example
 ///
 /// ```rust
 /// let (terrain, zones) = generate_terrain_with_multiple_landing_zones(
-///     1000, 0.0, 100.0, 0.01, 6, 0.5, 36
+///     1000, 0.0, 100.0, 0.01, 6, 0.5, 36, DEFAULT_ZONE_COUNT, 42
 /// );
 /// println!("Generated {} zones", zones.len());
 /// ```
+#[allow(clippy::too_many_arguments)]
 pub fn generate_terrain_with_multiple_landing_zones(
     num_points: usize,
     min_height: f64,
@@ -117,13 +349,105 @@ pub fn generate_terrain_with_multiple_landing_zones(
     octaves: u32,
     persistence: f64,
     lander_width_points: usize,
+    zone_count_range: RangeInclusive<usize>,
+    seed: u64,
 ) -> (Vec<f64>, Vec<LandingZone>) {
-    let perlin = Perlin::new();
-    let mut terrain = Vec::with_capacity(num_points);
-    let mut rng = rand::thread_rng();
+    let landing_zones = place_landing_zones_with_rng(
+        &mut StdRng::seed_from_u64(seed),
+        num_points,
+        lander_width_points,
+        zone_count_range,
+    );
+
+    debug!("Generated {} landing zones:", landing_zones.len());
+    for (i, zone) in landing_zones.iter().enumerate() {
+        debug!("  Zone {}: {} difficulty, positions {}-{} ({} points)", 
+               i + 1, zone.difficulty.name(), zone.start, zone.end, zone.width_points);
+    }
+
+    let mut terrain = generate_terrain_heights(
+        num_points,
+        min_height,
+        max_height,
+        base_frequency,
+        octaves,
+        persistence,
+        &landing_zones,
+        noise_seed(seed),
+    );
+    roughen_landing_zones(&mut terrain, &landing_zones, max_height - min_height);
 
-    // Create 1-3 landing zones with different difficulties
-    let num_zones = rng.gen_range(1..=3);
+    (terrain, landing_zones)
+}
+
+/// Adds each zone's surface roughness on top of its flat height.
+///
+/// A zone gets one full sine wave across its width, with an amplitude of
+/// `roughness() * height_range` and a phase taken from its start position,
+/// so the left and right legs rest at different heights. The profile is
+/// deterministic for a given layout.
+///
+/// # Arguments
+///
+/// * `terrain` - Terrain heights with flat zones
+/// * `landing_zones` - Zones laid out on the terrain
+/// * `height_range` - Height range the terrain was generated in
+pub fn roughen_landing_zones(terrain: &mut [f64], landing_zones: &[LandingZone], height_range: f64) {
+    for zone in landing_zones {
+        let amplitude = zone.difficulty.roughness() * height_range;
+        if amplitude <= 0.0 || zone.start >= terrain.len() {
+            continue;
+        }
+
+        let end = zone.end.min(terrain.len() - 1);
+        let span = (end - zone.start).max(1) as f64;
+        let phase = zone.start as f64;
+        for (offset, height) in terrain[zone.start..=end].iter_mut().enumerate() {
+            let t = offset as f64 / span;
+            *height += amplitude * (std::f64::consts::TAU * t + phase).sin();
+        }
+    }
+}
+
+/// Picks landing zone positions and difficulties for a terrain.
+///
+/// # Zone Generation
+///
+/// - Picks a zone count from `zone_count_range` (an empty range is treated as its start)
+/// - Each zone has a random difficulty (Hard/Medium/Easy)
+/// - Zones are spaced at least 150 points apart and 100 points from the terrain ends
+/// - If the terrain is too narrow for the requested count, as many zones as
+///   fit are placed (at least one) and a warning is logged
+///
+/// # Arguments
+///
+/// * `num_points` - Number of terrain points
+/// * `lander_width_points` - Width of the lander in terrain points
+/// * `zone_count_range` - Minimum and maximum number of zones
+///
+/// # Returns
+///
+/// Landing zones sorted by start position
+pub fn place_landing_zones(
+    num_points: usize,
+    lander_width_points: usize,
+    zone_count_range: RangeInclusive<usize>,
+) -> Vec<LandingZone> {
+    place_landing_zones_with_rng(&mut rand::thread_rng(), num_points, lander_width_points, zone_count_range)
+}
+
+/// Picks landing zones like `place_landing_zones`, drawing from the given RNG.
+///
+/// With a seeded RNG the layout is reproducible.
+pub fn place_landing_zones_with_rng<R: Rng + ?Sized>(
+    rng: &mut R,
+    num_points: usize,
+    lander_width_points: usize,
+    zone_count_range: RangeInclusive<usize>,
+) -> Vec<LandingZone> {
+
+    let (min_zones, max_zones) = (*zone_count_range.start(), *zone_count_range.end());
+    let requested = rng.gen_range(min_zones..=max_zones.max(min_zones));
     let mut landing_zones = Vec::new();
     
     // Define difficulty levels to choose from
@@ -133,137 +457,202 @@ pub fn generate_terrain_with_multiple_landing_zones(
         LandingZoneDifficulty::Easy,
     ];
     
-    // Calculate spacing to ensure zones don't overlap
-    let zone_spacing = 150; // Minimum points between zones
+    // Calculate how many zones fit without overlapping
     let max_zone_width = (lander_width_points as f32 * 1.5) as usize; // Largest possible zone
-    let available_space = num_points - 200; // Leave margins on both sides
-    let total_space_needed = num_zones * (max_zone_width + zone_spacing);
-    
-    if total_space_needed > available_space {
-        // Fallback to single zone if we can't fit multiple zones
+    let available_space = num_points.saturating_sub(2 * ZONE_EDGE_MARGIN); // Leave margins on both sides
+    let zones_that_fit = (available_space / (max_zone_width + ZONE_SPACING)).max(1);
+    let num_zones = if requested > zones_that_fit {
+        warn!(
+            "{} landing zones requested but only {} fit on {} points",
+            requested, zones_that_fit, num_points
+        );
+        zones_that_fit
+    } else {
+        requested.max(1)
+    };
+
+    // Generate non-overlapping zones
+    let mut positions = Vec::new();
+
+    for _i in 0..num_zones {
         let difficulty = difficulties[rng.gen_range(0..difficulties.len())];
         let width_points = (lander_width_points as f32 * difficulty.width_multiplier()) as usize;
-        let start = rng.gen_range(100..(num_points - width_points - 100));
-        let end = start + width_points - 1;
-        
-        landing_zones.push(LandingZone {
-            start,
-            end,
-            difficulty,
-            width_points,
-        });
-    } else {
-        // Generate multiple non-overlapping zones
-        let mut positions = Vec::new();
-        
-        for _i in 0..num_zones {
-            let difficulty = difficulties[rng.gen_range(0..difficulties.len())];
-            let width_points = (lander_width_points as f32 * difficulty.width_multiplier()) as usize;
-            
-            // Find a position that doesn't overlap with existing zones
-            let mut attempts = 0;
-            loop {
-                let start = rng.gen_range(100..(num_points - width_points - 100));
-                let end = start + width_points - 1;
-                
-                // Check if this overlaps with any existing zone
-                let overlaps = positions.iter().any(|(existing_start, existing_end)| {
-                    !(end + zone_spacing < *existing_start || start > *existing_end + zone_spacing)
+        let last_start = num_points.saturating_sub(width_points + ZONE_EDGE_MARGIN).max(ZONE_EDGE_MARGIN + 1);
+
+        // Find a position that doesn't overlap with existing zones
+        let mut attempts = 0;
+        loop {
+            let start = rng.gen_range(ZONE_EDGE_MARGIN..last_start);
+            let end = start + width_points - 1;
+
+            // Check if this overlaps with any existing zone
+            let overlaps = positions.iter().any(|(existing_start, existing_end)| {
+                !(end + ZONE_SPACING < *existing_start || start > *existing_end + ZONE_SPACING)
+            });
+
+            if !overlaps || attempts > 50 {
+                positions.push((start, end));
+                landing_zones.push(LandingZone {
+                    start,
+                    end,
+                    difficulty,
+                    width_points,
+                    motion: None,
                 });
-                
-                if !overlaps || attempts > 50 {
-                    positions.push((start, end));
-                    landing_zones.push(LandingZone {
-                        start,
-                        end,
-                        difficulty,
-                        width_points,
-                    });
-                    break;
-                }
-                attempts += 1;
+                break;
             }
+            attempts += 1;
         }
     }
     
     // Sort zones by position for easier processing
     landing_zones.sort_by_key(|zone| zone.start);
-    
-    debug!("Generated {} landing zones:", landing_zones.len());
-    for (i, zone) in landing_zones.iter().enumerate() {
-        debug!("  Zone {}: {} difficulty, positions {}-{} ({} points)", 
-               i + 1, zone.difficulty.name(), zone.start, zone.end, zone.width_points);
+    landing_zones
+}
+
+/// Folds a 64-bit terrain seed into the 32-bit seed the Perlin noise takes.
+pub fn noise_seed(seed: u64) -> u32 {
+    (seed ^ (seed >> 32)) as u32
+}
+
+/// Places the single Easy zone of practice mode directly under a given X.
+///
+/// The zone is centered on `center_x` where possible and shifted just enough
+/// to stay on the terrain when `center_x` is near an end.
+///
+/// # Arguments
+///
+/// * `num_points` - Number of terrain points
+/// * `lander_width_points` - Lander width in terrain points
+/// * `center_x` - Terrain X the zone should be centered on (the lander's start)
+pub fn practice_landing_zone(num_points: usize, lander_width_points: usize, center_x: f32) -> LandingZone {
+    let difficulty = LandingZoneDifficulty::Easy;
+    let width_points = ((lander_width_points as f32 * difficulty.width_multiplier()) as usize).clamp(1, num_points.max(1));
+    let start = (center_x - width_points as f32 / 2.0)
+        .round()
+        .clamp(0.0, num_points.saturating_sub(width_points) as f32) as usize;
+    LandingZone {
+        start,
+        end: start + width_points - 1,
+        difficulty,
+        width_points,
+        motion: None,
+    }
+}
+
+/// Generates terrain heights around already placed landing zones.
+///
+/// This is the deterministic hot path of terrain generation: for the same
+/// arguments it always returns the same heights. It is written for wide
+/// terrains (high-DPI windows, frequent rerolls):
+///
+/// - Octave frequencies and the amplitude normalization are computed once
+/// - Zone membership comes from a per-point lookup table instead of scanning
+///   every zone for every point
+/// - The terrain range is tracked while generating, leaving a single scaling pass
+///
+/// Each landing zone is flat at the noise height of its first point. Where
+/// zones overlap, the zone that comes first in `landing_zones` wins.
+///
+/// # Arguments
+///
+/// * `num_points` - Number of terrain points to generate
+/// * `min_height` - Minimum terrain height
+/// * `max_height` - Maximum terrain height
+/// * `base_frequency` - Base frequency for Perlin noise
+/// * `octaves` - Number of noise octaves for terrain detail
+/// * `persistence` - Persistence factor for noise octaves
+/// * `landing_zones` - Landing zones to flatten, sorted by start
+/// * `seed` - Perlin noise seed (see `noise_seed`)
+///
+/// # Returns
+///
+/// Terrain heights scaled to `[min_height, max_height]`, or all at the
+/// mid-range height if the noise field is flat
+#[allow(clippy::too_many_arguments)]
+pub fn generate_terrain_heights(
+    num_points: usize,
+    min_height: f64,
+    max_height: f64,
+    base_frequency: f64,
+    octaves: u32,
+    persistence: f64,
+    landing_zones: &[LandingZone],
+    seed: u32,
+) -> Vec<f64> {
+    let perlin = Perlin::new().set_seed(seed);
+
+    // Per-octave (frequency, amplitude), accumulated exactly as the original loop did
+    let mut octave_params = Vec::with_capacity(octaves as usize);
+    let mut amplitude = 1.0;
+    let mut frequency = base_frequency;
+    let mut max_amplitude = 0.0;
+    for _ in 0..octaves {
+        octave_params.push((frequency, amplitude));
+        max_amplitude += amplitude;
+        amplitude *= persistence;
+        frequency *= 2.0;
+    }
+
+    let noise_at = |i: usize| {
+        let mut height = 0.0;
+        for &(frequency, amplitude) in &octave_params {
+            height += perlin.get([i as f64 * frequency, 0.0]) * amplitude;
+        }
+        if max_amplitude > 0.0 {
+            height / max_amplitude // Normalize
+        } else {
+            0.0 // No octaves: flat
+        }
+    };
+
+    // Zone lookup table, painted last-to-first so earlier zones win overlaps
+    let mut zone_at: Vec<Option<usize>> = vec![None; num_points];
+    for (zone_idx, zone) in landing_zones.iter().enumerate().rev() {
+        let end = zone.end.min(num_points.saturating_sub(1));
+        for slot in zone_at.iter_mut().take(end + 1).skip(zone.start) {
+            *slot = Some(zone_idx);
+        }
     }
 
-    // Generate terrain using Perlin noise with integrated flat spots
+    // Generate terrain with integrated flat spots, tracking the range as we go
+    let mut terrain = Vec::with_capacity(num_points);
     let mut zone_heights = vec![0.0; landing_zones.len()];
-    
-    for i in 0..num_points {
-        let height = {
-            // Check if this point is in any landing zone
-            let mut in_zone = None;
-            for (zone_idx, zone) in landing_zones.iter().enumerate() {
-                if i >= zone.start && i <= zone.end {
-                    in_zone = Some(zone_idx);
-                    break;
-                }
-            }
-            
-            if let Some(zone_idx) = in_zone {
-                let zone = &landing_zones[zone_idx];
-                if i == zone.start {
-                    // First point of flat spot - generate the height using Perlin noise
-                    let mut height = 0.0;
-                    let mut amplitude = 1.0;
-                    let mut frequency = base_frequency;
-                    let mut max_amplitude = 0.0;
-
-                    for _ in 0..octaves {
-                        height += perlin.get([i as f64 * frequency, 0.0]) * amplitude;
-                        max_amplitude += amplitude;
-                        amplitude *= persistence;
-                        frequency *= 2.0;
-                    }
-                    height /= max_amplitude; // Normalize
-                    
-                    // Store this height for the entire flat section
-                    zone_heights[zone_idx] = height;
-                    height
-                } else {
-                    // Rest of flat spot - use the same height as the first point
-                    zone_heights[zone_idx]
-                }
-            } else {
-                // Normal terrain - generate Perlin noise
-                let mut height = 0.0;
-                let mut amplitude = 1.0;
-                let mut frequency = base_frequency;
-                let mut max_amplitude = 0.0;
-
-                for _ in 0..octaves {
-                    height += perlin.get([i as f64 * frequency, 0.0]) * amplitude;
-                    max_amplitude += amplitude;
-                    amplitude *= persistence;
-                    frequency *= 2.0;
-                }
-                height /= max_amplitude; // Normalize
-                height
+    let mut terrain_min = f64::INFINITY;
+    let mut terrain_max = f64::NEG_INFINITY;
+
+    for (i, zone) in zone_at.iter().enumerate() {
+        let height = match zone {
+            // First point of flat spot - generate the height and hold it for the whole zone
+            Some(zone_idx) if i == landing_zones[*zone_idx].start => {
+                zone_heights[*zone_idx] = noise_at(i);
+                zone_heights[*zone_idx]
             }
+            // Rest of flat spot - use the same height as the first point
+            Some(zone_idx) => zone_heights[*zone_idx],
+            // Normal terrain - generate Perlin noise
+            None => noise_at(i),
         };
-        
+
+        terrain_min = terrain_min.min(height);
+        terrain_max = terrain_max.max(height);
         terrain.push(height);
     }
 
-    // Normalize and scale the terrain to the desired height range
-    let terrain_min = terrain.iter().cloned().fold(f64::INFINITY, f64::min);
-    let terrain_max = terrain.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    // A flat noise field (or a single point) has no range to normalize by
+    let range = terrain_max - terrain_min;
+    if !(range > 0.0 && range.is_finite()) {
+        terrain.fill((min_height + max_height) / 2.0);
+        return terrain;
+    }
 
+    // Normalize and scale the terrain to the desired height range
     terrain.iter_mut().for_each(|h| {
-        *h = (*h - terrain_min) / (terrain_max - terrain_min); // Normalize to [0, 1]
+        *h = (*h - terrain_min) / range; // Normalize to [0, 1]
         *h = *h * (max_height - min_height) + min_height; // Scale to [min_height, max_height]
     });
 
-    (terrain, landing_zones)
+    terrain
 }
 
 /// Legacy function for backward compatibility with single flat spot generation.
@@ -281,12 +670,14 @@ pub fn generate_terrain_with_multiple_landing_zones(
 /// * `octaves` - Number of noise octaves for terrain detail
 /// * `persistence` - Persistence factor for noise octaves
 /// * `lander_width_points` - Width of the lander in terrain points
+/// * `seed` - Seed for the zone position and the terrain noise
 ///
 /// # Returns
 ///
 /// A tuple containing:
 /// * `Vec<f64>` - Generated terrain heights
 /// * `(usize, usize)` - Flat spot range (start_index, end_index)
+#[allow(clippy::too_many_arguments)]
 pub fn generate_terrain_with_flat_spot(
     num_points: usize,
     min_height: f64,
@@ -295,6 +686,7 @@ pub fn generate_terrain_with_flat_spot(
     octaves: u32,
     persistence: f64,
     lander_width_points: usize,
+    seed: u64,
 ) -> (Vec<f64>, (usize, usize)) {
     // Use the new multiple landing zones function and convert to legacy format
     let (terrain, landing_zones) = generate_terrain_with_multiple_landing_zones(
@@ -305,6 +697,8 @@ pub fn generate_terrain_with_flat_spot(
         octaves,
         persistence,
         lander_width_points,
+        1..=1,
+        seed,
     );
     
     // Return the first (or only) landing zone for backward compatibility
@@ -317,10 +711,378 @@ pub fn generate_terrain_with_flat_spot(
     (terrain, flat_spot_range)
 }
 
+/// Terrain height at a fractional X position, linearly interpolated.
+///
+/// # Arguments
+///
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `x` - Position in terrain points
+///
+/// # Returns
+///
+/// Interpolated camera Y of the surface, or `None` if `x` is off the terrain
+pub fn terrain_height_at(terrain: &[f64], x: f32) -> Option<f32> {
+    if x < 0.0 || terrain.is_empty() || x > (terrain.len() - 1) as f32 {
+        return None;
+    }
+
+    let left = x.floor() as usize;
+    let right = (left + 1).min(terrain.len() - 1);
+    let t = x - left as f32;
+    Some(terrain[left] as f32 * (1.0 - t) + terrain[right] as f32 * t)
+}
+
+/// Local terrain slope (dy/dx) at an X position.
+///
+/// Uses the segment containing `x`, clamped to the terrain ends.
+///
+/// # Arguments
+///
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `x` - Position in terrain points
+///
+/// # Returns
+///
+/// Rise per terrain point of the surface, `0.0` for terrain shorter than two points
+pub fn terrain_slope_at(terrain: &[f64], x: f32) -> f32 {
+    if terrain.len() < 2 {
+        return 0.0;
+    }
+
+    let left = (x.max(0.0) as usize).min(terrain.len() - 2);
+    (terrain[left + 1] - terrain[left]) as f32
+}
+
+/// Highest terrain point under a horizontal span.
+///
+/// # Arguments
+///
+/// * `terrain` - Terrain heights, one per terrain point
+/// * `x` - Left edge of the span in terrain points
+/// * `width` - Span width in terrain points
+///
+/// # Returns
+///
+/// The peak height under the span (clamped to the terrain), or `0.0` for empty terrain
+pub fn peak_height_under(terrain: &[f64], x: f32, width: f32) -> f32 {
+    if terrain.is_empty() {
+        return 0.0;
+    }
+
+    let last = terrain.len() - 1;
+    let start = (x.max(0.0) as usize).min(last);
+    let end = ((x + width).max(0.0) as usize).min(last);
+    terrain[start..=end].iter().copied().fold(f64::MIN, f64::max) as f32
+}
+
+/// Presses a bowl-shaped crater into the terrain.
+///
+/// The crater is deepest at `center_index` and eases to nothing at `radius`
+/// points either side. Points inside a landing zone are never lowered, so
+/// zones stay flat and landable, and heights never drop below zero.
+///
+/// # Arguments
+///
+/// * `terrain` - Terrain heights, one per terrain point, modified in place
+/// * `center_index` - Terrain index of the impact point
+/// * `radius` - Crater half-width in terrain points
+/// * `depth` - Depth at the center of the crater
+/// * `landing_zones` - Zones that must be left untouched
+pub fn deform_terrain_crater(
+    terrain: &mut [f64],
+    center_index: usize,
+    radius: usize,
+    depth: f64,
+    landing_zones: &[LandingZone],
+) {
+    if terrain.is_empty() || radius == 0 || depth <= 0.0 || center_index >= terrain.len() {
+        return;
+    }
+
+    let first = center_index.saturating_sub(radius);
+    let last = (center_index + radius).min(terrain.len() - 1);
+    for i in first..=last {
+        if landing_zones.iter().any(|zone| i >= zone.start && i <= zone.end) {
+            continue;
+        }
+
+        // Cosine bowl: full depth at the center, flat at the rim
+        let distance = i.abs_diff(center_index) as f64 / radius as f64;
+        let falloff = 0.5 * (1.0 + (std::f64::consts::PI * distance).cos());
+        terrain[i] = (terrain[i] - depth * falloff).max(0.0);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_zone_symbols_are_distinct() {
+        let symbols = [
+            LandingZoneDifficulty::Hard.symbol(),
+            LandingZoneDifficulty::Medium.symbol(),
+            LandingZoneDifficulty::Easy.symbol(),
+        ];
+        assert_ne!(symbols[0], symbols[1]);
+        assert_ne!(symbols[1], symbols[2]);
+        assert_ne!(symbols[0], symbols[2]);
+    }
+
+    #[test]
+    fn test_zone_roughness_by_difficulty() {
+        let zone = |start: usize, difficulty| LandingZone { start, end: start + 31, difficulty, width_points: 32, motion: None };
+        let zones = [zone(100, LandingZoneDifficulty::Hard), zone(300, LandingZoneDifficulty::Easy)];
+        let mut terrain = vec![50.0; 500];
+        roughen_landing_zones(&mut terrain, &zones, 100.0);
+
+        // Easy stays pristine, Hard bumps stay within their amplitude
+        assert!(terrain[300..=331].iter().all(|&h| h == 50.0));
+        let hard = &terrain[100..=131];
+        assert!(hard.iter().any(|&h| h != 50.0));
+        assert!(hard.iter().all(|&h| (h - 50.0).abs() <= 3.0 + 1e-9));
+
+        // At the game's 0.4 screen scale, the ground between the leg centers
+        // (70% of a lander width apart) tilts well inside the 15° limit
+        let leg_span = 22;
+        let worst = (0..hard.len() - leg_span)
+            .map(|i| ((hard[i + leg_span] - hard[i]) * 0.4 / leg_span as f64).atan().to_degrees().abs())
+            .fold(0.0, f64::max);
+        assert!(worst > 1.0 && worst < 10.0, "Worst tilt {:.1}°", worst);
+    }
+
+    #[test]
+    fn test_practice_zone_under_start() {
+        let zone = practice_landing_zone(800, 32, 400.0);
+        assert_eq!(zone.difficulty, LandingZoneDifficulty::Easy);
+        assert_eq!(zone.width_points, 48);
+        assert_eq!((zone.start, zone.end), (376, 423));
+
+        // Kept on the terrain near either end
+        assert_eq!(practice_landing_zone(800, 32, 5.0).start, 0);
+        assert_eq!(practice_landing_zone(800, 32, 795.0).end, 799);
+    }
+
+    #[test]
+    fn test_zone_count_range() {
+        // Seeded, so the spacing check doesn't depend on a lucky draw
+        let place = |num_points, zone_count_range| {
+            place_landing_zones_with_rng(&mut StdRng::seed_from_u64(7), num_points, 36, zone_count_range)
+        };
+
+        // Exactly one zone, or five on a terrain wide enough for them
+        assert_eq!(place(1000, 1..=1).len(), 1);
+        let zones = place(3000, 5..=5);
+        assert_eq!(zones.len(), 5);
+        assert!(zones.windows(2).all(|pair| pair[0].end + ZONE_SPACING < pair[1].start), "Zones keep their spacing");
+
+        // Too many for the width: as many as fit, but never none
+        assert_eq!(place(800, 5..=5).len(), 2);
+        assert_eq!(place(300, 3..=3).len(), 1);
+        assert_eq!(place(1000, 0..=0).len(), 1);
+    }
+
+    #[test]
+    fn test_flat_noise_field_stays_finite() {
+        // Zero frequency samples Perlin noise at the origin only, which is 0 everywhere
+        let terrain = generate_terrain_heights(50, 100.0, 300.0, 0.0, 4, 0.5, &[], 0);
+        assert_eq!(terrain.len(), 50);
+        assert!(terrain.iter().all(|&h| h == 200.0), "Flat terrain sits mid-range");
+
+        // No octaves at all, and a single-point terrain
+        let terrain = generate_terrain_heights(10, 100.0, 300.0, 0.02, 0, 0.5, &[], 0);
+        assert!(terrain.iter().all(|&h| h == 200.0));
+        let terrain = generate_terrain_heights(1, 100.0, 300.0, 0.02, 4, 0.5, &[], 0);
+        assert_eq!(terrain, vec![200.0]);
+    }
+
+    #[test]
+    fn test_crater_spares_landing_zones() {
+        let zone = LandingZone {
+            start: 12,
+            end: 16,
+            difficulty: LandingZoneDifficulty::Easy,
+            width_points: 5,
+            motion: None,
+        };
+        let mut terrain = vec![50.0; 20];
+        deform_terrain_crater(&mut terrain, 10, 4, 8.0, &[zone]);
+
+        assert_eq!(terrain[10], 42.0, "Full depth at the impact point");
+        assert!(terrain[9] < 50.0 && terrain[9] > 42.0, "Bowl eases toward the rim");
+        assert_eq!(terrain[6], 50.0, "Rim is untouched");
+        assert!(terrain[12..=16].iter().all(|&height| height == 50.0), "Zone stays flat");
+
+        // Deep craters stop at zero, out-of-range impacts are ignored
+        let mut terrain = vec![3.0; 5];
+        deform_terrain_crater(&mut terrain, 2, 2, 10.0, &[]);
+        assert_eq!(terrain[2], 0.0);
+        deform_terrain_crater(&mut terrain, 99, 2, 10.0, &[]);
+        assert_eq!(terrain.len(), 5);
+    }
+
+    #[test]
+    fn test_peak_height_under() {
+        let terrain = vec![10.0, 30.0, 20.0, 5.0, 5.0];
+        assert_eq!(peak_height_under(&terrain, 0.0, 2.0), 30.0);
+        assert_eq!(peak_height_under(&terrain, 2.0, 1.0), 20.0);
+        assert_eq!(peak_height_under(&terrain, 3.0, 10.0), 5.0, "Span clamped to the terrain end");
+        assert_eq!(peak_height_under(&[], 0.0, 5.0), 0.0);
+    }
+
+    /// The straightforward per-point generation loop the optimized path replaced
+    fn reference_terrain_heights(
+        num_points: usize,
+        min_height: f64,
+        max_height: f64,
+        base_frequency: f64,
+        octaves: u32,
+        persistence: f64,
+        landing_zones: &[LandingZone],
+    ) -> Vec<f64> {
+        let perlin = Perlin::new();
+        let noise_at = |i: usize| {
+            let mut height = 0.0;
+            let mut amplitude = 1.0;
+            let mut frequency = base_frequency;
+            let mut max_amplitude = 0.0;
+            for _ in 0..octaves {
+                height += perlin.get([i as f64 * frequency, 0.0]) * amplitude;
+                max_amplitude += amplitude;
+                amplitude *= persistence;
+                frequency *= 2.0;
+            }
+            height / max_amplitude
+        };
+
+        let mut terrain = Vec::with_capacity(num_points);
+        let mut zone_heights = vec![0.0; landing_zones.len()];
+        for i in 0..num_points {
+            let in_zone = landing_zones.iter().position(|zone| i >= zone.start && i <= zone.end);
+            let height = match in_zone {
+                Some(zone_idx) if i == landing_zones[zone_idx].start => {
+                    zone_heights[zone_idx] = noise_at(i);
+                    zone_heights[zone_idx]
+                }
+                Some(zone_idx) => zone_heights[zone_idx],
+                None => noise_at(i),
+            };
+            terrain.push(height);
+        }
+
+        let terrain_min = terrain.iter().cloned().fold(f64::INFINITY, f64::min);
+        let terrain_max = terrain.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        terrain.iter_mut().for_each(|h| {
+            *h = (*h - terrain_min) / (terrain_max - terrain_min);
+            *h = *h * (max_height - min_height) + min_height;
+        });
+        terrain
+    }
+
+    #[test]
+    fn test_seeded_terrain_is_reproducible() {
+        let generate = |seed| generate_terrain_with_multiple_landing_zones(1000, 0.0, 100.0, 0.01, 6, 0.5, 36, 2..=3, seed);
+        let span = |zones: &[LandingZone]| zones.iter().map(|zone| (zone.start, zone.end, zone.difficulty)).collect::<Vec<_>>();
+
+        let (terrain, zones) = generate(1234);
+        let (terrain_again, zones_again) = generate(1234);
+        assert_eq!(terrain, terrain_again, "Same seed, same heights");
+        assert_eq!(span(&zones), span(&zones_again), "Same seed, same zones");
+
+        // Another seed changes the noise even where the zones would line up
+        let (other_terrain, _) = generate(1235);
+        assert_ne!(terrain, other_terrain);
+        let empty: [LandingZone; 0] = [];
+        assert_ne!(
+            generate_terrain_heights(200, 0.0, 100.0, 0.01, 6, 0.5, &empty, 1),
+            generate_terrain_heights(200, 0.0, 100.0, 0.01, 6, 0.5, &empty, 2)
+        );
+        assert_ne!(noise_seed(1), noise_seed(1 << 32 | 2));
+    }
+
+    #[test]
+    fn test_terrain_heights_match_reference() {
+        let zone = |start: usize, end: usize| LandingZone {
+            start,
+            end,
+            difficulty: LandingZoneDifficulty::Medium,
+            width_points: end - start + 1,
+            motion: None,
+        };
+        // The first two zones overlap on points 140-160
+        let zones = vec![zone(120, 160), zone(140, 220), zone(600, 640), zone(4000, 4040)];
+
+        let optimized = generate_terrain_heights(5000, 0.0, 100.0, 0.01, 6, 0.5, &zones, 0);
+        let reference = reference_terrain_heights(5000, 0.0, 100.0, 0.01, 6, 0.5, &zones);
+        assert_eq!(optimized, reference, "Optimized generation must be bit-identical");
+
+        // The earlier zone keeps the overlap flat at its own height; the later
+        // zone is flat from the first point it owns
+        assert!(optimized[120..=160].iter().all(|&h| h == optimized[120]));
+        assert!(optimized[161..=220].iter().all(|&h| h == optimized[161]));
+    }
+
+    #[test]
+    fn test_terrain_height_and_slope() {
+        let terrain = vec![10.0, 20.0, 20.0, 14.0];
+
+        assert_eq!(terrain_height_at(&terrain, 0.5), Some(15.0));
+        assert_eq!(terrain_height_at(&terrain, 3.0), Some(14.0));
+        assert_eq!(terrain_height_at(&terrain, -1.0), None);
+        assert_eq!(terrain_height_at(&terrain, 3.5), None);
+
+        assert_eq!(terrain_slope_at(&terrain, 0.5), 10.0);
+        assert_eq!(terrain_slope_at(&terrain, 1.2), 0.0);
+        assert_eq!(terrain_slope_at(&terrain, 99.0), -6.0, "Clamped to the last segment");
+        assert_eq!(terrain_slope_at(&[5.0], 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_moving_pad_carries_its_surface() {
+        let mut terrain: Vec<f64> = (0..20).map(f64::from).collect();
+        let mut zones = vec![LandingZone {
+            start: 5,
+            end: 7,
+            difficulty: LandingZoneDifficulty::Hard,
+            width_points: 3,
+            motion: None,
+        }];
+        zones[0].set_moving(&mut terrain, 10.0, 4, 8);
+        assert_eq!(&terrain[4..9], &[4.0, 5.0, 5.0, 5.0, 8.0]);
+
+        // Slides right, restoring the ground it leaves
+        update_zones(&mut terrain, &mut zones, 0.1);
+        assert_eq!((zones[0].start, zones[0].end), (6, 8));
+        assert_eq!(&terrain[4..10], &[4.0, 5.0, 5.0, 5.0, 5.0, 9.0]);
+
+        // Stops at the end of the track and turns back
+        update_zones(&mut terrain, &mut zones, 0.25);
+        assert_eq!((zones[0].start, zones[0].end), (8, 10));
+        assert_eq!(&terrain[5..12], &[5.0, 6.0, 7.0, 5.0, 5.0, 5.0, 11.0]);
+        assert!(zones[0].motion.as_ref().unwrap().velocity < 0.0);
+
+        update_zones(&mut terrain, &mut zones, 0.45);
+        assert_eq!(zones[0].start, 4, "Clamped to the start of the track");
+        assert_eq!(&terrain[3..9], &[3.0, 5.0, 5.0, 5.0, 7.0, 8.0]);
+        assert!(terrain[7..].iter().zip(7..).all(|(height, i)| *height == i as f64));
+    }
+
+    #[test]
+    fn test_moving_pad_track_avoids_other_zones() {
+        let zone = |start: usize, width_points: usize| LandingZone {
+            start,
+            end: start + width_points - 1,
+            difficulty: LandingZoneDifficulty::Medium,
+            width_points,
+            motion: None,
+        };
+        let zones = vec![zone(100, 32), zone(300, 40), zone(500, 48)];
+
+        assert_eq!(moving_pad_track(&zones, 1, 800, 150), (182, 410));
+        assert_eq!(moving_pad_track(&zones, 0, 800, 150), (100, 218), "Kept clear of the next zone");
+        assert_eq!(moving_pad_track(&zones[2..], 0, 700, 150), (350, 552), "Stops short of the terrain end");
+    }
+
     #[test]
     fn test_scoring_system() {
         // Test scoring formula: 2 / width_multiplier
@@ -0,0 +1,132 @@
+//! Per-attempt flight telemetry for analysing landing technique.
+//!
+//! When enabled, the physics system records one sample per fixed step into a
+//! bounded ring buffer on the lander. When the attempt ends the buffer is
+//! written out as CSV, ready to plot in a spreadsheet or with `plotters`.
+
+use std::collections::VecDeque;
+use std::fs;
+use std::io;
+
+use crate::sim::FIXED_DT;
+
+/// Longest attempt (s) a log holds before the oldest samples are dropped
+const MAX_LOGGED_SECONDS: f32 = 900.0;
+/// Samples kept per attempt: one per fixed step for `MAX_LOGGED_SECONDS`
+const DEFAULT_CAPACITY: usize = (MAX_LOGGED_SECONDS / FIXED_DT) as usize;
+
+/// CSV header matching the fields of `TelemetrySample`
+const CSV_HEADER: &str = "time_s,altitude,velocity_x,velocity_y,thrust_pct,fuel_pct";
+
+/// One fixed step of flight data
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TelemetrySample {
+    pub time: f32,       // Seconds since the attempt started
+    pub altitude: f32,   // Height of the lander bottom above the terrain beneath it
+    pub velocity_x: f32, // Horizontal velocity (m/s)
+    pub velocity_y: f32, // Vertical velocity (m/s, positive is up)
+    pub thrust: f32,     // Actual (spooled) thrust level (0-100%)
+    pub fuel: f32,       // Remaining fuel (0-100%)
+}
+
+/// Ring buffer of telemetry samples for the current attempt
+#[derive(Debug, Clone)]
+pub struct TelemetryLog {
+    samples: VecDeque<TelemetrySample>,
+    capacity: usize,
+}
+
+impl Default for TelemetryLog {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+}
+
+impl TelemetryLog {
+    /// Creates a log that keeps at most `capacity` samples, dropping the oldest first.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity.min(DEFAULT_CAPACITY)),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Appends a sample, evicting the oldest one when full.
+    pub fn record(&mut self, sample: TelemetrySample) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// Discards all samples, ready for the next attempt.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Number of samples currently held
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Whether no samples have been recorded
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Formats the samples as CSV with a header row.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(CSV_HEADER);
+        csv.push('\n');
+        for sample in &self.samples {
+            csv.push_str(&format!(
+                "{:.3},{:.2},{:.3},{:.3},{:.1},{:.2}\n",
+                sample.time, sample.altitude, sample.velocity_x, sample.velocity_y, sample.thrust, sample.fuel
+            ));
+        }
+        csv
+    }
+
+    /// Writes the samples to a CSV file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Destination file, overwritten if it exists
+    pub fn write_csv(&self, path: &str) -> io::Result<()> {
+        fs::write(path, self.to_csv())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(time: f32) -> TelemetrySample {
+        TelemetrySample {
+            time,
+            altitude: 100.0 - time,
+            velocity_x: 0.5,
+            velocity_y: -time,
+            thrust: 0.0,
+            fuel: 100.0,
+        }
+    }
+
+    #[test]
+    fn test_ring_buffer_keeps_latest_samples() {
+        let mut log = TelemetryLog::with_capacity(3);
+        for i in 0..5 {
+            log.record(sample(i as f32));
+        }
+        assert_eq!(log.len(), 3);
+
+        let csv = log.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines[1], "2.000,98.00,0.500,-2.000,0.0,100.00", "Oldest samples are evicted");
+        assert_eq!(lines.len(), 4);
+
+        log.clear();
+        assert!(log.is_empty());
+    }
+}
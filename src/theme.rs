@@ -0,0 +1,125 @@
+//! Terrain color themes.
+//!
+//! A theme sets the color of regular terrain and the palette used for landing
+//! zones. Every palette keeps the three zone difficulties and consumed zones
+//! clearly apart from each other and from the base terrain color.
+
+use macroquad::prelude::*;
+
+use crate::surface::LandingZoneDifficulty;
+
+/// Colors used to draw terrain and landing zones
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TerrainTheme {
+    pub name: &'static str,
+    pub terrain: Color,  // Regular terrain lines
+    pub hard: Color,     // Hard landing zones
+    pub medium: Color,   // Medium landing zones
+    pub easy: Color,     // Easy landing zones
+    pub consumed: Color, // Zones already used this session
+}
+
+impl TerrainTheme {
+    /// Classic green vector look
+    pub const CLASSIC: TerrainTheme = TerrainTheme {
+        name: "classic",
+        terrain: GREEN,
+        hard: RED,
+        medium: ORANGE,
+        easy: YELLOW,
+        consumed: GRAY,
+    };
+
+    /// Grayscale lunar regolith
+    pub const LUNAR: TerrainTheme = TerrainTheme {
+        name: "lunar",
+        terrain: LIGHTGRAY,
+        hard: RED,
+        medium: ORANGE,
+        easy: YELLOW,
+        consumed: DARKGRAY,
+    };
+
+    /// Martian rust
+    pub const MARTIAN: TerrainTheme = TerrainTheme {
+        name: "mars",
+        terrain: Color::new(0.76, 0.35, 0.18, 1.0),
+        hard: MAGENTA,
+        medium: SKYBLUE,
+        easy: YELLOW,
+        consumed: GRAY,
+    };
+
+    /// All selectable themes
+    pub const ALL: [TerrainTheme; 3] = [Self::CLASSIC, Self::LUNAR, Self::MARTIAN];
+
+    /// Looks up a theme by name (case-insensitive).
+    ///
+    /// # Returns
+    ///
+    /// The matching theme, or `None` if no theme has that name
+    pub fn from_name(name: &str) -> Option<TerrainTheme> {
+        Self::ALL
+            .iter()
+            .find(|theme| theme.name.eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    /// Color for a landing zone.
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty` - Zone difficulty
+    /// * `consumed` - Whether the zone has already been used this session
+    pub fn zone_color(&self, difficulty: LandingZoneDifficulty, consumed: bool) -> Color {
+        if consumed {
+            return self.consumed;
+        }
+        match difficulty {
+            LandingZoneDifficulty::Hard => self.hard,
+            LandingZoneDifficulty::Medium => self.medium,
+            LandingZoneDifficulty::Easy => self.easy,
+        }
+    }
+}
+
+impl Default for TerrainTheme {
+    fn default() -> Self {
+        Self::CLASSIC
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn color_distance(a: Color, b: Color) -> f32 {
+        ((a.r - b.r).powi(2) + (a.g - b.g).powi(2) + (a.b - b.b).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn test_theme_lookup() {
+        assert_eq!(TerrainTheme::from_name("classic"), Some(TerrainTheme::CLASSIC));
+        assert_eq!(TerrainTheme::from_name("MARS"), Some(TerrainTheme::MARTIAN));
+        assert_eq!(TerrainTheme::from_name("venus"), None);
+        assert_eq!(TerrainTheme::default(), TerrainTheme::CLASSIC);
+    }
+
+    #[test]
+    fn test_zone_colors_distinguishable() {
+        for theme in TerrainTheme::ALL {
+            let colors = [theme.terrain, theme.hard, theme.medium, theme.easy, theme.consumed];
+            for i in 0..colors.len() {
+                for j in (i + 1)..colors.len() {
+                    assert!(
+                        color_distance(colors[i], colors[j]) > 0.25,
+                        "Theme {} colors {} and {} are too similar",
+                        theme.name,
+                        i,
+                        j
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Landing difficulty tuning.
+//!
+//! Collision margin, landing limits and leg geometry live in one
+//! `LandingTuning` value that collision detection reads. The defaults match
+//! the constants in `collision.rs`; a tuning file given with `--tuning <path>`
+//! overrides them at startup without recompiling. The landing limits are
+//! the ones Easy zones accept; harder zones scale them down (see
+//! `LandingZoneDifficulty::max_velocity`).
+//!
+//! The file is plain `key = value` lines, `#` starts a comment and missing
+//! keys keep their default:
+//!
+//! ```text
+//! # Forgiving landings for playtesting
+//! max_landing_velocity = 14.0
+//! max_landing_angle_degrees = 25
+//! ```
+
+use std::fs;
+
+use crate::collision::{
+    CollisionShape, COLLISION_MARGIN, MAX_LANDING_ANGLE_DEGREES, MAX_LANDING_VELOCITY, MAX_SLOPE_MISMATCH_DEGREES,
+};
+use crate::surface::LandingZoneDifficulty;
+
+/// Accepted range for `collision_margin`
+const MARGIN_RANGE: (f32, f32) = (0.0, 20.0);
+/// Accepted range for `max_landing_velocity` (m/s)
+const VELOCITY_RANGE: (f32, f32) = (0.5, 100.0);
+/// Accepted range for `max_landing_angle_degrees`
+const ANGLE_RANGE: (f32, f32) = (1.0, 90.0);
+/// Accepted range for `max_slope_mismatch_degrees`
+const SLOPE_MISMATCH_RANGE: (f32, f32) = (0.5, 45.0);
+/// Accepted range for `leg_height_ratio`
+const LEG_HEIGHT_RANGE: (f32, f32) = (0.05, 0.9);
+/// Accepted range for `leg_width_ratio`; two legs must leave room for the body
+const LEG_WIDTH_RANGE: (f32, f32) = (0.05, 0.45);
+
+/// Thresholds and geometry that decide whether a touchdown is a landing
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LandingTuning {
+    pub collision_margin: f32,           // Distance above the terrain that already counts as contact
+    pub max_landing_velocity: f32,       // Maximum safe landing speed (m/s)
+    pub max_landing_angle_degrees: f32,  // Maximum angle from the surface normal for a safe landing
+    pub max_slope_mismatch_degrees: f32, // Off a zone, how far the tilt may differ from the local slope
+    pub shape: CollisionShape,           // Leg and body layout of the lander
+}
+
+impl Default for LandingTuning {
+    fn default() -> Self {
+        Self {
+            collision_margin: COLLISION_MARGIN,
+            max_landing_velocity: MAX_LANDING_VELOCITY,
+            max_landing_angle_degrees: MAX_LANDING_ANGLE_DEGREES,
+            max_slope_mismatch_degrees: MAX_SLOPE_MISMATCH_DEGREES,
+            shape: CollisionShape::default(),
+        }
+    }
+}
+
+impl LandingTuning {
+    /// Reads a tuning file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of a `key = value` tuning file
+    ///
+    /// # Returns
+    ///
+    /// The tuning, or a message describing why the file could not be used
+    pub fn load(path: &str) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|err| format!("{}: {}", path, err))?;
+        Self::parse(&text).map_err(|err| format!("{}: {}", path, err))
+    }
+
+    /// Parses tuning text on top of the defaults and validates the result.
+    ///
+    /// # Returns
+    ///
+    /// The tuning, or a message naming the first bad line or out-of-range value
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut tuning = Self::default();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `key = value`", number + 1))?;
+            let value: f32 = value
+                .trim()
+                .parse()
+                .map_err(|_| format!("line {}: `{}` is not a number", number + 1, value.trim()))?;
+            let field = match key.trim() {
+                "collision_margin" => &mut tuning.collision_margin,
+                "max_landing_velocity" => &mut tuning.max_landing_velocity,
+                "max_landing_angle_degrees" => &mut tuning.max_landing_angle_degrees,
+                "max_slope_mismatch_degrees" => &mut tuning.max_slope_mismatch_degrees,
+                "leg_height_ratio" => &mut tuning.shape.leg_height_ratio,
+                "leg_width_ratio" => &mut tuning.shape.leg_width_ratio,
+                other => return Err(format!("line {}: unknown key `{}`", number + 1, other)),
+            };
+            *field = value;
+        }
+        tuning.validate()?;
+        Ok(tuning)
+    }
+
+    /// Landing speed (m/s) and angle (degrees) limits on a zone.
+    ///
+    /// # Arguments
+    ///
+    /// * `difficulty` - Difficulty of the zone, or `None` off any zone for the tuned limits as is
+    ///
+    /// # Returns
+    ///
+    /// `(max_velocity, max_angle_degrees)`
+    pub fn zone_limits(&self, difficulty: Option<LandingZoneDifficulty>) -> (f32, f32) {
+        match difficulty {
+            Some(difficulty) => (
+                difficulty.max_velocity(self.max_landing_velocity),
+                difficulty.max_angle(self.max_landing_angle_degrees),
+            ),
+            None => (self.max_landing_velocity, self.max_landing_angle_degrees),
+        }
+    }
+
+    /// Checks every value against its accepted range.
+    pub fn validate(&self) -> Result<(), String> {
+        let checks = [
+            ("collision_margin", self.collision_margin, MARGIN_RANGE),
+            ("max_landing_velocity", self.max_landing_velocity, VELOCITY_RANGE),
+            ("max_landing_angle_degrees", self.max_landing_angle_degrees, ANGLE_RANGE),
+            ("max_slope_mismatch_degrees", self.max_slope_mismatch_degrees, SLOPE_MISMATCH_RANGE),
+            ("leg_height_ratio", self.shape.leg_height_ratio, LEG_HEIGHT_RANGE),
+            ("leg_width_ratio", self.shape.leg_width_ratio, LEG_WIDTH_RANGE),
+        ];
+        for (name, value, (min, max)) in checks {
+            if !(min..=max).contains(&value) {
+                return Err(format!("{} = {} is outside {}..={}", name, value, min, max));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tuning() {
+        // Empty file keeps today's values
+        assert_eq!(LandingTuning::parse("").unwrap(), LandingTuning::default());
+        assert!(LandingTuning::default().validate().is_ok());
+
+        let tuning = LandingTuning::parse(
+            "# Forgiving\nmax_landing_velocity = 14.0\n\n  leg_height_ratio=0.3  # taller legs\n",
+        )
+        .unwrap();
+        assert_eq!(tuning.max_landing_velocity, 14.0);
+        assert_eq!(tuning.shape.leg_height_ratio, 0.3);
+        assert_eq!(tuning.max_landing_angle_degrees, MAX_LANDING_ANGLE_DEGREES);
+
+        // Bad lines and out-of-range values are rejected
+        assert!(LandingTuning::parse("max_landing_velocity 14").unwrap_err().contains("line 1"));
+        assert!(LandingTuning::parse("gravity = 3").unwrap_err().contains("unknown key"));
+        assert!(LandingTuning::parse("\ncollision_margin = fast").unwrap_err().contains("line 2"));
+        assert!(LandingTuning::parse("max_landing_angle_degrees = 120").is_err());
+        assert!(LandingTuning::parse("leg_width_ratio = 0.5").is_err());
+        assert!(LandingTuning::parse("max_slope_mismatch_degrees = 60").is_err());
+        assert_eq!(LandingTuning::parse("max_slope_mismatch_degrees = 8").unwrap().max_slope_mismatch_degrees, 8.0);
+        assert!(LandingTuning::parse("collision_margin = NaN").is_err());
+    }
+}
@@ -0,0 +1,103 @@
+//! Display units for the HUD.
+//!
+//! Physics runs in SI throughout; a `UnitSystem` only converts values at the
+//! moment they are formatted for display. Limits such as
+//! `MAX_LANDING_VELOCITY` stay in SI regardless of the selected system.
+//! World lengths need no conversion: gravity and thrust accelerate the lander
+//! in m/s² and move it in camera pixels, so one pixel of the world is one meter.
+
+use serde::{Deserialize, Serialize};
+
+/// Feet per meter
+const FEET_PER_METER: f32 = 3.280_84;
+/// Pounds per kilogram
+const POUNDS_PER_KILOGRAM: f64 = 2.204_62;
+/// Unit system used to display speeds, distances and masses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum UnitSystem {
+    #[default]
+    Metric,   // m/s, m, kg
+    Imperial, // ft/s, ft, lbs
+}
+
+impl UnitSystem {
+    /// Looks up a unit system by name (case-insensitive).
+    ///
+    /// # Returns
+    ///
+    /// The matching system for `metric` or `imperial`, or `None` otherwise
+    pub fn from_name(name: &str) -> Option<UnitSystem> {
+        if name.eq_ignore_ascii_case("metric") {
+            Some(UnitSystem::Metric)
+        } else if name.eq_ignore_ascii_case("imperial") {
+            Some(UnitSystem::Imperial)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the other unit system, for the in-game toggle.
+    pub fn toggle(self) -> Self {
+        match self {
+            UnitSystem::Metric => UnitSystem::Imperial,
+            UnitSystem::Imperial => UnitSystem::Metric,
+        }
+    }
+
+    /// Formats a speed given in m/s, e.g. `"12.5 m/s"` or `"41.0 ft/s"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `meters_per_second` - Speed in SI units
+    /// * `decimals` - Digits after the decimal point
+    pub fn format_speed(self, meters_per_second: f32, decimals: usize) -> String {
+        match self {
+            UnitSystem::Metric => format!("{:.*} m/s", decimals, meters_per_second),
+            UnitSystem::Imperial => format!("{:.*} ft/s", decimals, meters_per_second * FEET_PER_METER),
+        }
+    }
+
+    /// Formats a distance given in meters, e.g. `"350 m"` or `"1148 ft"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `meters` - Distance in SI units
+    /// * `decimals` - Digits after the decimal point
+    pub fn format_distance(self, meters: f32, decimals: usize) -> String {
+        match self {
+            UnitSystem::Metric => format!("{:.*} m", decimals, meters),
+            UnitSystem::Imperial => format!("{:.*} ft", decimals, meters * FEET_PER_METER),
+        }
+    }
+
+    /// Formats a mass given in kilograms as a whole number, e.g. `"23200kg"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `kilograms` - Mass in SI units
+    pub fn format_mass(self, kilograms: f64) -> String {
+        match self {
+            UnitSystem::Metric => format!("{:.0}kg", kilograms),
+            UnitSystem::Imperial => format!("{:.0}lbs", kilograms * POUNDS_PER_KILOGRAM),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_formatting() {
+        assert_eq!(UnitSystem::Metric.format_speed(10.0, 1), "10.0 m/s");
+        assert_eq!(UnitSystem::Imperial.format_speed(10.0, 1), "32.8 ft/s");
+        assert_eq!(UnitSystem::Imperial.format_distance(100.0, 0), "328 ft");
+        assert_eq!(UnitSystem::Metric.format_distance(350.0, 0), "350 m");
+        assert_eq!(UnitSystem::Metric.format_mass(23200.0), "23200kg");
+        assert_eq!(UnitSystem::Imperial.format_mass(1000.0), "2205lbs");
+
+        assert_eq!(UnitSystem::from_name("Imperial"), Some(UnitSystem::Imperial));
+        assert_eq!(UnitSystem::from_name("furlongs"), None);
+        assert_eq!(UnitSystem::default().toggle(), UnitSystem::Imperial);
+    }
+}
@@ -2,13 +2,74 @@ use macroquad::prelude::*;
 
 const FULL_CIRCLE_DEGREES: f32 = 360.0;
 
-pub fn transform_axes(position: Vec2) -> Vec2 {
+/// World size gameplay runs in when no window size is configured.
+pub const DEFAULT_VIRTUAL_RESOLUTION: Vec2 = Vec2::new(800.0, 600.0);
+
+pub fn transform_axes(position: Vec2, world_size: Vec2) -> Vec2 {
     vec2(
-        position.x + screen_width() / 2.0,
-        -position.y + screen_height() / 2.0,
+        position.x + world_size.x / 2.0,
+        -position.y + world_size.y / 2.0,
     )
 }
 
+/// Window area the virtual resolution is drawn into.
+///
+/// Gameplay coordinates are fixed at the virtual resolution no matter how big
+/// the window is; the world is scaled uniformly to the largest size that fits
+/// and centered, leaving black bars along the sides (or top and bottom) when
+/// the aspect ratios differ. Resizing the window therefore never moves the
+/// terrain or the lander relative to each other.
+///
+/// # Arguments
+///
+/// * `virtual_resolution` - Size of the gameplay world
+/// * `window` - Current window size in pixels
+///
+/// # Returns
+///
+/// The letterboxed area in window pixels; its width over the virtual width is the scale
+pub fn letterbox(virtual_resolution: Vec2, window: Vec2) -> Rect {
+    let scale = (window.x / virtual_resolution.x.max(1.0)).min(window.y / virtual_resolution.y.max(1.0));
+    let size = virtual_resolution * scale;
+    Rect::new((window.x - size.x) / 2.0, (window.y - size.y) / 2.0, size.x, size.y)
+}
+
+/// Camera X of a terrain position.
+///
+/// Terrain has a fixed number of points (see `surface::TERRAIN_POINTS`)
+/// spread evenly across the world, so the spacing between points depends on
+/// the world width rather than being one pixel.
+///
+/// # Arguments
+///
+/// * `terrain_x` - Position in terrain points (fractional between points)
+/// * `terrain_points` - Number of points in the terrain
+/// * `world_width` - Width in pixels the terrain spans
+///
+/// # Returns
+///
+/// The camera X coordinate of the position
+pub fn terrain_x_to_pixel(terrain_x: f32, terrain_points: usize, world_width: f32) -> f32 {
+    terrain_x * world_width / terrain_points.max(1) as f32
+}
+
+/// Terrain position of a camera X coordinate, the inverse of `terrain_x_to_pixel`.
+///
+/// Also converts widths: a span of `pixel_x` pixels covers the returned
+/// number of terrain points.
+///
+/// # Arguments
+///
+/// * `pixel_x` - Camera X coordinate
+/// * `terrain_points` - Number of points in the terrain
+/// * `world_width` - Width in pixels the terrain spans
+///
+/// # Returns
+///
+/// The position in terrain points, fractional between points
+pub fn pixel_to_terrain_x(pixel_x: f32, terrain_points: usize, world_width: f32) -> f32 {
+    pixel_x * terrain_points.max(1) as f32 / world_width.max(1.0)
+}
 
 
 // Legacy function for backward compatibility and testing
@@ -74,6 +135,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_terrain_pixel_conversion() {
+        // 1000 points over an 800 pixel world: 1.25 points per pixel
+        assert_eq!(terrain_x_to_pixel(500.0, 1000, 800.0), 400.0);
+        assert_eq!(pixel_to_terrain_x(400.0, 1000, 800.0), 500.0);
+        assert_eq!(pixel_to_terrain_x(32.0, 1000, 800.0), 40.0, "Widths scale the same way");
+
+        // Round trip at another window width
+        let x = 123.5;
+        let pixel = terrain_x_to_pixel(x, 1000, 1280.0);
+        assert!((pixel_to_terrain_x(pixel, 1000, 1280.0) - x).abs() < 0.001);
+
+        // One point per pixel when the counts match
+        assert_eq!(terrain_x_to_pixel(42.0, 800, 800.0), 42.0);
+    }
+
+    #[test]
+    fn test_letterbox_scaling() {
+        let world = vec2(800.0, 600.0);
+
+        // Same size: no bars
+        assert_eq!(letterbox(world, world), Rect::new(0.0, 0.0, 800.0, 600.0));
+
+        // Wider window: scaled 1.5x with bars left and right
+        assert_eq!(letterbox(world, vec2(1600.0, 900.0)), Rect::new(200.0, 0.0, 1200.0, 900.0));
+
+        // Taller window: scaled down with bars above and below
+        assert_eq!(letterbox(world, vec2(400.0, 600.0)), Rect::new(0.0, 150.0, 400.0, 300.0));
+
+        // The spawn point stays at the world center whatever the window
+        assert_eq!(transform_axes(vec2(0.0, 0.0), world), vec2(400.0, 300.0));
+    }
+
     #[test]
     fn test_coordinate_transformation_math() {
         // Test coordinate transformation logic without requiring graphics context
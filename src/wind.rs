@@ -0,0 +1,84 @@
+//! Horizontal wind pushing the lander sideways.
+//!
+//! Wind is a force in newtons rather than an acceleration, so a heavy,
+//! fully fueled lander drifts less than a light one. The steady part is set
+//! with `--wind`; with `--gusts` the force rises and falls over a few seconds,
+//! following a Perlin noise curve sampled along the simulation clock. The
+//! default is calm, which leaves the classic game unchanged.
+
+use noise::{NoiseFn, Perlin, Seedable};
+
+/// Noise seed for the gust pattern
+const GUST_SEED: u32 = 1971;
+/// Noise samples per second of simulation time; lower gives slower gusts
+const GUST_FREQUENCY: f64 = 0.2;
+
+/// Wind blowing across the landing site
+#[derive(Clone)]
+pub struct Wind {
+    pub strength: f32,  // Steady force in newtons; positive blows to the right
+    pub gustiness: f32, // How far gusts swing the force, as a fraction of `strength` (0.0-1.0)
+    perlin: Perlin,
+}
+
+impl Default for Wind {
+    fn default() -> Self {
+        Self::new(0.0, 0.0)
+    }
+}
+
+impl Wind {
+    /// Creates a wind of `strength` newtons with gusts of up to `gustiness` of that.
+    pub fn new(strength: f32, gustiness: f32) -> Self {
+        Self {
+            strength,
+            gustiness: gustiness.clamp(0.0, 1.0),
+            perlin: Perlin::new().set_seed(GUST_SEED),
+        }
+    }
+
+    /// Whether there is no wind at all
+    pub fn is_calm(&self) -> bool {
+        self.strength == 0.0
+    }
+
+    /// Strongest force the gusts can reach, in newtons (unsigned)
+    pub fn peak_force(&self) -> f32 {
+        self.strength.abs() * (1.0 + self.gustiness)
+    }
+
+    /// Horizontal wind force at `time` seconds of simulation time.
+    ///
+    /// Gusts never reverse the wind; they only vary how hard it blows.
+    pub fn force_at(&self, time: f32) -> f32 {
+        if self.gustiness == 0.0 {
+            return self.strength;
+        }
+        let gust = self.perlin.get([time as f64 * GUST_FREQUENCY, 0.5]).clamp(-1.0, 1.0) as f32;
+        self.strength * (1.0 + self.gustiness * gust)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wind_gusts_stay_in_range() {
+        assert!(Wind::default().is_calm());
+        assert_eq!(Wind::default().force_at(12.0), 0.0);
+
+        let steady = Wind::new(-800.0, 0.0);
+        assert_eq!(steady.force_at(0.0), -800.0);
+        assert_eq!(steady.force_at(30.0), -800.0);
+
+        let gusty = Wind::new(1000.0, 0.5);
+        assert_eq!(gusty.peak_force(), 1500.0);
+        let forces: Vec<f32> = (0..600).map(|i| gusty.force_at(i as f32 * 0.1)).collect();
+        assert!(forces.iter().all(|force| (500.0..=1500.0).contains(force)));
+        assert!(forces.iter().any(|force| (force - 1000.0).abs() > 50.0), "Gusts vary the force");
+        assert_eq!(gusty.force_at(7.5), Wind::new(1000.0, 0.5).force_at(7.5), "Same gusts every run");
+
+        assert_eq!(Wind::new(1.0, 3.0).gustiness, 1.0, "Gustiness is clamped");
+    }
+}